@@ -7,8 +7,8 @@
 
 use std::sync::Arc;
 
-use metaverse_runtime::{opaque::Block, AccountId, Index};
-use primitives::Balance;
+use metaverse_runtime::{opaque::Block, AccountId, ClassId, Index};
+use primitives::{Balance, BlockNumber, Hash};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -33,9 +33,13 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: metaverse_rpc::MetaverseRuntimeApi<Block, AccountId, Balance, Hash, BlockNumber>,
+	C::Api: nft_rpc::NftRuntimeApi<Block, AccountId, ClassId>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use metaverse_rpc::{Metaverse, MetaverseApi};
+	use nft_rpc::{Nft, NftApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
@@ -56,6 +60,10 @@ where
 		client.clone(),
 	)));
 
+	io.extend_with(MetaverseApi::to_delegate(Metaverse::new(client.clone())));
+
+	io.extend_with(NftApi::to_delegate(Nft::new(client.clone())));
+
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
 	// to call into the runtime.
@@ -39,7 +39,7 @@ use primitives::Balance;
 use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
 // use orml_traits::BasicCurrencyExtended;
 use auction_manager::{CheckAuctionItemHandler, ListingLevel};
-use bc_primitives::{MetaverseInfo, MetaverseTrait};
+use bc_primitives::{MetaverseInfo, MetaverseRole, MetaverseTrait};
 use primitives::{FungibleTokenId, UndeployedLandBlock, UndeployedLandBlockId, UndeployedLandBlockType};
 
 pub type AccountId = u128;
@@ -84,6 +84,7 @@ fn mint_NFT<T: Config>(caller: T::AccountId) {
 		0u32.into(),
 		TokenType::Transferable,
 		CollectionType::Collectable,
+		None,
 	);
 	NFTModule::<T>::mint(
 		RawOrigin::Signed(caller.clone()).into(),
@@ -111,6 +112,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 		}
 	}
 
+	fn has_role(who: &AccountId, metaverse_id: &MetaverseId, _role: MetaverseRole) -> bool {
+		Self::check_ownership(who, metaverse_id)
+	}
+
 	fn get_metaverse(metaverse_id: u64) -> Option<MetaverseInfo<u128>> {
 		None
 	}
@@ -131,7 +136,7 @@ benchmarks! {
 
 		let caller = funded_account::<T>("caller", 0);
 		mint_NFT::<T>(caller.clone());
-	}: _(RawOrigin::Signed(caller.clone()), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global)
+	}: _(RawOrigin::Signed(caller.clone()), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global, FungibleTokenId::NativeToken(0))
 
 	// create_new_buy_now
 	create_new_buy_now{
@@ -139,7 +144,7 @@ benchmarks! {
 
 		let caller = funded_account::<T>("caller", 0);
 		mint_NFT::<T>(caller.clone());
-	}: _(RawOrigin::Signed(caller.clone()), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global)
+	}: _(RawOrigin::Signed(caller.clone()), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global, FungibleTokenId::NativeToken(0))
 
 	// bid
 	bid{
@@ -149,7 +154,7 @@ benchmarks! {
 		let bidder = funded_account::<T>("bidder", 0);
 		mint_NFT::<T>(caller.clone());
 
-		crate::Pallet::<T>::create_new_auction(RawOrigin::Signed(caller.clone()).into(), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global);
+		crate::Pallet::<T>::create_new_auction(RawOrigin::Signed(caller.clone()).into(), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global, FungibleTokenId::NativeToken(0));
 	}: _(RawOrigin::Signed(bidder.clone()), 0u32.into(), 100u32.into())
 
 	// buy_now
@@ -160,7 +165,7 @@ benchmarks! {
 		let bidder = funded_account::<T>("bidder", 0);
 		mint_NFT::<T>(caller.clone());
 
-		crate::Pallet::<T>::create_new_buy_now(RawOrigin::Signed(caller.clone()).into(), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global);
+		crate::Pallet::<T>::create_new_buy_now(RawOrigin::Signed(caller.clone()).into(), ItemId::NFT(0), 100u32.into(), 100u32.into(), ListingLevel::Global, FungibleTokenId::NativeToken(0));
 	}: _(RawOrigin::Signed(bidder.clone()), 0u32.into(), 100u32.into())
 
 	// create_new_auction at local metaverse level
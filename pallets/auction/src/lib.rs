@@ -27,12 +27,16 @@
 
 use auction_manager::{Auction, AuctionHandler, AuctionInfo, AuctionItem, AuctionType, Change, OnNewBidResult};
 use frame_support::traits::{Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency};
-use frame_support::{ensure, pallet_prelude::*, transactional};
-use frame_system::{self as system, ensure_signed};
+use frame_support::{
+	ensure,
+	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+	transactional,
+};
+use frame_system::{self as system, ensure_root, ensure_signed};
 pub use pallet::*;
 use pallet_nft::Pallet as NFTModule;
 use primitives::{continuum::Continuum, estate::Estate, AuctionId, ItemId};
-use sp_core::sp_std::convert::TryInto;
 use sp_runtime::SaturatedConversion;
 use sp_runtime::{
 	traits::{CheckedDiv, One, Saturating, Zero},
@@ -57,7 +61,7 @@ pub struct AuctionLogicHandler;
 pub mod pallet {
 	use super::*;
 	use auction_manager::{CheckAuctionItemHandler, ListingLevel};
-	use bc_primitives::MetaverseTrait;
+	use bc_primitives::{MetaverseRole, MetaverseTrait};
 	use frame_support::dispatch::DispatchResultWithPostInfo;
 	use frame_support::sp_runtime::traits::CheckedSub;
 	use frame_system::pallet_prelude::OriginFor;
@@ -81,7 +85,7 @@ pub mod pallet {
 		type Currency: ReservableCurrency<Self::AccountId>
 			+ LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
 		/// Continuum protocol handler
-		type ContinuumHandler: Continuum<Self::AccountId>;
+		type ContinuumHandler: Continuum<Self::AccountId, BalanceOf<Self>>;
 		/// Multi-fungible token currency
 		type FungibleTokenCurrency: MultiReservableCurrency<
 			Self::AccountId,
@@ -127,6 +131,16 @@ pub mod pallet {
 	pub(super) type AuctionEndTime<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, AuctionId, (), OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn whitelisted_currencies)]
+	/// Fungible tokens that global listings are allowed to settle in, besides native currency.
+	pub(super) type WhitelistedCurrencies<T: Config> = StorageMap<_, Twox64Concat, FungibleTokenId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reserve_prices)]
+	/// Minimum first bid of auctions created with a reserve price, e.g. NFT buyouts.
+	pub(super) type ReservePrices<T: Config> = StorageMap<_, Twox64Concat, AuctionId, BalanceOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -143,6 +157,13 @@ pub mod pallet {
 		AuctionFinalized(AuctionId, T::AccountId, BalanceOf<T>),
 		BuyNowFinalised(AuctionId, T::AccountId, BalanceOf<T>),
 		AuctionFinalizedNoBid(AuctionId),
+		/// Auction ended but the item could not be settled, the high bid is released [auction_id,
+		/// error]
+		AuctionSettlementFailed(AuctionId, DispatchError),
+		/// Currency is whitelisted for global listings. [currency_id]
+		CurrencyWhitelisted(FungibleTokenId),
+		/// Currency is removed from whitelist. [currency_id]
+		CurrencyRemovedFromWhitelist(FungibleTokenId),
 	}
 
 	/// Errors inform users that something went wrong.
@@ -176,6 +197,12 @@ pub mod pallet {
 		Overflow,
 		EstateDoesNotExist,
 		LandUnitDoesNotExist,
+		/// Currency is not whitelisted for global listings
+		CurrencyIsNotWhitelisted,
+		/// Currency is already whitelisted
+		CurrencyAlreadyWhitelisted,
+		/// Currency does not match the listing level
+		InvalidAuctionCurrency,
 	}
 
 	#[pallet::call]
@@ -186,52 +213,7 @@ pub mod pallet {
 		pub fn bid(origin: OriginFor<T>, id: AuctionId, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			let auction_item: AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>> =
-				Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
-			ensure!(
-				auction_item.auction_type == AuctionType::Auction,
-				Error::<T>::InvalidAuctionType
-			);
-			ensure!(auction_item.recipient != from, Error::<T>::SelfBidNotAccepted);
-			ensure!(
-				auction_item.listing_level == ListingLevel::Global,
-				Error::<T>::WrongListingLevel
-			);
-
-			<Auctions<T>>::try_mutate_exists(id, |auction| -> DispatchResult {
-				let mut auction = auction.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
-
-				let block_number = <system::Pallet<T>>::block_number();
-
-				// make sure auction is started
-				ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
-
-				let auction_end: Option<T::BlockNumber> = auction.end;
-
-				ensure!(block_number < auction_end.unwrap(), Error::<T>::AuctionIsExpired);
-
-				if let Some(ref current_bid) = auction.bid {
-					ensure!(value > current_bid.1, Error::<T>::InvalidBidPrice);
-				} else {
-					ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
-				}
-				// implement hooks for future event
-				let bid_result = T::Handler::on_new_bid(block_number, id, (from.clone(), value), auction.bid.clone());
-
-				ensure!(bid_result.accept_bid, Error::<T>::BidNotAccepted);
-
-				ensure!(
-					<T as Config>::Currency::free_balance(&from) >= value,
-					Error::<T>::InsufficientFreeBalance
-				);
-
-				Self::auction_bid_handler(block_number, id, (from.clone(), value), auction.bid.clone())?;
-
-				auction.bid = Some((from.clone(), value));
-				Self::deposit_event(Event::Bid(id, from, value));
-
-				Ok(())
-			})?;
+			Self::do_bid(from, id, value, ListingLevel::Global)?;
 
 			Ok(().into())
 		}
@@ -247,166 +229,24 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			let auction_item: AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>> =
-				Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
-			ensure!(
-				auction_item.auction_type == AuctionType::Auction,
-				Error::<T>::InvalidAuctionType
-			);
-			ensure!(auction_item.recipient != from, Error::<T>::SelfBidNotAccepted);
-			ensure!(
-				auction_item.listing_level == ListingLevel::Local(bc_id),
-				Error::<T>::WrongListingLevel
-			);
-
-			let social_currency_id = auction_item.currency_id;
-
-			<Auctions<T>>::try_mutate_exists(id, |auction| -> DispatchResult {
-				let mut auction = auction.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
-
-				let block_number = <system::Pallet<T>>::block_number();
-
-				// make sure auction is started
-				ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
-
-				let auction_end: Option<T::BlockNumber> = auction.end;
-
-				ensure!(block_number < auction_end.unwrap(), Error::<T>::AuctionIsExpired);
-
-				if let Some(ref current_bid) = auction.bid {
-					ensure!(value > current_bid.1, Error::<T>::InvalidBidPrice);
-				} else {
-					ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
-				}
-				let bid_result = T::Handler::on_new_bid(block_number, id, (from.clone(), value), auction.bid.clone());
-
-				ensure!(bid_result.accept_bid, Error::<T>::BidNotAccepted);
-
-				ensure!(
-					T::FungibleTokenCurrency::free_balance(social_currency_id, &from)
-						>= TryInto::<Balance>::try_into(value).unwrap_or_default(),
-					Error::<T>::InsufficientFreeBalance
-				);
-
-				Self::local_auction_bid_handler(
-					block_number,
-					id,
-					(from.clone(), value),
-					auction.bid.clone(),
-					social_currency_id,
-				)?;
-
-				auction.bid = Some((from.clone(), value));
-				Self::deposit_event(Event::Bid(id, from, value));
-
-				Ok(())
-			})?;
+			Self::do_bid(from, id, value, ListingLevel::Local(bc_id))?;
 
 			Ok(().into())
 		}
 
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
 		pub fn buy_now(origin: OriginFor<T>, auction_id: AuctionId, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			let auction = Self::auctions(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
 			let auction_item = Self::get_auction_item(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
+			Self::do_buy_now(from, auction_id, auction_item, value)?;
 
-			ensure!(
-				auction_item.auction_type == AuctionType::BuyNow,
-				Error::<T>::InvalidAuctionType
-			);
-
-			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
-
-			let block_number = <system::Pallet<T>>::block_number();
-			ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
-			if !(auction.end.is_none()) {
-				let auction_end: T::BlockNumber = auction.end.unwrap();
-				ensure!(block_number < auction_end, Error::<T>::AuctionIsExpired);
-			}
-
-			ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
-			ensure!(
-				<T as Config>::Currency::free_balance(&from) >= value,
-				Error::<T>::InsufficientFunds
-			);
-
-			Self::remove_auction(auction_id.clone(), auction_item.item_id);
-
-			// Transfer balance from buy it now user to asset owner
-			let currency_transfer = <T as Config>::Currency::transfer(
-				&from,
-				&auction_item.recipient,
-				value,
-				ExistenceRequirement::KeepAlive,
-			);
-			match currency_transfer {
-				Err(_e) => {}
-				Ok(_v) => {
-					// Transfer asset from asset owner to buy it now user
-					<ItemsInAuction<T>>::remove(auction_item.item_id);
-					match auction_item.item_id {
-						ItemId::NFT(asset_id) => {
-							Self::collect_royalty_fee(
-								&value,
-								&auction_item.recipient,
-								&asset_id,
-								FungibleTokenId::NativeToken(0),
-							);
-
-							let asset_transfer = NFTModule::<T>::do_transfer(&auction_item.recipient, &from, asset_id);
-							match asset_transfer {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::Spot(spot_id, metaverse_id) => {
-							let continuum_spot = T::ContinuumHandler::transfer_spot(
-								spot_id,
-								&auction_item.recipient,
-								&(from.clone(), metaverse_id),
-							);
-							match continuum_spot {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::Estate(estate_id) => {
-							let estate =
-								T::EstateHandler::transfer_estate(estate_id, &auction_item.recipient, &from.clone());
-							match estate {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::LandUnit(coordinate, metaverse_id) => {
-							let land_unit = T::EstateHandler::transfer_landunit(
-								coordinate,
-								&auction_item.recipient,
-								&(from.clone(), metaverse_id),
-							);
-							match land_unit {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						_ => {} // Future implementation for Land, Metaverse
-					}
-				}
-			}
 			Ok(().into())
 		}
 
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
 		pub fn buy_now_local(
 			origin: OriginFor<T>,
 			auction_id: AuctionId,
@@ -415,103 +255,19 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			let auction = Self::auctions(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
 			let auction_item = Self::get_auction_item(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
-			ensure!(
-				auction_item.auction_type == AuctionType::BuyNow,
-				Error::<T>::InvalidAuctionType
-			);
-			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
 			ensure!(
 				auction_item.listing_level == ListingLevel::Local(bc_id),
 				Error::<T>::WrongListingLevel
 			);
+			Self::do_buy_now(from, auction_id, auction_item, value)?;
 
-			let block_number = <system::Pallet<T>>::block_number();
-			ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
-			if !(auction.end.is_none()) {
-				let auction_end: T::BlockNumber = auction.end.unwrap();
-				ensure!(block_number < auction_end, Error::<T>::AuctionIsExpired);
-			}
-
-			let social_currency_id = auction_item.currency_id;
-
-			ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
-			ensure!(
-				T::FungibleTokenCurrency::free_balance(social_currency_id, &from)
-					>= TryInto::<Balance>::try_into(value).unwrap_or_default(),
-				Error::<T>::InsufficientFunds
-			);
-
-			Self::remove_auction(auction_id.clone(), auction_item.item_id);
-			// Transfer balance from buy it now user to asset owner
-
-			let social_currency_transfer = T::FungibleTokenCurrency::transfer(
-				social_currency_id,
-				&from,
-				&auction_item.recipient,
-				value.saturated_into(),
-			);
-			match social_currency_transfer {
-				Err(_e) => {}
-				Ok(_v) => {
-					// Transfer asset from asset owner to buy it now user
-					<ItemsInAuction<T>>::remove(auction_item.item_id);
-					match auction_item.item_id {
-						ItemId::NFT(asset_id) => {
-							Self::collect_royalty_fee(&value, &auction_item.recipient, &asset_id, social_currency_id);
-
-							let asset_transfer = NFTModule::<T>::do_transfer(&auction_item.recipient, &from, asset_id);
-							match asset_transfer {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::Spot(spot_id, metaverse_id) => {
-							let continuum_spot = T::ContinuumHandler::transfer_spot(
-								spot_id,
-								&auction_item.recipient,
-								&(from.clone(), metaverse_id),
-							);
-							match continuum_spot {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::Estate(estate_id) => {
-							let estate =
-								T::EstateHandler::transfer_estate(estate_id, &auction_item.recipient, &from.clone());
-							match estate {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::LandUnit(coordinate, metaverse_id) => {
-							let land_unit = T::EstateHandler::transfer_landunit(
-								coordinate,
-								&auction_item.recipient,
-								&(from.clone(), metaverse_id),
-							);
-							match land_unit {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						_ => {} // Future implementation for Spot, Metaverse
-					}
-				}
-			}
 			Ok(().into())
 		}
 
+		/// Create new auction. Global listings can settle in native currency or any whitelisted
+		/// fungible token, local listings must settle in the metaverse token and can only be
+		/// created by holders of its Marketplace role.
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		pub fn create_new_auction(
 			origin: OriginFor<T>,
@@ -519,6 +275,7 @@ pub mod pallet {
 			value: BalanceOf<T>,
 			end_time: T::BlockNumber,
 			listing_level: ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
@@ -526,6 +283,7 @@ pub mod pallet {
 				matches!(item_id, ItemId::NFT(_)),
 				Error::<T>::NoPermissionToCreateAuction
 			);
+			Self::ensure_can_list(&from, &listing_level)?;
 
 			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
 
@@ -536,7 +294,7 @@ pub mod pallet {
 				Error::<T>::AuctionEndIsLessThanMinimumDuration
 			);
 
-			let auction_id = Self::create_auction(
+			let auction_id = Self::do_create_auction(
 				AuctionType::Auction,
 				item_id,
 				Some(end_time),
@@ -544,6 +302,7 @@ pub mod pallet {
 				value.clone(),
 				start_time,
 				listing_level.clone(),
+				currency_id,
 			)?;
 			Self::deposit_event(Event::NewAuctionItem(
 				auction_id,
@@ -557,6 +316,9 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Create new buy now listing. Global listings can settle in native currency or any
+		/// whitelisted fungible token, local listings must settle in the metaverse token and can
+		/// only be created by holders of its Marketplace role.
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		pub fn create_new_buy_now(
 			origin: OriginFor<T>,
@@ -564,12 +326,14 @@ pub mod pallet {
 			value: BalanceOf<T>,
 			end_time: T::BlockNumber,
 			listing_level: ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 			ensure!(
 				matches!(item_id, ItemId::NFT(_)),
 				Error::<T>::NoPermissionToCreateAuction
 			);
+			Self::ensure_can_list(&from, &listing_level)?;
 
 			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
 			let remaining_time: T::BlockNumber = end_time.checked_sub(&start_time).ok_or(Error::<T>::Overflow)?;
@@ -579,7 +343,7 @@ pub mod pallet {
 				Error::<T>::AuctionEndIsLessThanMinimumDuration
 			);
 
-			let auction_id = Self::create_auction(
+			let auction_id = Self::do_create_auction(
 				AuctionType::BuyNow,
 				item_id,
 				Some(end_time),
@@ -587,6 +351,7 @@ pub mod pallet {
 				value.clone(),
 				start_time,
 				listing_level.clone(),
+				currency_id,
 			)?;
 			Self::deposit_event(Event::NewAuctionItem(
 				auction_id,
@@ -599,6 +364,42 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Allow global listings to settle in a fungible token, only triggered by governance
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn whitelist_currency(origin: OriginFor<T>, currency_id: FungibleTokenId) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				!WhitelistedCurrencies::<T>::contains_key(currency_id),
+				Error::<T>::CurrencyAlreadyWhitelisted
+			);
+
+			WhitelistedCurrencies::<T>::insert(currency_id, ());
+			Self::deposit_event(Event::CurrencyWhitelisted(currency_id));
+
+			Ok(().into())
+		}
+
+		/// Remove fungible token from the auction currency whitelist, only triggered by governance.
+		/// Existing listings keep settling in their original currency.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn remove_whitelisted_currency(
+			origin: OriginFor<T>,
+			currency_id: FungibleTokenId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				WhitelistedCurrencies::<T>::contains_key(currency_id),
+				Error::<T>::CurrencyIsNotWhitelisted
+			);
+
+			WhitelistedCurrencies::<T>::remove(currency_id);
+			Self::deposit_event(Event::CurrencyRemovedFromWhitelist(currency_id));
+
+			Ok(().into())
+		}
 	}
 
 	#[pallet::hooks]
@@ -608,210 +409,29 @@ pub mod pallet {
 				if let Some(auction) = <Auctions<T>>::get(&auction_id) {
 					if let Some(auction_item) = <AuctionItems<T>>::get(&auction_id) {
 						Self::remove_auction(auction_id.clone(), auction_item.item_id);
-						// Transfer balance from high bidder to asset owner
+						<ItemsInAuction<T>>::remove(auction_item.item_id);
 						if let Some(current_bid) = auction.bid {
 							let (high_bidder, high_bid_price): (T::AccountId, BalanceOf<T>) = current_bid;
-							// Handle global listing
-							if auction_item.listing_level == ListingLevel::Global {
-								<T as Config>::Currency::unreserve(&high_bidder, high_bid_price);
-
-								// Handle balance transfer
-								let currency_transfer = <T as Config>::Currency::transfer(
-									&high_bidder,
-									&auction_item.recipient,
-									high_bid_price,
-									ExistenceRequirement::KeepAlive,
-								);
-
-								match currency_transfer {
-									Err(_e) => continue,
-									Ok(_v) => {
-										// Transfer asset from asset owner to high bidder
-										// Check asset type and handle internal logic
-
-										match auction_item.item_id {
-											ItemId::NFT(asset_id) => {
-												Self::collect_royalty_fee(
-													&high_bid_price,
-													&auction_item.recipient,
-													&asset_id,
-													FungibleTokenId::NativeToken(0),
-												);
-
-												let asset_transfer = NFTModule::<T>::do_transfer(
-													&auction_item.recipient,
-													&high_bidder,
-													asset_id,
-												);
-
-												match asset_transfer {
-													Err(_) => continue,
-													Ok(_) => {
-														Self::deposit_event(Event::AuctionFinalized(
-															auction_id,
-															high_bidder,
-															high_bid_price,
-														));
-													}
-												}
-											}
-											ItemId::Spot(spot_id, metaverse_id) => {
-												let continuum_spot = T::ContinuumHandler::transfer_spot(
-													spot_id,
-													&auction_item.recipient,
-													&(high_bidder.clone(), metaverse_id),
-												);
-												match continuum_spot {
-													Err(_) => continue,
-													Ok(_) => {
-														Self::deposit_event(Event::AuctionFinalized(
-															auction_id,
-															high_bidder,
-															high_bid_price,
-														));
-													}
-												}
-											}
-											ItemId::Estate(estate_id) => {
-												let estate = T::EstateHandler::transfer_estate(
-													estate_id,
-													&auction_item.recipient,
-													&high_bidder.clone(),
-												);
-												match estate {
-													Err(_) => (),
-													Ok(_) => {
-														Self::deposit_event(Event::AuctionFinalized(
-															auction_id,
-															high_bidder,
-															high_bid_price,
-														));
-													}
-												}
-											}
-											ItemId::LandUnit(coordinate, metaverse_id) => {
-												let land_unit = T::EstateHandler::transfer_landunit(
-													coordinate,
-													&auction_item.recipient,
-													&(high_bidder.clone(), metaverse_id),
-												);
-												match land_unit {
-													Err(_) => (),
-													Ok(_) => {
-														Self::deposit_event(Event::AuctionFinalized(
-															auction_id,
-															high_bidder,
-															high_bid_price,
-														));
-													}
-												}
-											}
-											_ => {} // Future implementation for Spot, Metaverse
-										}
-										<ItemsInAuction<T>>::remove(auction_item.item_id);
-									}
+
+							// Payment and item move together, the bid is released if either fails
+							let settlement = with_transaction(|| {
+								match Self::settle_auction(&auction_item, &high_bidder, high_bid_price) {
+									Ok(()) => TransactionOutcome::Commit(Ok(())),
+									Err(e) => TransactionOutcome::Rollback(Err(e)),
 								}
-							} else {
-								// Handle local bit country social token transfer
-								let social_currency_id = auction_item.currency_id.clone();
-								T::FungibleTokenCurrency::unreserve(
-									social_currency_id,
-									&high_bidder,
-									high_bid_price.saturated_into(),
-								);
-								let social_currency_transfer = T::FungibleTokenCurrency::transfer(
-									social_currency_id,
-									&high_bidder,
-									&auction_item.recipient,
-									high_bid_price.saturated_into(),
-								);
-								match social_currency_transfer {
-									Err(_e) => continue,
-									Ok(_v) => {
-										// Transfer asset from asset owner to high bidder
-										// Check asset type and handle internal logic
-
-										match auction_item.item_id {
-											ItemId::NFT(asset_id) => {
-												Self::collect_royalty_fee(
-													&high_bid_price,
-													&auction_item.recipient,
-													&asset_id,
-													social_currency_id,
-												);
-
-												let asset_transfer = NFTModule::<T>::do_transfer(
-													&auction_item.recipient,
-													&high_bidder,
-													asset_id,
-												);
-
-												match asset_transfer {
-													Err(_) => continue,
-													Ok(_) => {
-														Self::deposit_event(Event::AuctionFinalized(
-															auction_id,
-															high_bidder,
-															high_bid_price,
-														));
-													}
-												}
-											}
-											ItemId::Spot(spot_id, metaverse_id) => {
-												let continuum_spot = T::ContinuumHandler::transfer_spot(
-													spot_id,
-													&auction_item.recipient,
-													&(high_bidder.clone(), metaverse_id),
-												);
-												match continuum_spot {
-													Err(_) => continue,
-													Ok(_) => {
-														Self::deposit_event(Event::AuctionFinalized(
-															auction_id,
-															high_bidder,
-															high_bid_price,
-														));
-													}
-												}
-											}
-											ItemId::Estate(estate_id) => {
-												let estate = T::EstateHandler::transfer_estate(
-													estate_id,
-													&auction_item.recipient,
-													&high_bidder.clone(),
-												);
-												match estate {
-													Err(_) => (),
-													Ok(_) => {
-														Self::deposit_event(Event::AuctionFinalized(
-															auction_id,
-															high_bidder,
-															high_bid_price,
-														));
-													}
-												}
-											}
-											ItemId::LandUnit(coordinate, metaverse_id) => {
-												let land_unit = T::EstateHandler::transfer_landunit(
-													coordinate,
-													&auction_item.recipient,
-													&(high_bidder.clone(), metaverse_id),
-												);
-												match land_unit {
-													Err(_) => (),
-													Ok(_) => {
-														Self::deposit_event(Event::AuctionFinalized(
-															auction_id,
-															high_bidder,
-															high_bid_price,
-														));
-													}
-												}
-											}
-											_ => {} // Future implementation for Spot, Metaverse
-										}
-										<ItemsInAuction<T>>::remove(auction_item.item_id);
-									}
+							});
+
+							match settlement {
+								Ok(()) => {
+									Self::deposit_event(Event::AuctionFinalized(
+										auction_id,
+										high_bidder,
+										high_bid_price,
+									));
+								}
+								Err(e) => {
+									Self::unreserve_currency(auction_item.currency_id, &high_bidder, high_bid_price);
+									Self::deposit_event(Event::AuctionSettlementFailed(auction_id, e));
 								}
 							}
 						} else {
@@ -823,57 +443,123 @@ pub mod pallet {
 		}
 	}
 
-	impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
-		type Balance = BalanceOf<T>;
-
-		fn update_auction(
+	impl<T: Config> Pallet<T> {
+		fn do_bid(
+			from: T::AccountId,
 			id: AuctionId,
-			info: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber>,
+			value: BalanceOf<T>,
+			listing_level: ListingLevel<T::AccountId>,
 		) -> DispatchResult {
-			let auction = <Auctions<T>>::get(id).ok_or(Error::<T>::AuctionNotExist)?;
-			if let Some(old_end) = auction.end {
-				<AuctionEndTime<T>>::remove(&old_end, id);
-			}
-			if let Some(new_end) = info.end {
-				<AuctionEndTime<T>>::insert(&new_end, id, ());
-			}
-			<Auctions<T>>::insert(id, info);
-			Ok(())
+			let auction_item: AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>> =
+				Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(
+				auction_item.auction_type == AuctionType::Auction,
+				Error::<T>::InvalidAuctionType
+			);
+			ensure!(auction_item.recipient != from, Error::<T>::SelfBidNotAccepted);
+			ensure!(
+				auction_item.listing_level == listing_level,
+				Error::<T>::WrongListingLevel
+			);
+
+			<Auctions<T>>::try_mutate_exists(id, |auction| -> DispatchResult {
+				let mut auction = auction.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
+
+				let block_number = <system::Pallet<T>>::block_number();
+
+				// make sure auction is started
+				ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
+
+				let auction_end: Option<T::BlockNumber> = auction.end;
+
+				ensure!(block_number < auction_end.unwrap(), Error::<T>::AuctionIsExpired);
+
+				if let Some(ref current_bid) = auction.bid {
+					ensure!(value > current_bid.1, Error::<T>::InvalidBidPrice);
+				} else {
+					ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
+				}
+				// implement hooks for future event
+				let bid_result = T::Handler::on_new_bid(block_number, id, (from.clone(), value), auction.bid.clone());
+
+				ensure!(bid_result.accept_bid, Error::<T>::BidNotAccepted);
+
+				ensure!(
+					Self::currency_free_balance(auction_item.currency_id, &from) >= value,
+					Error::<T>::InsufficientFreeBalance
+				);
+
+				Self::auction_bid_handler(block_number, id, (from.clone(), value), auction.bid.clone())?;
+
+				auction.bid = Some((from.clone(), value));
+				Self::deposit_event(Event::Bid(id, from, value));
+
+				Ok(())
+			})
 		}
 
-		fn new_auction(
-			_recipient: T::AccountId,
-			_initial_amount: Self::Balance,
-			start: T::BlockNumber,
-			end: Option<T::BlockNumber>,
-		) -> Result<AuctionId, DispatchError> {
-			let auction: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber> =
-				AuctionInfo { bid: None, start, end };
+		fn do_buy_now(
+			from: T::AccountId,
+			auction_id: AuctionId,
+			auction_item: AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			value: BalanceOf<T>,
+		) -> DispatchResult {
+			let auction = Self::auctions(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
 
-			let auction_id: AuctionId = AuctionsIndex::<T>::try_mutate(|n| -> Result<AuctionId, DispatchError> {
-				let id = *n;
-				ensure!(id != AuctionId::max_value(), Error::<T>::NoAvailableAuctionId);
-				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
-				Ok(id)
-			})?;
+			ensure!(
+				auction_item.auction_type == AuctionType::BuyNow,
+				Error::<T>::InvalidAuctionType
+			);
 
-			<Auctions<T>>::insert(auction_id, auction);
+			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
 
-			if let Some(end_block) = end {
-				<AuctionEndTime<T>>::insert(&end_block, auction_id, ());
+			let block_number = <system::Pallet<T>>::block_number();
+			ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
+			if !(auction.end.is_none()) {
+				let auction_end: T::BlockNumber = auction.end.unwrap();
+				ensure!(block_number < auction_end, Error::<T>::AuctionIsExpired);
 			}
 
-			Ok(auction_id)
+			let currency_id = auction_item.currency_id;
+
+			ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
+			ensure!(
+				Self::currency_free_balance(currency_id, &from) >= value,
+				Error::<T>::InsufficientFunds
+			);
+
+			Self::remove_auction(auction_id.clone(), auction_item.item_id);
+
+			// Pay the asset owner and hand over the asset, callers revert both if either fails
+			Self::transfer_currency(currency_id, &from, &auction_item.recipient, value)?;
+			Self::transfer_auction_item(&auction_item, &from, value)?;
+
+			Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
+			Ok(())
 		}
 
-		fn create_auction(
+		/// Local listings of a metaverse can only be created by holders of its Marketplace role
+		fn ensure_can_list(from: &T::AccountId, listing_level: &ListingLevel<T::AccountId>) -> DispatchResult {
+			if let ListingLevel::Local(metaverse_id) = listing_level {
+				ensure!(
+					T::MetaverseInfoSource::has_role(from, metaverse_id, MetaverseRole::Marketplace),
+					Error::<T>::NoPermissionToCreateAuction
+				);
+			}
+			Ok(())
+		}
+
+		/// Create auction item with a specific settlement currency
+		#[allow(clippy::too_many_arguments)]
+		fn do_create_auction(
 			auction_type: AuctionType,
 			item_id: ItemId,
 			_end: Option<T::BlockNumber>,
 			recipient: T::AccountId,
-			initial_amount: Self::Balance,
+			initial_amount: BalanceOf<T>,
 			_start: T::BlockNumber,
 			listing_level: ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
 		) -> Result<AuctionId, DispatchError> {
 			ensure!(
 				Self::items_in_auction(item_id) == None,
@@ -895,6 +581,7 @@ pub mod pallet {
 						class_info_data.token_type.is_transferable(),
 						Error::<T>::NoPermissionToCreateAuction
 					);
+					Self::ensure_valid_auction_currency(&listing_level, currency_id)?;
 
 					let start_time = <system::Pallet<T>>::block_number();
 
@@ -903,11 +590,6 @@ pub mod pallet {
 						end_time = _end_block
 					}
 					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
-					let mut currency_id: FungibleTokenId = FungibleTokenId::NativeToken(0);
-					if let ListingLevel::Local(bc_id) = listing_level {
-						currency_id = T::MetaverseInfoSource::get_metaverse_token(bc_id)
-							.ok_or(Error::<T>::FungibleTokenCurrencyNotFound)?;
-					}
 
 					let new_auction_item = AuctionItem {
 						item_id,
@@ -935,6 +617,8 @@ pub mod pallet {
 					Ok(auction_id)
 				}
 				ItemId::Spot(_spot_id, _metaverse_id) => {
+					Self::ensure_valid_auction_currency(&listing_level, currency_id)?;
+
 					let start_time = <system::Pallet<T>>::block_number();
 					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get();
 					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
@@ -948,7 +632,7 @@ pub mod pallet {
 						end_time,
 						auction_type,
 						listing_level: listing_level.clone(),
-						currency_id: FungibleTokenId::NativeToken(0),
+						currency_id,
 					};
 
 					<AuctionItems<T>>::insert(auction_id, new_auction_item);
@@ -970,6 +654,7 @@ pub mod pallet {
 						T::EstateHandler::check_estate(_estate_id_)?,
 						Error::<T>::EstateDoesNotExist
 					);
+					Self::ensure_valid_auction_currency(&ListingLevel::Global, currency_id)?;
 
 					let start_time = <system::Pallet<T>>::block_number();
 					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get(); // add 7 days block for default auction
@@ -984,7 +669,7 @@ pub mod pallet {
 						end_time,
 						auction_type,
 						listing_level: ListingLevel::Global,
-						currency_id: FungibleTokenId::NativeToken(0),
+						currency_id,
 					};
 
 					<AuctionItems<T>>::insert(auction_id, new_auction_item);
@@ -1006,6 +691,7 @@ pub mod pallet {
 						T::EstateHandler::check_landunit(_metaverse_id_, _coordinate_)?,
 						Error::<T>::LandUnitDoesNotExist
 					);
+					Self::ensure_valid_auction_currency(&ListingLevel::Global, currency_id)?;
 
 					let start_time = <system::Pallet<T>>::block_number();
 					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get(); // add 7 days block for default auction
@@ -1020,7 +706,7 @@ pub mod pallet {
 						end_time,
 						auction_type,
 						listing_level: ListingLevel::Global,
-						currency_id: FungibleTokenId::NativeToken(0),
+						currency_id,
 					};
 
 					<AuctionItems<T>>::insert(auction_id, new_auction_item);
@@ -1040,16 +726,206 @@ pub mod pallet {
 			}
 		}
 
+		/// Pay the seller with the reserved high bid and transfer the item to the high bidder
+		fn settle_auction(
+			auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			high_bidder: &T::AccountId,
+			high_bid_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let currency_id = auction_item.currency_id;
+			Self::unreserve_currency(currency_id, high_bidder, high_bid_price);
+			Self::transfer_currency(currency_id, high_bidder, &auction_item.recipient, high_bid_price)?;
+			Self::transfer_auction_item(auction_item, high_bidder, high_bid_price)
+		}
+
+		/// Transfer the auctioned item from the seller to the buyer, collecting royalty for NFTs
+		fn transfer_auction_item(
+			auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			to: &T::AccountId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			match auction_item.item_id {
+				ItemId::NFT(asset_id) => {
+					let _ =
+						Self::collect_royalty_fee(&price, &auction_item.recipient, &asset_id, auction_item.currency_id);
+
+					NFTModule::<T>::do_transfer(&auction_item.recipient, to, asset_id)?;
+				}
+				ItemId::Spot(spot_id, metaverse_id) => {
+					T::ContinuumHandler::transfer_spot(spot_id, &auction_item.recipient, &(to.clone(), metaverse_id))?;
+					T::ContinuumHandler::record_spot_sale(spot_id, price);
+				}
+				ItemId::Estate(estate_id) => {
+					T::EstateHandler::transfer_estate(estate_id, &auction_item.recipient, to)?;
+				}
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					T::EstateHandler::transfer_landunit(
+						coordinate,
+						&auction_item.recipient,
+						&(to.clone(), metaverse_id),
+					)?;
+				}
+				_ => return Err(Error::<T>::AuctionTypeIsNotSupported.into()), // Future implementation for Metaverse
+			}
+			Ok(())
+		}
+
+		/// Check the listing can settle in the given currency
+		fn ensure_valid_auction_currency(
+			listing_level: &ListingLevel<T::AccountId>,
+			currency_id: FungibleTokenId,
+		) -> DispatchResult {
+			match listing_level {
+				ListingLevel::Local(metaverse_id) => {
+					let metaverse_currency_id = T::MetaverseInfoSource::get_metaverse_token(*metaverse_id)
+						.ok_or(Error::<T>::FungibleTokenCurrencyNotFound)?;
+					ensure!(currency_id == metaverse_currency_id, Error::<T>::InvalidAuctionCurrency);
+				}
+				_ => {
+					ensure!(
+						Self::is_native_currency(&currency_id) || WhitelistedCurrencies::<T>::contains_key(currency_id),
+						Error::<T>::CurrencyIsNotWhitelisted
+					);
+				}
+			}
+			Ok(())
+		}
+
+		pub fn is_native_currency(currency_id: &FungibleTokenId) -> bool {
+			*currency_id == FungibleTokenId::NativeToken(0)
+		}
+
+		fn currency_free_balance(currency_id: FungibleTokenId, who: &T::AccountId) -> BalanceOf<T> {
+			if Self::is_native_currency(&currency_id) {
+				<T as Config>::Currency::free_balance(who)
+			} else {
+				T::FungibleTokenCurrency::free_balance(currency_id, who).saturated_into()
+			}
+		}
+
+		fn reserve_currency(currency_id: FungibleTokenId, who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			if Self::is_native_currency(&currency_id) {
+				<T as Config>::Currency::reserve(who, amount)
+			} else {
+				T::FungibleTokenCurrency::reserve(currency_id, who, amount.saturated_into())
+			}
+		}
+
+		fn unreserve_currency(currency_id: FungibleTokenId, who: &T::AccountId, amount: BalanceOf<T>) {
+			if Self::is_native_currency(&currency_id) {
+				<T as Config>::Currency::unreserve(who, amount);
+			} else {
+				T::FungibleTokenCurrency::unreserve(currency_id, who, amount.saturated_into());
+			}
+		}
+
+		fn transfer_currency(
+			currency_id: FungibleTokenId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			if Self::is_native_currency(&currency_id) {
+				<T as Config>::Currency::transfer(from, to, amount, ExistenceRequirement::KeepAlive)
+			} else {
+				T::FungibleTokenCurrency::transfer(currency_id, from, to, amount.saturated_into())
+			}
+		}
+	}
+
+	impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
+		type Balance = BalanceOf<T>;
+
+		fn update_auction(
+			id: AuctionId,
+			info: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber>,
+		) -> DispatchResult {
+			let auction = <Auctions<T>>::get(id).ok_or(Error::<T>::AuctionNotExist)?;
+			if let Some(old_end) = auction.end {
+				<AuctionEndTime<T>>::remove(&old_end, id);
+			}
+			if let Some(new_end) = info.end {
+				<AuctionEndTime<T>>::insert(&new_end, id, ());
+			}
+			<Auctions<T>>::insert(id, info);
+			Ok(())
+		}
+
+		fn new_auction(
+			_recipient: T::AccountId,
+			_initial_amount: Self::Balance,
+			start: T::BlockNumber,
+			end: Option<T::BlockNumber>,
+		) -> Result<AuctionId, DispatchError> {
+			let auction: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber> =
+				AuctionInfo { bid: None, start, end };
+
+			let auction_id: AuctionId = AuctionsIndex::<T>::try_mutate(|n| -> Result<AuctionId, DispatchError> {
+				let id = *n;
+				ensure!(id != AuctionId::max_value(), Error::<T>::NoAvailableAuctionId);
+				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
+				Ok(id)
+			})?;
+
+			<Auctions<T>>::insert(auction_id, auction);
+
+			if let Some(end_block) = end {
+				<AuctionEndTime<T>>::insert(&end_block, auction_id, ());
+			}
+
+			Ok(auction_id)
+		}
+
+		fn create_auction(
+			auction_type: AuctionType,
+			item_id: ItemId,
+			_end: Option<T::BlockNumber>,
+			recipient: T::AccountId,
+			initial_amount: Self::Balance,
+			_start: T::BlockNumber,
+			listing_level: ListingLevel<T::AccountId>,
+		) -> Result<AuctionId, DispatchError> {
+			// Local listings settle in the metaverse token, everything else in native currency
+			let currency_id = match listing_level {
+				ListingLevel::Local(bc_id) => T::MetaverseInfoSource::get_metaverse_token(bc_id)
+					.ok_or(Error::<T>::FungibleTokenCurrencyNotFound)?,
+				_ => FungibleTokenId::NativeToken(0),
+			};
+
+			Self::do_create_auction(
+				auction_type,
+				item_id,
+				_end,
+				recipient,
+				initial_amount,
+				_start,
+				listing_level,
+				currency_id,
+			)
+		}
+
 		fn remove_auction(id: AuctionId, item_id: ItemId) {
 			if let Some(auction) = <Auctions<T>>::get(&id) {
 				if let Some(end_block) = auction.end {
 					<AuctionEndTime<T>>::remove(end_block, id);
 					<Auctions<T>>::remove(&id);
 					<ItemsInAuction<T>>::remove(item_id);
+					<ReservePrices<T>>::remove(id);
 				}
 			}
 		}
 
+		fn set_reserve_price(id: AuctionId, reserve_price: Self::Balance) -> DispatchResult {
+			ensure!(<AuctionItems<T>>::contains_key(id), Error::<T>::AuctionNotExist);
+			<ReservePrices<T>>::insert(id, reserve_price);
+			Ok(())
+		}
+
+		fn place_bid(who: T::AccountId, id: AuctionId, value: Self::Balance) -> DispatchResult {
+			let auction_item = Self::get_auction_item(id).ok_or(Error::<T>::AuctionNotExist)?;
+			Self::do_bid(who, id, value, auction_item.listing_level)
+		}
+
 		fn auction_bid_handler(
 			_now: T::BlockNumber,
 			id: AuctionId,
@@ -1069,57 +945,28 @@ pub mod pallet {
 					_ => {}
 				}
 
-				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
-				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
-
-				if let Some(last_bidder) = last_bidder {
-					//unlock reserve amount
-					if !last_bid_price.is_zero() {
-						//Unreserve balance of last bidder
-						<T as Config>::Currency::unreserve(&last_bidder, last_bid_price);
+				// First bid must meet the reserve price, if the auction has one
+				if last_bid.is_none() {
+					if let Some(reserve_price) = Self::reserve_prices(id) {
+						ensure!(new_bid_price >= reserve_price, Error::<T>::InvalidBidPrice);
 					}
 				}
 
-				// Lock fund of new bidder
-				// Reserve balance
-				<T as Config>::Currency::reserve(&new_bidder, new_bid_price)?;
-				auction_item.amount = new_bid_price.clone();
-
-				Ok(())
-			})
-		}
-
-		fn local_auction_bid_handler(
-			_now: T::BlockNumber,
-			id: AuctionId,
-			new_bid: (T::AccountId, Self::Balance),
-			last_bid: Option<(T::AccountId, Self::Balance)>,
-			social_currency_id: FungibleTokenId,
-		) -> DispatchResult {
-			let (new_bidder, new_bid_price) = new_bid;
-			ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
-
-			<AuctionItems<T>>::try_mutate_exists(id, |auction_item| -> DispatchResult {
-				let mut auction_item = auction_item.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
-
+				let currency_id = auction_item.currency_id;
 				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
 				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
 
 				if let Some(last_bidder) = last_bidder {
-					// unlock reserve amount
+					//unlock reserve amount
 					if !last_bid_price.is_zero() {
-						// Un-reserve balance of last bidder
-						T::FungibleTokenCurrency::unreserve(
-							social_currency_id,
-							&last_bidder,
-							last_bid_price.saturated_into(),
-						);
+						//Unreserve balance of last bidder
+						Self::unreserve_currency(currency_id, &last_bidder, last_bid_price);
 					}
 				}
 
 				// Lock fund of new bidder
 				// Reserve balance
-				T::FungibleTokenCurrency::reserve(social_currency_id, &new_bidder, new_bid_price.saturated_into())?;
+				Self::reserve_currency(currency_id, &new_bidder, new_bid_price)?;
 				auction_item.amount = new_bid_price.clone();
 
 				Ok(())
@@ -1148,26 +995,19 @@ pub mod pallet {
 			// Get asset detail from id
 			let asset = NFTModule::<T>::get_asset(asset_id).ok_or(Error::<T>::AssetIsNotExist)?;
 			let class_fund = NFTModule::<T>::get_class_fund(&asset.0);
+			// Supporters of the asset receive their share of the royalty first
+			let supporters_share: BalanceOf<T> = NFTModule::<T>::distribute_supporter_royalty(
+				&high_bidder,
+				asset_id,
+				social_currency_id,
+				royalty_fee.saturated_into(),
+			)?
+			.saturated_into();
+			let class_royalty_fee = royalty_fee.saturating_sub(supporters_share);
 			// Transfer loyalty fee from winner to class fund pot
-			if social_currency_id == FungibleTokenId::NativeToken(0) {
-				<T as Config>::Currency::transfer(
-					&high_bidder,
-					&class_fund,
-					royalty_fee,
-					ExistenceRequirement::KeepAlive,
-				)?;
-				// Reserve class fund pot
-				<T as Config>::Currency::reserve(&class_fund, royalty_fee)?;
-			} else {
-				T::FungibleTokenCurrency::transfer(
-					social_currency_id.clone(),
-					&high_bidder,
-					&class_fund,
-					royalty_fee.saturated_into(),
-				)?;
-				// Reserve class fund pot
-				T::FungibleTokenCurrency::reserve(social_currency_id, &class_fund, royalty_fee.saturated_into())?;
-			}
+			Self::transfer_currency(social_currency_id, &high_bidder, &class_fund, class_royalty_fee)?;
+			// Reserve class fund pot
+			Self::reserve_currency(social_currency_id, &class_fund, class_royalty_fee)?;
 			Ok(())
 		}
 	}
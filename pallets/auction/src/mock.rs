@@ -2,14 +2,18 @@
 
 use frame_support::traits::{EqualPrivilegeOnly, Nothing};
 use frame_support::{construct_runtime, pallet_prelude::Hooks, parameter_types, PalletId};
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned};
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::traits::AccountIdConversion;
-use sp_runtime::{testing::Header, traits::IdentityLookup};
-
-use auction_manager::{CheckAuctionItemHandler, ListingLevel};
-use bc_primitives::{MetaverseInfo, MetaverseTrait};
+use sp_runtime::{
+	testing::Header,
+	traits::{IdentifyAccount, IdentityLookup, Lazy, Verify},
+	Perbill,
+};
+
+use auction_manager::{CheckAuctionItemHandler, ListingLevel, SwapManager};
+use bc_primitives::{MetaverseInfo, MetaverseRole, MetaverseTrait};
 use primitives::{continuum::Continuum, estate::Estate, Amount, AuctionId, EstateId, FungibleTokenId};
 
 use crate as auction;
@@ -31,9 +35,11 @@ pub const CLASS_ID: u32 = 0;
 pub const COLLECTION_ID: u64 = 0;
 pub const ALICE_METAVERSE_ID: MetaverseId = 1;
 pub const BOB_METAVERSE_ID: MetaverseId = 2;
+pub const SOCIAL_TOKEN_ID: FungibleTokenId = FungibleTokenId::FungibleToken(1);
 
 pub const ESTATE_ID_EXIST: EstateId = 0;
 pub const ESTATE_ID_EXIST_1: EstateId = 1;
+pub const ESTATE_ID_LOCKED: EstateId = 2;
 pub const ESTATE_ID_NOT_EXIST: EstateId = 99;
 pub const LAND_UNIT_EXIST: (i32, i32) = (0, 0);
 pub const LAND_UNIT_EXIST_1: (i32, i32) = (1, 1);
@@ -83,17 +89,22 @@ impl pallet_balances::Config for Runtime {
 
 pub struct Continuumm;
 
-impl Continuum<u128> for Continuumm {
+impl Continuum<u128, Balance> for Continuumm {
 	fn transfer_spot(_spot_id: u64, _from: &AccountId, _to: &(AccountId, u64)) -> Result<u64, DispatchError> {
 		Ok(1)
 	}
+
+	fn record_spot_sale(_spot_id: u64, _price: Balance) {}
 }
 
 pub struct EstateHandler;
 
 impl Estate<u128> for EstateHandler {
-	fn transfer_estate(_estate_id: EstateId, _from: &AccountId, _to: &AccountId) -> Result<EstateId, DispatchError> {
-		Ok(1)
+	fn transfer_estate(estate_id: EstateId, _from: &AccountId, _to: &AccountId) -> Result<EstateId, DispatchError> {
+		match estate_id {
+			ESTATE_ID_LOCKED => Err(DispatchError::Other("Estate is locked")),
+			_ => Ok(1),
+		}
 	}
 
 	fn transfer_landunit(
@@ -106,7 +117,7 @@ impl Estate<u128> for EstateHandler {
 
 	fn check_estate(estate_id: EstateId) -> Result<bool, DispatchError> {
 		match estate_id {
-			ESTATE_ID_EXIST | ESTATE_ID_EXIST_1 => Ok(true),
+			ESTATE_ID_EXIST | ESTATE_ID_EXIST_1 | ESTATE_ID_LOCKED => Ok(true),
 			ESTATE_ID_NOT_EXIST => Ok(false),
 			_ => Ok(false),
 		}
@@ -199,12 +210,19 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 		}
 	}
 
+	fn has_role(who: &AccountId, metaverse_id: &MetaverseId, _role: MetaverseRole) -> bool {
+		Self::check_ownership(who, metaverse_id)
+	}
+
 	fn get_metaverse(_metaverse_id: u64) -> Option<MetaverseInfo<u128>> {
 		None
 	}
 
-	fn get_metaverse_token(_metaverse_id: u64) -> Option<FungibleTokenId> {
-		None
+	fn get_metaverse_token(metaverse_id: u64) -> Option<FungibleTokenId> {
+		match metaverse_id {
+			BOB_METAVERSE_ID => Some(SOCIAL_TOKEN_ID),
+			_ => None,
+		}
 	}
 
 	fn update_metaverse_token(_metaverse_id: u64, _currency_id: FungibleTokenId) -> Result<(), DispatchError> {
@@ -261,7 +279,13 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 2000;
 	pub MaxMetadata: u32 = 10;
-	pub PromotionIncentive: Balance = 1;
+	pub MaxPromotionCampaigns: u32 = 10;
+	pub MaxSupporters: u32 = 3;
+	pub SupportVestingPeriod: BlockNumber = 10;
+	pub MaxSupportSchedules: u32 = 2;
+	pub MinimumSupportContribution: Balance = 1;
+	pub SupporterRoyaltyShare: Perbill = Perbill::from_percent(50);
+	pub MaxSupporterRewardCurrencies: u32 = 2;
 }
 
 impl pallet_nft::Config for Runtime {
@@ -275,8 +299,40 @@ impl pallet_nft::Config for Runtime {
 	type MaxBatchMinting = MaxBatchMinting;
 	type MaxMetadata = MaxMetadata;
 	type MultiCurrency = Currencies;
-	type MiningResourceId = MiningCurrencyId;
-	type PromotionIncentive = PromotionIncentive;
+	type MaxPromotionCampaigns = MaxPromotionCampaigns;
+	type ExecutableCall = Call;
+	type ExecutableCallFilter = ();
+	type OffchainSignature = MockSignature;
+	type OffchainPublic = MockSigner;
+	type MaxSupporters = MaxSupporters;
+	type SupportVestingPeriod = SupportVestingPeriod;
+	type MaxSupportSchedules = MaxSupportSchedules;
+	type MinimumSupportContribution = MinimumSupportContribution;
+	type SupporterRoyaltyShare = SupporterRoyaltyShare;
+	type MaxSupporterRewardCurrencies = MaxSupporterRewardCurrencies;
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MockSigner(pub AccountId);
+
+impl IdentifyAccount for MockSigner {
+	type AccountId = AccountId;
+
+	fn into_account(self) -> AccountId {
+		self.0
+	}
+}
+
+/// Signature valid for the signed message and signer account it carries
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MockSignature(pub AccountId, pub Vec<u8>);
+
+impl Verify for MockSignature {
+	type Signer = MockSigner;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+		&self.0 == signer && self.1 == msg.get()
+	}
 }
 
 parameter_types! {
@@ -293,6 +349,40 @@ impl orml_nft::Config for Runtime {
 	type MaxTokenMetadata = MaxTokenMetadata;
 }
 
+pub struct DEXManager;
+
+impl SwapManager<AccountId, FungibleTokenId, Balance> for DEXManager {
+	fn add_liquidity(
+		_who: &AccountId,
+		_token_id_a: FungibleTokenId,
+		_token_id_b: FungibleTokenId,
+		_max_amount_a: Balance,
+		_max_amount_b: Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 100;
+	pub const BuyoutDelay: BlockNumber = 0;
+}
+
+impl tokenization::Config for Runtime {
+	type Event = Event;
+	type TokenId = u64;
+	type MetaverseMultiCurrency = Currencies;
+	type FungibleTokenTreasury = MetaverseFundPalletId;
+	type MetaverseInfoSource = MetaverseInfoSource;
+	type LiquidityPoolManager = DEXManager;
+	type MinVestedTransfer = MinVestedTransfer;
+	type VestedTransferOrigin = EnsureSigned<AccountId>;
+	type NFTHandler = NFTModule;
+	type AuctionHandler = AuctionModule;
+	type BuyoutDelay = BuyoutDelay;
+	type WeightInfo = ();
+}
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -310,6 +400,7 @@ construct_runtime!(
 		NFTModule: pallet_nft::{Pallet, Storage ,Call, Event<T>},
 		OrmlNft: orml_nft::{Pallet, Storage, Config<T>},
 		AuctionModule: auction::{Pallet, Call, Storage, Event<T>},
+		Tokenization: tokenization::{Pallet, Call, Storage, Event<T>},
 	}
 );
 pub struct ExtBuilder;
@@ -336,6 +427,12 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: vec![(ALICE, SOCIAL_TOKEN_ID, 1000), (BOB, SOCIAL_TOKEN_ID, 1000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(block_number));
 		ext
@@ -395,21 +492,19 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 
 	fn remove_auction(_id: u64, _item_id: ItemId) {}
 
-	fn auction_bid_handler(
-		_now: u64,
-		_id: u64,
-		_new_bid: (u128, Self::Balance),
-		_last_bid: Option<(u128, Self::Balance)>,
-	) -> DispatchResult {
+	fn set_reserve_price(_id: u64, _reserve_price: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn place_bid(_who: u128, _id: u64, _value: Self::Balance) -> DispatchResult {
 		Ok(())
 	}
 
-	fn local_auction_bid_handler(
+	fn auction_bid_handler(
 		_now: u64,
 		_id: u64,
 		_new_bid: (u128, Self::Balance),
 		_last_bid: Option<(u128, Self::Balance)>,
-		_social_currency_id: FungibleTokenId,
 	) -> DispatchResult {
 		Ok(())
 	}
@@ -1,6 +1,8 @@
 #![cfg(test)]
 
 use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use sp_runtime::traits::BadOrigin;
 use sp_std::collections::btree_map::BTreeMap;
 
 use auction_manager::ListingLevel;
@@ -20,6 +22,7 @@ fn init_test_nft(owner: Origin) {
 		COLLECTION_ID,
 		TokenType::Transferable,
 		CollectionType::Collectable,
+		None,
 	));
 
 	assert_ok!(NFTModule::<Runtime>::mint(
@@ -157,6 +160,7 @@ fn create_auction_fail() {
 			COLLECTION_ID,
 			TokenType::Transferable,
 			CollectionType::Collectable,
+			None,
 		));
 
 		assert_ok!(NFTModule::<Runtime>::mint(
@@ -187,6 +191,7 @@ fn create_auction_fail() {
 			COLLECTION_ID,
 			TokenType::BoundToAddress,
 			CollectionType::Collectable,
+			None,
 		));
 
 		assert_ok!(NFTModule::<Runtime>::mint(
@@ -257,6 +262,37 @@ fn remove_auction_work() {
 	});
 }
 
+#[test]
+fn place_bid_should_follow_bid_rules() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::Spot(0, 1),
+			None,
+			BOB,
+			0,
+			0,
+			ListingLevel::NetworkSpot(vec![ALICE])
+		));
+
+		assert_noop!(
+			<AuctionModule as Auction<AccountId, BlockNumber>>::place_bid(BOB, 0, 100),
+			Error::<Runtime>::SelfBidNotAccepted
+		);
+		assert_ok!(<AuctionModule as Auction<AccountId, BlockNumber>>::place_bid(
+			ALICE, 0, 100
+		));
+		assert_eq!(last_event(), Event::AuctionModule(crate::Event::Bid(0, ALICE, 100)));
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+		assert_eq!(AuctionModule::auctions(0).unwrap().bid, Some((ALICE, 100)));
+
+		assert_noop!(
+			<AuctionModule as Auction<AccountId, BlockNumber>>::place_bid(ALICE, 0, 100),
+			Error::<Runtime>::InvalidBidPrice
+		);
+	});
+}
+
 #[test]
 // Walk the happy path
 fn bid_works() {
@@ -327,6 +363,90 @@ fn bid_works_for_valid_land_unit() {
 	});
 }
 
+#[test]
+fn first_bid_only_needs_to_meet_reserve_price_when_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+
+		// Ordinary auctions accept any non-zero first bid
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 50));
+		assert_eq!(AuctionModule::reserve_prices(0), None);
+
+		init_test_nft(Origin::signed(BOB));
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(1),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+		assert_noop!(
+			AuctionModule::set_reserve_price(2, 100),
+			Error::<Runtime>::AuctionNotExist
+		);
+		assert_ok!(AuctionModule::set_reserve_price(1, 100));
+
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 1, 99),
+			Error::<Runtime>::InvalidBidPrice
+		);
+		assert_noop!(
+			AuctionModule::auction_bid_handler(1, 1, (ALICE, 99), None),
+			Error::<Runtime>::InvalidBidPrice
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 1, 100));
+
+		run_to_block(102);
+		assert_eq!(AuctionModule::reserve_prices(1), None);
+	});
+}
+
+#[test]
+fn buyout_of_fractionalized_nft_should_require_reserve_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+		assert_ok!(Tokenization::fractionalize(
+			Origin::signed(BOB),
+			0,
+			b"SHR".to_vec(),
+			1000,
+			200
+		));
+		let vault_account = Tokenization::get_fractional_vault_account(0);
+		assert_ok!(Tokenization::trigger_buyout(Origin::signed(ALICE), 0));
+		assert_eq!(
+			AuctionModule::get_auction_item(0).map(|item| (item.recipient, item.initial_amount)),
+			Some((vault_account, 200))
+		);
+		assert_eq!(AuctionModule::reserve_prices(0), Some(200));
+
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 1),
+			Error::<Runtime>::InvalidBidPrice
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		run_to_block(102);
+		assert_eq!(OrmlNft::tokens(CLASS_ID, 0).map(|token| token.owner), Some(ALICE));
+
+		// Vault keeps the winning bid minus the 1% royalty fee
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(Tokenization::redeem_shares(Origin::signed(BOB), 0));
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 198);
+	});
+}
+
 #[test]
 fn cannot_bid_on_non_existent_auction() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -430,6 +550,81 @@ fn asset_transfers_after_auction() {
 	});
 }
 
+#[test]
+fn failed_settlement_should_release_bid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id = ItemId::Estate(ESTATE_ID_LOCKED);
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			item_id,
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		assert_eq!(Balances::reserved_balance(ALICE), 200);
+
+		run_to_block(102);
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionSettlementFailed(
+				0,
+				DispatchError::Other("Estate is locked")
+			))
+		);
+
+		// Payment to the seller is reverted and the bid released
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(AuctionModule::items_in_auction(item_id), None);
+	});
+}
+
+#[test]
+fn failed_buy_now_transfer_should_revert_payment() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id = ItemId::Estate(ESTATE_ID_LOCKED);
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			item_id,
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global
+		));
+
+		assert_noop!(
+			AuctionModule::buy_now(Origin::signed(ALICE), 0, 150),
+			DispatchError::Other("Estate is locked")
+		);
+
+		// Payment is reverted and the listing is still live
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(AuctionModule::items_in_auction(item_id), Some(true));
+	});
+}
+
+#[test]
+fn local_spot_auction_should_settle_in_metaverse_token() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::Spot(0, BOB_METAVERSE_ID),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Local(BOB_METAVERSE_ID)
+		));
+		assert_eq!(AuctionModule::get_auction_item(0).unwrap().currency_id, SOCIAL_TOKEN_ID);
+	});
+}
+
 #[test]
 fn cannot_bid_on_ended_auction() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -776,3 +971,183 @@ fn on_finalize_should_work() {
 		assert_eq!(last_event(), event);
 	});
 }
+
+#[test]
+// Whitelisting auction currency should only work for governance
+fn whitelist_currency_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::whitelist_currency(Origin::signed(ALICE), SOCIAL_TOKEN_ID),
+			BadOrigin
+		);
+
+		assert_ok!(AuctionModule::whitelist_currency(Origin::root(), SOCIAL_TOKEN_ID));
+		assert_eq!(AuctionModule::whitelisted_currencies(SOCIAL_TOKEN_ID), Some(()));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::CurrencyWhitelisted(SOCIAL_TOKEN_ID))
+		);
+
+		assert_noop!(
+			AuctionModule::whitelist_currency(Origin::root(), SOCIAL_TOKEN_ID),
+			Error::<Runtime>::CurrencyAlreadyWhitelisted
+		);
+
+		assert_ok!(AuctionModule::remove_whitelisted_currency(
+			Origin::root(),
+			SOCIAL_TOKEN_ID
+		));
+		assert_eq!(AuctionModule::whitelisted_currencies(SOCIAL_TOKEN_ID), None);
+		assert_noop!(
+			AuctionModule::remove_whitelisted_currency(Origin::root(), SOCIAL_TOKEN_ID),
+			Error::<Runtime>::CurrencyIsNotWhitelisted
+		);
+	});
+}
+
+#[test]
+// Global listing in non-whitelisted currency should fail
+fn create_global_auction_with_non_whitelisted_currency_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				owner.clone(),
+				ItemId::NFT(0),
+				100,
+				101,
+				ListingLevel::Global,
+				SOCIAL_TOKEN_ID
+			),
+			Error::<Runtime>::CurrencyIsNotWhitelisted
+		);
+	});
+}
+
+#[test]
+// Global auction settled in whitelisted fungible token should work
+fn global_auction_with_whitelisted_currency_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		let bidder = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+
+		assert_ok!(AuctionModule::whitelist_currency(Origin::root(), SOCIAL_TOKEN_ID));
+		assert_ok!(AuctionModule::create_new_auction(
+			owner.clone(),
+			ItemId::NFT(0),
+			100,
+			101,
+			ListingLevel::Global,
+			SOCIAL_TOKEN_ID
+		));
+		assert_eq!(AuctionModule::get_auction_item(0).unwrap().currency_id, SOCIAL_TOKEN_ID);
+
+		assert_ok!(AuctionModule::bid(bidder, 0, 200));
+		assert_eq!(Tokens::reserved_balance(SOCIAL_TOKEN_ID, &ALICE), 200);
+		// native balance is untouched
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+		run_to_block(102);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0]);
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN_ID, &ALICE), 800);
+		// BOB receives 200 - 2 (1% of 200 as royalty fee)
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN_ID, &BOB), 1198);
+		assert_eq!(
+			Tokens::reserved_balance(SOCIAL_TOKEN_ID, &NFTModule::<Runtime>::get_class_fund(&CLASS_ID)),
+			2
+		);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+
+		let event = mock::Event::AuctionModule(crate::Event::AuctionFinalized(0, ALICE, 200));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+// Global buy now settled in whitelisted fungible token should work
+fn global_buy_now_with_whitelisted_currency_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		let buyer = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+
+		assert_ok!(AuctionModule::whitelist_currency(Origin::root(), SOCIAL_TOKEN_ID));
+		assert_ok!(AuctionModule::create_new_buy_now(
+			owner.clone(),
+			ItemId::NFT(0),
+			150,
+			101,
+			ListingLevel::Global,
+			SOCIAL_TOKEN_ID
+		));
+
+		assert_noop!(
+			AuctionModule::buy_now_local(buyer.clone(), 0, ALICE_METAVERSE_ID, 150),
+			Error::<Runtime>::WrongListingLevel
+		);
+		assert_ok!(AuctionModule::buy_now(buyer.clone(), 0, 150));
+
+		assert_eq!(NFTModule::<Runtime>::get_assets_by_owner(ALICE), [0]);
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN_ID, &ALICE), 850);
+		// BOB receives 150 - 1 (1% of 150 as royalty fee)
+		assert_eq!(Tokens::free_balance(SOCIAL_TOKEN_ID, &BOB), 1149);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+
+		let event = mock::Event::AuctionModule(crate::Event::BuyNowFinalised(0, ALICE, 150));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+// Local listings need the Marketplace role of the metaverse
+fn create_local_listing_should_require_marketplace_role() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+
+		assert_noop!(
+			AuctionModule::create_new_auction(
+				owner.clone(),
+				ItemId::NFT(0),
+				100,
+				101,
+				ListingLevel::Local(BOB_METAVERSE_ID),
+				SOCIAL_TOKEN_ID
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+		assert_noop!(
+			AuctionModule::create_new_buy_now(
+				owner.clone(),
+				ItemId::NFT(0),
+				100,
+				101,
+				ListingLevel::Local(BOB_METAVERSE_ID),
+				SOCIAL_TOKEN_ID
+			),
+			Error::<Runtime>::NoPermissionToCreateAuction
+		);
+	});
+}
+
+#[test]
+fn create_local_buy_now_with_marketplace_role_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+
+		assert_ok!(AuctionModule::create_new_buy_now(
+			owner.clone(),
+			ItemId::NFT(0),
+			150,
+			101,
+			ListingLevel::Local(BOB_METAVERSE_ID),
+			SOCIAL_TOKEN_ID
+		));
+		assert_eq!(AuctionModule::get_auction_item(0).unwrap().currency_id, SOCIAL_TOKEN_ID);
+	});
+}
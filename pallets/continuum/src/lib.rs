@@ -33,32 +33,50 @@
 //!
 //! Continuum Spot Auction Process (rotate every x block):
 //! - Slot Registration (Express of Interest) - metaverse owner can register for their favourite
-//!   slot
-//! - Highest registered slot will move to Auction slots.
+//!   slot with a refundable deposit
+//! - Slots with the highest total deposit move to Auction slots, ties go to the earliest
+//!   registered slot. Deposits of slots that are not selected are refunded
 //! - The Auction slot will move to Good neighborhood protocol to start voting by neighbor of the
 //!   spot
-//! - Simple majority negative voting applied - the bidder who has more than 51% vote nay will be
-//!   rejected
-//! - The auction will start on pallet_auction.
+//! - Negative voting applied - only owners of the neighbouring spots can vote, weighted by the
+//!   number of neighbouring spots they own and optionally their conviction. Each neighbouring spot
+//!   votes once on a bidder, whoever owns it. The bidder whose nay votes are above the approval
+//!   threshold of the turnout will be rejected
+//! - The auction will start on pallet_auction. Approved participants can apply their deposit as
+//!   their bid or withdraw it once the auction is over, rejected participants are refunded.
+//! - A spot taken by a rejected participant while the referendum was ongoing is evicted back to
+//!   the continuum treasury, which refunds its acquisition price to the metaverse owner.
+//!
+//! Owners can release their spot back to the continuum treasury, refunded on the governance
+//! configured refund curve. Governance can reclaim spots of frozen or destroyed metaverses.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
 use codec::{Decode, Encode};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, PalletId};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	storage::{with_transaction, TransactionOutcome},
+	traits::Get,
+	transactional,
+	weights::Weight,
+	BoundedVec, PalletId,
+};
 use frame_system::{ensure_root, ensure_signed};
-use primitives::{continuum::Continuum, ItemId, MetaverseId, SpotId};
+use primitives::{continuum::Continuum, AuctionId, ItemId, MetaverseId, SpotId};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
-	DispatchError, RuntimeDebug,
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, One, Saturating, Zero},
+	DispatchError, Perbill, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::vec;
 use sp_std::vec::Vec;
 
 use auction_manager::{Auction, AuctionType, CheckAuctionItemHandler, ListingLevel};
-use bc_primitives::MetaverseTrait;
-use frame_support::traits::{Currency, LockableCurrency, ReservableCurrency};
+use bc_primitives::{MetaverseRole, MetaverseTrait};
+use frame_support::traits::{Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency};
 
 #[cfg(feature = "std")]
 use frame_support::traits::GenesisBuild;
@@ -69,6 +87,7 @@ mod vote;
 pub use types::*;
 pub use vote::*;
 
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -90,9 +109,15 @@ pub enum ContinuumAuctionSlotStatus {
 /// Information of EOI on Continuum spot
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
 #[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
-pub struct SpotEOI<AccountId> {
+#[scale_info(skip_type_params(T))]
+pub struct SpotEOI<T: Config> {
 	spot_id: SpotId,
-	participants: Vec<AccountId>,
+	/// Participants and their deposit
+	participants: BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxEOIParticipants>,
+	/// Sum of the participants deposit
+	total_deposit: BalanceOf<T>,
+	/// Block of the first EOI on the spot
+	registered_at: T::BlockNumber,
 }
 
 /// Information of an active auction slot
@@ -108,9 +133,9 @@ pub struct AuctionSlot<BlockNumber, AccountId> {
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::traits::ExistenceRequirement;
 	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
 	use frame_system::pallet_prelude::OriginFor;
+	use sp_std::convert::TryInto;
 
 	pub(crate) type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -132,7 +157,8 @@ pub mod pallet {
 		/// Emergency shutdown origin which allow cancellation in an emergency
 		type EmergencyOrigin: EnsureOrigin<Self::Origin>;
 		/// Auction Handler
-		type AuctionHandler: Auction<Self::AccountId, Self::BlockNumber> + CheckAuctionItemHandler;
+		type AuctionHandler: Auction<Self::AccountId, Self::BlockNumber, Balance = BalanceOf<Self>>
+			+ CheckAuctionItemHandler;
 		/// Auction duration
 		#[pallet::constant]
 		type AuctionDuration: Get<Self::BlockNumber>;
@@ -144,6 +170,28 @@ pub mod pallet {
 			+ LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
 		/// Source of Metaverse Network Info
 		type MetaverseInfoSource: MetaverseTrait<Self::AccountId>;
+		/// Share of the turnout voting nay above which a bidder is rejected by Good Neighbourhood
+		/// Protocol
+		#[pallet::constant]
+		type GNPApprovalThreshold: Get<Perbill>;
+		/// Maximum number of premium zones on continuum map
+		#[pallet::constant]
+		type MaxPremiumZones: Get<u32>;
+		/// Number of recent auction sales used to adjust spot prices
+		#[pallet::constant]
+		type MaxRecentSpotSales: Get<u32>;
+		/// Maximum factor recent auction sales can raise or lower the model spot price by
+		#[pallet::constant]
+		type MaxSpotPriceAdjustment: Get<u32>;
+		/// Minimum deposit reserved with an express of interest
+		#[pallet::constant]
+		type MinimumEOIDeposit: Get<BalanceOf<Self>>;
+		/// Maximum number of spots with express of interest in a session
+		#[pallet::constant]
+		type MaxEOISpots: Get<u32>;
+		/// Maximum number of participants of the express of interest on a spot
+		#[pallet::constant]
+		type MaxEOIParticipants: Get<u32>;
 	}
 
 	#[pallet::genesis_config]
@@ -171,7 +219,7 @@ pub mod pallet {
 		fn build(&self) {
 			CurrentIndex::<T>::set(self.initial_active_session);
 			MaxDesiredAuctionSlot::<T>::set(self.initial_auction_rate);
-			let eoi_slots: Vec<SpotEOI<T::AccountId>> = vec![];
+			let eoi_slots: BoundedVec<SpotEOI<T>, T::MaxEOISpots> = Default::default();
 			let gnp_slots: Vec<AuctionSlot<T::BlockNumber, T::AccountId>> = vec![];
 			let active_auction_slots: Vec<AuctionSlot<T::BlockNumber, T::AccountId>> = vec![];
 			EOISlots::<T>::insert(self.initial_active_session, eoi_slots);
@@ -182,7 +230,10 @@ pub mod pallet {
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::hooks]
@@ -191,12 +242,31 @@ pub mod pallet {
 		fn on_initialize(now: T::BlockNumber) -> Weight {
 			let auction_duration: T::BlockNumber = T::SessionDuration::get();
 			if !auction_duration.is_zero() && (now % auction_duration).is_zero() {
-				Self::rotate_auction_slots(now);
-				20_000_000
+				// Rotation is all or nothing, a failing step must not leave deposits refunded or
+				// slots half moved
+				let result = with_transaction(|| match Self::rotate_auction_slots(now) {
+					Ok(()) => TransactionOutcome::Commit(Ok(())),
+					Err(e) => TransactionOutcome::Rollback(Err(e)),
+				});
+				if let Err(e) = result {
+					Self::deposit_event(Event::AuctionSlotRotationFailed(now, e));
+				}
+				Self::rotation_weight()
 			} else {
 				0
 			}
 		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+
+			if StorageVersion::get::<Pallet<T>>() < 1 {
+				weight = weight.saturating_add(migrations::migrate_to_v1::<T>());
+				StorageVersion::new(1).put::<Pallet<T>>();
+			}
+
+			weight
+		}
 	}
 
 	/// Get current active session
@@ -229,7 +299,20 @@ pub mod pallet {
 	/// Active set of EOI on Continuum Spot
 	#[pallet::storage]
 	#[pallet::getter(fn get_eoi_set)]
-	pub type EOISlots<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, Vec<SpotEOI<T::AccountId>>, ValueQuery>;
+	pub type EOISlots<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, BoundedVec<SpotEOI<T>, T::MaxEOISpots>, ValueQuery>;
+
+	/// Deposits of EOI selected for auction slots, held until applied to the spot auction bid or
+	/// withdrawn
+	#[pallet::storage]
+	#[pallet::getter(fn get_eoi_deposit)]
+	pub type EOIDeposits<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SpotId, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Auction created for spot after Good Neighbourhood Protocol
+	#[pallet::storage]
+	#[pallet::getter(fn get_spot_auction)]
+	pub type SpotAuctions<T: Config> = StorageMap<_, Twox64Concat, SpotId, AuctionId, OptionQuery>;
 
 	/// Information of Continuum Spot Referendum
 	#[pallet::storage]
@@ -240,7 +323,14 @@ pub mod pallet {
 	/// All votes of a particular voter
 	#[pallet::storage]
 	#[pallet::getter(fn get_voting_info)]
-	pub type VotingOf<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Voting<T::AccountId>, ValueQuery>;
+	pub type VotingOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Voting<T::AccountId, T::BlockNumber>, ValueQuery>;
+
+	/// Accounts that voted on the ongoing referendum of spot, their votes are cleared when the
+	/// referendum finishes
+	#[pallet::storage]
+	#[pallet::getter(fn get_referendum_voters)]
+	pub type ReferendumVoters<T: Config> = StorageMap<_, Twox64Concat, SpotId, Vec<T::AccountId>, ValueQuery>;
 
 	/// Get max bound
 	#[pallet::storage]
@@ -269,11 +359,54 @@ pub mod pallet {
 	#[pallet::getter(fn initial_spot_price)]
 	pub type SpotPrice<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// Pricing model of continuum spots, the flat spot price applies if not set
+	#[pallet::storage]
+	#[pallet::getter(fn get_spot_pricing)]
+	pub type SpotPricingModel<T: Config> = StorageValue<_, SpotPricing<BalanceOf<T>>, OptionQuery>;
+
+	/// Premium zones on continuum map
+	#[pallet::storage]
+	#[pallet::getter(fn get_premium_zones)]
+	pub type PremiumZones<T: Config> =
+		StorageValue<_, BoundedVec<PremiumZone<BalanceOf<T>>, T::MaxPremiumZones>, ValueQuery>;
+
+	/// Recent auction sales of spots as sale price and model price at the time of sale, oldest
+	/// first
+	#[pallet::storage]
+	#[pallet::getter(fn get_recent_spot_sales)]
+	pub type RecentSpotSales<T: Config> =
+		StorageValue<_, BoundedVec<(BalanceOf<T>, BalanceOf<T>), T::MaxRecentSpotSales>, ValueQuery>;
+
+	/// Refund curve of released spots, nothing is refunded if not set
+	#[pallet::storage]
+	#[pallet::getter(fn get_release_refund_curve)]
+	pub type ReleaseRefundCurve<T: Config> = StorageValue<_, SpotRefundCurve<T::BlockNumber>, OptionQuery>;
+
+	/// Price paid and block of the last sale of spot
+	#[pallet::storage]
+	#[pallet::getter(fn get_spot_acquisition)]
+	pub type SpotAcquisitions<T: Config> =
+		StorageMap<_, Twox64Concat, SpotId, (BalanceOf<T>, T::BlockNumber), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// New express of interest
-		NewExpressOfInterestAdded(T::AccountId, SpotId),
+		/// New express of interest [who, spot, deposit]
+		NewExpressOfInterestAdded(T::AccountId, SpotId, BalanceOf<T>),
+		/// EOI on spot selected for auction slot [spot, total deposit]
+		EOISelectedForAuction(SpotId, BalanceOf<T>),
+		/// EOI deposit refunded [who, spot, deposit]
+		EOIDepositRefunded(T::AccountId, SpotId, BalanceOf<T>),
+		/// EOI deposit applied as bid on spot auction [who, spot, auction, deposit]
+		EOIDepositAppliedToBid(T::AccountId, SpotId, AuctionId, BalanceOf<T>),
+		/// Refund curve of released spots updated
+		ReleaseRefundCurveUpdated(Option<SpotRefundCurve<T::BlockNumber>>),
+		/// Spot released to continuum treasury [who, spot, metaverse, refund]
+		SpotReleased(T::AccountId, SpotId, MetaverseId, BalanceOf<T>),
+		/// Spot of frozen or destroyed metaverse reclaimed by governance [spot, metaverse]
+		SpotReclaimed(SpotId, MetaverseId),
+		/// Spot of participant rejected by Good Neighbourhood Protocol evicted [spot, metaverse, refund]
+		SpotEvicted(SpotId, MetaverseId, BalanceOf<T>),
 		/// New max bound set on continuum map
 		NewMaxBoundSet((i32, i32)),
 		/// Emergency shutdown is on
@@ -288,8 +421,20 @@ pub mod pallet {
 		NewMaxAuctionSlotSet(u8),
 		/// Rotated new auction slot
 		NewAuctionSlotRotated(T::BlockNumber),
+		/// Auction slot rotation failed and was reverted [block, error]
+		AuctionSlotRotationFailed(T::BlockNumber, DispatchError),
 		/// Finalize vote
 		FinalizedVote(SpotId),
+		/// Vote cast on spot referendum [voter, spot, participant, nay, votes]
+		ContinuumVoteCast(T::AccountId, SpotId, T::AccountId, bool, u32),
+		/// Vote removed from spot referendum [voter, spot, participant]
+		ContinuumVoteRemoved(T::AccountId, SpotId, T::AccountId),
+		/// Spot pricing model updated
+		SpotPricingUpdated(SpotPricing<BalanceOf<T>>),
+		/// Premium zones updated [number of zones]
+		PremiumZonesUpdated(u32),
+		/// Auction sale of spot recorded [spot, price]
+		SpotSaleRecorded(SpotId, BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -328,6 +473,38 @@ pub mod pallet {
 		ContinuumBuyNowIsDisabled,
 		/// Continuum Spot is in auction
 		SpotIsInAuction,
+		/// Account is not a participant of the spot referendum
+		ParticipantNotFound,
+		/// Participant can't vote on themselves
+		CannotVoteForSelf,
+		/// Already voted on the participant
+		AlreadyVoted,
+		/// Vote not found
+		VoteNotFound,
+		/// Vote with conviction can't be removed until the referendum ends
+		VoteIsLocked,
+		/// Exceeded maximum number of premium zones
+		TooManyPremiumZones,
+		/// EOI deposit is below minimum
+		EOIDepositTooLow,
+		/// No EOI deposit on the spot
+		EOIDepositNotFound,
+		/// No auction created for the spot
+		SpotAuctionNotFound,
+		/// Spot auction already ended
+		SpotAuctionEnded,
+		/// Deposit is not higher than the current bid
+		EOIDepositBelowCurrentBid,
+		/// Spot is not owned by any metaverse
+		SpotIsNotOwned,
+		/// Metaverse of the spot is neither frozen nor destroyed
+		MetaverseIsActive,
+		/// Session reached the maximum number of spots with express of interest
+		TooManyEOISpots,
+		/// Spot reached the maximum number of express of interest participants
+		TooManyEOIParticipants,
+		/// Metaverse of the spot is not found
+		MetaverseNotFound,
 	}
 
 	#[pallet::call]
@@ -341,7 +518,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			ensure!(
-				T::MetaverseInfoSource::check_ownership(&sender, &metaverse_id),
+				T::MetaverseInfoSource::has_role(&sender, &metaverse_id, MetaverseRole::LandManager),
 				Error::<T>::NoPermission
 			);
 			ensure!(AllowBuyNow::<T>::get() == true, Error::<T>::ContinuumBuyNowIsDisabled);
@@ -354,7 +531,7 @@ pub mod pallet {
 			}
 
 			let spot_id = Self::check_spot_ownership(maybe_spot_id, coordinate)?;
-			let continuum_price_spot = SpotPrice::<T>::get();
+			let continuum_price_spot = Self::get_spot_price(coordinate);
 
 			let continuum_treasury = Self::account_id();
 
@@ -370,10 +547,64 @@ pub mod pallet {
 			)?;
 
 			Self::do_transfer_spot(spot_id, &continuum_treasury, &(sender, metaverse_id))?;
+			SpotAcquisitions::<T>::insert(
+				spot_id,
+				(continuum_price_spot, frame_system::Pallet::<T>::block_number()),
+			);
 
 			Ok(().into())
 		}
 
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		/// Release owned spot back to continuum treasury, refunded on the release refund curve
+		#[transactional]
+		pub fn release_spot(origin: OriginFor<T>, spot_id: SpotId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let spot = ContinuumSpots::<T>::try_get(spot_id).map_err(|_| Error::<T>::ContinuumSpotNotFound)?;
+			ensure!(spot.metaverse_id != 0, Error::<T>::SpotIsNotOwned);
+			ensure!(
+				T::MetaverseInfoSource::has_role(&sender, &spot.metaverse_id, MetaverseRole::LandManager),
+				Error::<T>::NoPermission
+			);
+
+			let refund = Self::release_refund(spot_id);
+			Self::do_release_spot(spot_id, &spot)?;
+			if !refund.is_zero() {
+				T::Currency::transfer(&Self::account_id(), &sender, refund, ExistenceRequirement::KeepAlive)?;
+			}
+
+			Self::deposit_event(Event::SpotReleased(sender, spot_id, spot.metaverse_id, refund));
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		/// Council reclaim spot of frozen or destroyed metaverse without refund
+		pub fn reclaim_spot(origin: OriginFor<T>, spot_id: SpotId) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let spot = ContinuumSpots::<T>::try_get(spot_id).map_err(|_| Error::<T>::ContinuumSpotNotFound)?;
+			ensure!(spot.metaverse_id != 0, Error::<T>::SpotIsNotOwned);
+			if let Some(metaverse) = T::MetaverseInfoSource::get_metaverse(spot.metaverse_id) {
+				ensure!(metaverse.is_frozen, Error::<T>::MetaverseIsActive);
+			}
+
+			Self::do_release_spot(spot_id, &spot)?;
+
+			Self::deposit_event(Event::SpotReclaimed(spot_id, spot.metaverse_id));
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		/// Council set refund curve of released spots
+		pub fn set_release_refund_curve(
+			origin: OriginFor<T>,
+			curve: Option<SpotRefundCurve<T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ReleaseRefundCurve::<T>::set(curve.clone());
+			Self::deposit_event(Event::ReleaseRefundCurveUpdated(curve));
+			Ok(().into())
+		}
+
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		/// Whether council enable buy now option
 		pub fn set_allow_buy_now(origin: OriginFor<T>, enable: bool) -> DispatchResultWithPostInfo {
@@ -383,17 +614,21 @@ pub mod pallet {
 		}
 
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		/// Register continuum slot interest
+		/// Register continuum slot interest, the deposit is reserved until refunded or applied to
+		/// the spot auction bid
+		#[transactional]
 		pub fn register_interest(
 			origin: OriginFor<T>,
 			metaverse_id: MetaverseId,
 			coordinate: (i32, i32),
+			deposit: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			ensure!(
-				T::MetaverseInfoSource::check_ownership(&sender, &metaverse_id),
+				T::MetaverseInfoSource::has_role(&sender, &metaverse_id, MetaverseRole::LandManager),
 				Error::<T>::NoPermission
 			);
+			ensure!(deposit >= T::MinimumEOIDeposit::get(), Error::<T>::EOIDepositTooLow);
 			let mut maybe_spot_id = Option::None;
 
 			if ContinuumCoordinates::<T>::contains_key(coordinate) {
@@ -405,46 +640,96 @@ pub mod pallet {
 
 			// Get current active session
 			let current_active_session_id = CurrentIndex::<T>::get();
-
-			if EOISlots::<T>::contains_key(current_active_session_id) {
-				// Mutate current active EOI Slot session
-				EOISlots::<T>::try_mutate(current_active_session_id, |spot_eoi| -> DispatchResult {
-					// Check if the interested Spot exists
-					let interested_spot_index: Option<usize> = spot_eoi.iter().position(|x| x.spot_id == spot_id);
-					match interested_spot_index {
-						// Already got participants
-						Some(index) => {
-							// Works on existing eoi index
-							let interested_spot = spot_eoi.get_mut(index).ok_or("No Spot EOI exist")?;
-
-							interested_spot.participants.push(sender.clone());
-						}
-						// No participants - add one
-						None => {
-							// No spot found - first one in EOI
-							let mut new_list: Vec<T::AccountId> = Vec::new();
-							new_list.push(sender.clone());
-
-							let _spot_eoi = SpotEOI {
+			let now = frame_system::Pallet::<T>::block_number();
+
+			// Mutate current active EOI Slot session
+			EOISlots::<T>::try_mutate(current_active_session_id, |spot_eoi| -> DispatchResult {
+				// Check if the interested Spot exists
+				let interested_spot_index: Option<usize> = spot_eoi.iter().position(|x| x.spot_id == spot_id);
+				match interested_spot_index {
+					// Already got participants
+					Some(index) => {
+						// Works on existing eoi index
+						let interested_spot = spot_eoi.get_mut(index).ok_or("No Spot EOI exist")?;
+						ensure!(
+							!interested_spot.participants.iter().any(|(who, _)| *who == sender),
+							Error::<T>::EOIAlreadyExists
+						);
+
+						interested_spot
+							.participants
+							.try_push((sender.clone(), deposit))
+							.map_err(|_| Error::<T>::TooManyEOIParticipants)?;
+						interested_spot.total_deposit = interested_spot.total_deposit.saturating_add(deposit);
+					}
+					// No participants - add one
+					None => {
+						// No spot found - first one in EOI
+						let participants = vec![(sender.clone(), deposit)]
+							.try_into()
+							.map_err(|_| Error::<T>::TooManyEOIParticipants)?;
+						spot_eoi
+							.try_push(SpotEOI {
 								spot_id,
-								participants: new_list,
-							};
-							spot_eoi.push(_spot_eoi);
-						}
+								participants,
+								total_deposit: deposit,
+								registered_at: now,
+							})
+							.map_err(|_| Error::<T>::TooManyEOISpots)?;
 					}
-					Ok(())
-				})?;
-			} else {
-				// Never get to this logic but it's safe to handle it nicely.
-				let mut eoi_slots: Vec<SpotEOI<T::AccountId>> = Vec::new();
-				eoi_slots.push(SpotEOI {
-					spot_id,
-					participants: vec![sender.clone()],
-				});
-				EOISlots::<T>::insert(current_active_session_id, eoi_slots);
+				}
+				Ok(())
+			})?;
+
+			T::Currency::reserve(&sender, deposit)?;
+
+			Self::deposit_event(Event::NewExpressOfInterestAdded(sender, spot_id, deposit));
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		/// Apply EOI deposit as bid on the spot auction, the deposit must be higher than the current
+		/// bid
+		#[transactional]
+		pub fn apply_eoi_deposit(origin: OriginFor<T>, spot_id: SpotId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let auction_id = SpotAuctions::<T>::get(spot_id).ok_or(Error::<T>::SpotAuctionNotFound)?;
+			let auction = T::AuctionHandler::auction_info(auction_id).ok_or(Error::<T>::SpotAuctionNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(auction.end.map_or(true, |end| now < end), Error::<T>::SpotAuctionEnded);
+
+			let deposit = EOIDeposits::<T>::take(spot_id, &sender);
+			ensure!(!deposit.is_zero(), Error::<T>::EOIDepositNotFound);
+			if let Some((_, current_bid)) = auction.bid {
+				ensure!(deposit > current_bid, Error::<T>::EOIDepositBelowCurrentBid);
 			}
 
-			Self::deposit_event(Event::NewExpressOfInterestAdded(sender, spot_id));
+			// Auction reserves the bid again
+			T::Currency::unreserve(&sender, deposit);
+			T::AuctionHandler::place_bid(sender.clone(), auction_id, deposit)?;
+
+			Self::deposit_event(Event::EOIDepositAppliedToBid(sender, spot_id, auction_id, deposit));
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		/// Withdraw EOI deposit that was not applied once the spot auction is over
+		pub fn withdraw_eoi_deposit(origin: OriginFor<T>, spot_id: SpotId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				SpotAuctions::<T>::contains_key(spot_id),
+				Error::<T>::SpotAuctionNotFound
+			);
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::Spot(spot_id, Default::default())),
+				Error::<T>::SpotIsInAuction
+			);
+
+			let deposit = EOIDeposits::<T>::take(spot_id, &sender);
+			ensure!(!deposit.is_zero(), Error::<T>::EOIDepositNotFound);
+			Self::refund_eoi_deposit(&sender, spot_id, deposit);
 			Ok(().into())
 		}
 
@@ -458,6 +743,33 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		// Council set pricing model of continuum spots
+		pub fn set_spot_pricing(
+			origin: OriginFor<T>,
+			pricing: SpotPricing<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			SpotPricingModel::<T>::put(pricing.clone());
+			Self::deposit_event(Event::SpotPricingUpdated(pricing));
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		// Council set premium zones of continuum map, replacing existing zones
+		pub fn set_premium_zones(
+			origin: OriginFor<T>,
+			zones: Vec<PremiumZone<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let zones_count = zones.len() as u32;
+			let zones: BoundedVec<PremiumZone<BalanceOf<T>>, T::MaxPremiumZones> =
+				zones.try_into().map_err(|_| Error::<T>::TooManyPremiumZones)?;
+			PremiumZones::<T>::put(zones);
+			Self::deposit_event(Event::PremiumZonesUpdated(zones_count));
+			Ok(().into())
+		}
+
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		// Council set how many auction can run per period
 		pub fn set_new_auction_rate(origin: OriginFor<T>, new_rate: u8) -> DispatchResultWithPostInfo {
@@ -473,6 +785,15 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		/// Remove vote on participant of spot referendum, the tally is only updated while the
+		/// referendum is ongoing
+		pub fn remove_vote(origin: OriginFor<T>, id: SpotId, participant: T::AccountId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::try_remove_vote(&sender, id, &participant)?;
+			Ok(().into())
+		}
+
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		pub fn emergency_shutdown(origin: OriginFor<T>, spot_id: SpotId) -> DispatchResultWithPostInfo {
 			// Only some origins can execute this function
@@ -501,7 +822,7 @@ impl<T: Config> Pallet<T> {
 		// Move EOI to Auction Slots
 		Self::eoi_to_auction_slots(current_active_session_id, now)?;
 		// Finalise due vote
-		Self::finalize_vote(now);
+		Self::finalize_vote(now)?;
 		let active_auction_slots = <ActiveAuctionSlots<T>>::get(&current_active_session_id);
 
 		match active_auction_slots {
@@ -533,42 +854,83 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn finalize_vote(now: T::BlockNumber) -> DispatchResult {
-		let recent_slots = GNPSlots::<T>::get(now).ok_or(Error::<T>::NoActiveReferendum)?;
+		// Referendums started on the previous rotation end now
+		let gnp_session = now.saturating_sub(T::SessionDuration::get());
+		let recent_slots = match GNPSlots::<T>::take(gnp_session) {
+			Some(recent_slots) => recent_slots,
+			// No referendum was started on the previous rotation
+			None => return Ok(()),
+		};
+		let approval_threshold = T::GNPApprovalThreshold::get();
 
 		for mut recent_slot in recent_slots.into_iter() {
+			// Referendum could be removed by emergency shutdown
 			let referendum_info: ReferendumStatus<T::AccountId, T::BlockNumber> =
-				Self::referendum_status(recent_slot.spot_id)?;
-
-			if referendum_info.end == now {
-				let banned_list: Vec<T::AccountId> = referendum_info
-					.tallies
-					.into_iter()
-					.filter(|t| Self::check_approved(t) == true)
-					.map(|tally| tally.who)
-					.collect();
-
-				for banned_account in banned_list {
-					let account_index = recent_slot
-						.participants
-						.iter()
-						.position(|x| *x == banned_account)
-						.unwrap();
-					recent_slot.participants.remove(account_index);
-					recent_slot.status = ContinuumAuctionSlotStatus::GNPConfirmed;
+				match Self::referendum_status(recent_slot.spot_id) {
+					Ok(referendum_info) => referendum_info,
+					Err(_) => {
+						Self::refund_eoi_deposits(recent_slot.spot_id, &recent_slot.participants);
+						Self::clear_referendum_votes(recent_slot.spot_id, None);
+						continue;
+					}
+				};
+			Self::clear_referendum_votes(recent_slot.spot_id, Some(referendum_info.end));
+
+			let banned_list: Vec<T::AccountId> = referendum_info
+				.tallies
+				.into_iter()
+				.filter(|tally| tally.is_rejected(approval_threshold))
+				.map(|tally| tally.who)
+				.collect();
+
+			recent_slot
+				.participants
+				.retain(|participant| !banned_list.contains(participant));
+			Self::refund_eoi_deposits(recent_slot.spot_id, &banned_list);
+
+			// Spot taken by a rejected participant while the referendum was ongoing is evicted
+			let spot = ContinuumSpots::<T>::get(recent_slot.spot_id);
+			if spot.metaverse_id != 0
+				&& banned_list
+					.iter()
+					.any(|who| T::MetaverseInfoSource::has_role(who, &spot.metaverse_id, MetaverseRole::LandManager))
+			{
+				let evicted = with_transaction(|| match Self::do_evict_spot(recent_slot.spot_id, &spot) {
+					Ok(refund) => TransactionOutcome::Commit(Some(refund)),
+					Err(_) => TransactionOutcome::Rollback(None),
+				});
+				if let Some(refund) = evicted {
+					Self::deposit_event(Event::SpotEvicted(recent_slot.spot_id, spot.metaverse_id, refund));
 				}
+			}
+
+			let approved = !recent_slot.participants.is_empty();
+			ReferendumInfoOf::<T>::insert(
+				recent_slot.spot_id,
+				ReferendumInfo::Finished {
+					approved,
+					end: referendum_info.end,
+				},
+			);
+
+			if approved {
 				let treasury = Self::account_id();
 				// From treasury spot
-				T::AuctionHandler::create_auction(
+				match T::AuctionHandler::create_auction(
 					AuctionType::Auction,
 					ItemId::Spot(recent_slot.spot_id, Default::default()),
 					Some(now + T::AuctionDuration::get()),
 					treasury,
 					Default::default(),
 					now,
-					ListingLevel::NetworkSpot(recent_slot.participants),
-				)?;
-				Self::deposit_event(Event::FinalizedVote(referendum_info.spot_id))
+					ListingLevel::NetworkSpot(recent_slot.participants.clone()),
+				) {
+					Ok(auction_id) => SpotAuctions::<T>::insert(recent_slot.spot_id, auction_id),
+					// Deposits can't be applied or withdrawn without the spot auction
+					Err(_) => Self::refund_eoi_deposits(recent_slot.spot_id, &recent_slot.participants),
+				}
 			}
+			Self::deposit_event(Event::FinalizedVote(referendum_info.spot_id))
 		}
 
 		Ok(())
@@ -580,36 +942,28 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		for slot in slots {
 			let end = end + T::SessionDuration::get();
-			Self::start_referendum(end, slot.spot_id.clone())?;
+			Self::start_referendum(end, slot.spot_id, &slot.participants)?;
 			Self::deposit_event(Event::NewContinuumReferendumStarted(end, slot.spot_id));
 		}
 		Ok(())
 	}
 
-	fn start_referendum(end: T::BlockNumber, spot_id: SpotId) -> Result<SpotId, DispatchError> {
-		let spot = ContinuumSpots::<T>::get(spot_id);
-		let neighbors = spot.find_neighbour();
-		let mut available_neighbors: u8 = 0;
-
-		for (x, y) in neighbors {
-			if ContinuumCoordinates::<T>::contains_key((x, y)) {
-				available_neighbors = available_neighbors.checked_add(One::one()).ok_or("Overflow")?;
-			}
-		}
-
+	fn start_referendum(
+		end: T::BlockNumber,
+		spot_id: SpotId,
+		participants: &[T::AccountId],
+	) -> Result<SpotId, DispatchError> {
 		let mut status: ReferendumStatus<T::AccountId, T::BlockNumber> = ReferendumStatus {
 			end,
 			spot_id,
 			tallies: Default::default(),
 		};
 
-		for _i in 0..available_neighbors {
-			let initial_tally: ContinuumSpotTally<T::AccountId> = ContinuumSpotTally {
-				nays: One::one(),
-				who: Default::default(),
-				turnout: available_neighbors,
-			};
-			status.tallies.push(initial_tally);
+		// One tally per participant
+		for participant in participants {
+			if !status.tallies.iter().any(|tally| tally.who == *participant) {
+				status.tallies.push(ContinuumSpotTally::new(participant.clone()));
+			}
 		}
 
 		let item: ReferendumInfo<T::AccountId, T::BlockNumber> = ReferendumInfo::Ongoing(status);
@@ -621,81 +975,268 @@ impl<T: Config> Pallet<T> {
 	fn eoi_to_auction_slots(active_session: T::BlockNumber, now: T::BlockNumber) -> DispatchResult {
 		// Get maximum desired slots
 		let desired_slots = MaxDesiredAuctionSlot::<T>::get();
-		let session_duration = T::SessionDuration::get();
+		let active_session_index = now.checked_add(&T::SessionDuration::get()).ok_or("Overflow")?;
 
 		// Get active EOI and add the top N to new Auction Slots
-		let mut current_eoi_slots: Vec<SpotEOI<T::AccountId>> = EOISlots::<T>::get(active_session);
+		let mut current_eoi_slots: Vec<SpotEOI<T>> = EOISlots::<T>::get(active_session).into_inner();
+
+		// Rank by total deposit, earliest registered first on ties
+		current_eoi_slots.sort_by(|a, b| {
+			b.total_deposit
+				.cmp(&a.total_deposit)
+				.then_with(|| a.registered_at.cmp(&b.registered_at))
+		});
+		let unselected_eoi_slots = if current_eoi_slots.len() > desired_slots as usize {
+			current_eoi_slots.split_off(desired_slots as usize)
+		} else {
+			Vec::new()
+		};
+		// Refund EOI that are not selected
+		for item in unselected_eoi_slots {
+			for (who, deposit) in item.participants {
+				Self::refund_eoi_deposit(&who, item.spot_id, deposit);
+			}
+		}
 
-		current_eoi_slots.sort_by_key(|eoi_slot| eoi_slot.participants.len());
-		// Get highest ranked slot
 		let mut new_valid_auction_slot: Vec<AuctionSlot<T::BlockNumber, T::AccountId>> = Vec::new();
-		let highest_ranked_sorted: Vec<SpotEOI<T::AccountId>> = current_eoi_slots
-			.iter()
-			.map(|x| x.clone())
-			.take(desired_slots as usize)
-			.collect::<Vec<SpotEOI<T::AccountId>>>();
 		// Add highest ranked EOI to New Active Auction slot
-		for (_x, item) in highest_ranked_sorted.iter().enumerate() {
+		for item in current_eoi_slots {
+			// New auction round of the spot, deposits left from the previous spot auction can't be
+			// withdrawn once it's replaced
+			if SpotAuctions::<T>::take(item.spot_id).is_some() {
+				for (who, deposit) in EOIDeposits::<T>::drain_prefix(item.spot_id) {
+					Self::refund_eoi_deposit(&who, item.spot_id, deposit);
+				}
+			}
+			let mut participants: Vec<T::AccountId> = Vec::new();
+			for (who, deposit) in item.participants {
+				EOIDeposits::<T>::mutate(item.spot_id, &who, |total| *total = total.saturating_add(deposit));
+				participants.push(who);
+			}
+
 			let auction_slot = AuctionSlot {
 				spot_id: item.spot_id,
-				participants: item.participants.clone(),
-				active_session_index: now.checked_add(&session_duration).ok_or("Overflow")?,
+				participants,
+				active_session_index,
 				status: ContinuumAuctionSlotStatus::AcceptParticipates,
 			};
 			new_valid_auction_slot.push(auction_slot);
+			Self::deposit_event(Event::EOISelectedForAuction(item.spot_id, item.total_deposit));
 		}
 
 		ActiveAuctionSlots::<T>::insert(now, new_valid_auction_slot);
 		// Remove EOISlot
 		EOISlots::<T>::remove(active_session);
-		let empty_eoi_spots: Vec<SpotEOI<T::AccountId>> = Vec::new();
+		let empty_eoi_spots: BoundedVec<SpotEOI<T>, T::MaxEOISpots> = Default::default();
 		// Add new EOISlot for current session - ensure active session has entry
 		EOISlots::<T>::insert(now, empty_eoi_spots);
 		Ok(())
 	}
 
-	fn try_vote(who: &T::AccountId, spot_id: SpotId, vote: AccountVote<T::AccountId>) -> DispatchResult {
-		let status = Self::referendum_status(spot_id)?;
-
-		let spot = ContinuumSpots::<T>::get(spot_id);
-		let neighbors = spot.find_neighbour();
-		let mut is_neighbour: bool = false;
-
-		for (x, y) in neighbors {
-			// if spot exists
-			let neighbor_spot_id = ContinuumCoordinates::<T>::get((x, y));
-			let continuum_spot = ContinuumSpots::<T>::get(neighbor_spot_id);
-			if T::MetaverseInfoSource::check_ownership(&who, &continuum_spot.metaverse_id) {
-				is_neighbour = true;
-				break;
+	/// Worst case weight of rotating auction slots. Every EOI participant is refunded or has its
+	/// deposit held for the new auction slot, deposits left from the previous spot auction and
+	/// deposits of participants rejected in the finished referendums are refunded.
+	fn rotation_weight() -> Weight {
+		let spots = T::MaxEOISpots::get() as Weight;
+		let participants = spots.saturating_mul(T::MaxEOIParticipants::get() as Weight);
+		// Account and EOI deposit of a participant, on each of the three steps
+		let participant_weight = T::DbWeight::get().reads_writes(2, 2).saturating_add(2_000_000);
+		// Referendum, voters and spot auction of a spot, plus the voting of up to eight neighbours
+		let spot_weight = T::DbWeight::get().reads_writes(12, 12).saturating_add(10_000_000);
+
+		(20_000_000 as Weight)
+			.saturating_add(participants.saturating_mul(3).saturating_mul(participant_weight))
+			.saturating_add(spots.saturating_mul(spot_weight))
+	}
+
+	/// Return spot to continuum treasury, the spot keeps its coordinate
+	fn do_release_spot(spot_id: SpotId, spot: &ContinuumSpot) -> DispatchResult {
+		ensure!(
+			ContinuumCoordinates::<T>::try_get((spot.x, spot.y)).ok() == Some(spot_id),
+			Error::<T>::ContinuumSpotNotFound
+		);
+		ensure!(
+			!T::AuctionHandler::check_item_in_auction(ItemId::Spot(spot_id, spot.metaverse_id)),
+			Error::<T>::SpotIsInAuction
+		);
+
+		ContinuumSpots::<T>::insert(
+			spot_id,
+			ContinuumSpot {
+				metaverse_id: 0,
+				..spot.clone()
+			},
+		);
+		SpotAcquisitions::<T>::remove(spot_id);
+		Ok(())
+	}
+
+	/// Evict spot back to continuum treasury, refunding its acquisition price to the metaverse owner
+	fn do_evict_spot(spot_id: SpotId, spot: &ContinuumSpot) -> Result<BalanceOf<T>, DispatchError> {
+		let refund = Self::get_spot_acquisition(spot_id).map_or_else(Zero::zero, |(price, _)| price);
+		Self::do_release_spot(spot_id, spot)?;
+		if !refund.is_zero() {
+			let metaverse =
+				T::MetaverseInfoSource::get_metaverse(spot.metaverse_id).ok_or(Error::<T>::MetaverseNotFound)?;
+			T::Currency::transfer(
+				&Self::account_id(),
+				&metaverse.owner,
+				refund,
+				ExistenceRequirement::KeepAlive,
+			)?;
+		}
+		Ok(refund)
+	}
+
+	/// Refund of releasing spot now on the release refund curve
+	fn release_refund(spot_id: SpotId) -> BalanceOf<T> {
+		match (Self::get_release_refund_curve(), Self::get_spot_acquisition(spot_id)) {
+			(Some(curve), Some((price, acquired_at))) => {
+				let elapsed = frame_system::Pallet::<T>::block_number().saturating_sub(acquired_at);
+				curve.refund(price, elapsed)
 			}
+			_ => Zero::zero(),
 		}
+	}
 
-		ensure!(is_neighbour, Error::<T>::NoPermission);
+	fn refund_eoi_deposit(who: &T::AccountId, spot_id: SpotId, deposit: BalanceOf<T>) {
+		T::Currency::unreserve(who, deposit);
+		Self::deposit_event(Event::EOIDepositRefunded(who.clone(), spot_id, deposit));
+	}
+
+	/// Refund deposits of selected EOI held for the spot
+	fn refund_eoi_deposits(spot_id: SpotId, participants: &[T::AccountId]) {
+		for who in participants {
+			let deposit = EOIDeposits::<T>::take(spot_id, who);
+			if !deposit.is_zero() {
+				Self::refund_eoi_deposit(who, spot_id, deposit);
+			}
+		}
+	}
+
+	/// Spots neighbouring the spot that are owned by the account, unowned spots are skipped
+	fn neighbour_spots(who: &T::AccountId, spot_id: SpotId) -> Vec<SpotId> {
+		ContinuumSpots::<T>::get(spot_id)
+			.find_neighbour()
+			.into_iter()
+			.filter_map(|coordinate| ContinuumCoordinates::<T>::try_get(coordinate).ok())
+			.filter(|neighbour_spot_id| {
+				let neighbour_spot = ContinuumSpots::<T>::get(neighbour_spot_id);
+				neighbour_spot.metaverse_id != 0
+					&& T::MetaverseInfoSource::check_ownership(who, &neighbour_spot.metaverse_id)
+			})
+			.collect()
+	}
 
+	fn try_vote(who: &T::AccountId, spot_id: SpotId, vote: AccountVote<T::AccountId>) -> DispatchResult {
+		let mut status = Self::referendum_status(spot_id)?;
+
+		let neighbour_spots = Self::neighbour_spots(who, spot_id);
+		ensure!(!neighbour_spots.is_empty(), Error::<T>::NoPermission);
+
+		let Vote { nay, who: participant } = vote.clone().vote_who();
+		ensure!(participant != *who, Error::<T>::CannotVoteForSelf);
+
+		let votes = VotingOf::<T>::try_mutate(who, |voting| -> Result<u32, DispatchError> {
+			let position = voting.votes.binary_search_by(|(voted_spot_id, spot_vote)| {
+				(*voted_spot_id, spot_vote.vote.participant()).cmp(&(spot_id, &participant))
+			});
+
+			let tally = status
+				.tallies
+				.iter_mut()
+				.find(|tally| tally.who == participant)
+				.ok_or(Error::<T>::ParticipantNotFound)?;
+			// Spots already counted on the participant, e.g. before a metaverse transfer, can't vote again
+			let spots: Vec<SpotId> = neighbour_spots
+				.into_iter()
+				.filter(|neighbour_spot_id| !tally.has_voted(neighbour_spot_id))
+				.collect();
+			ensure!(!spots.is_empty(), Error::<T>::AlreadyVoted);
+			let conviction = vote.conviction();
+			tally.add(nay, &spots, conviction).ok_or(Error::<T>::TallyOverflow)?;
+
+			let votes = conviction.votes(spots.len() as u32);
+			let spot_vote = SpotVote {
+				vote,
+				spots,
+				end: status.end,
+			};
+			match position {
+				// Vote left from a previous referendum on the same spot
+				Ok(i) => {
+					let previous = voting.votes.get_mut(i).ok_or(Error::<T>::VoteNotFound)?;
+					ensure!(previous.1.end != status.end, Error::<T>::AlreadyVoted);
+					previous.1 = spot_vote;
+				}
+				Err(i) => voting.votes.insert(i, (spot_id, spot_vote)),
+			}
+			Ok(votes)
+		})?;
+
+		ReferendumInfoOf::<T>::insert(spot_id, ReferendumInfo::Ongoing(status));
+		ReferendumVoters::<T>::mutate(spot_id, |voters| {
+			if !voters.contains(who) {
+				voters.push(who.clone());
+			}
+		});
+		Self::deposit_event(Event::ContinuumVoteCast(who.clone(), spot_id, participant, nay, votes));
+		Ok(())
+	}
+
+	fn try_remove_vote(who: &T::AccountId, spot_id: SpotId, participant: &T::AccountId) -> DispatchResult {
 		VotingOf::<T>::try_mutate(who, |voting| -> DispatchResult {
-			let votes = &mut voting.votes;
-			match votes.binary_search_by_key(&spot_id, |i| i.0) {
-				// Already voted
-				Ok(_i) => {}
-				Err(i) => {
-					// Haven't vote for this spot id
-					// Add votes under user
-					let new_vote: AccountVote<T::AccountId> = vote.clone();
-					let who = new_vote.vote_who();
-					votes.insert(i, (spot_id, vote.clone()));
-
-					// Find existing tally of bidder
-					for mut tally in status.tallies {
-						// Existing vote
-						if tally.who == who.who {
-							tally.add(vote.clone()).ok_or(Error::<T>::TallyOverflow)?
-						}
-					}
+			let i = voting
+				.votes
+				.binary_search_by(|(voted_spot_id, spot_vote)| {
+					(*voted_spot_id, spot_vote.vote.participant()).cmp(&(spot_id, participant))
+				})
+				.map_err(|_| Error::<T>::VoteNotFound)?;
+			let (_, spot_vote) = voting.votes.remove(i);
+
+			// Only the ongoing referendum that the vote was cast in needs to be updated
+			if let Ok(mut status) = Self::referendum_status(spot_id) {
+				if status.end == spot_vote.end {
+					ensure!(!spot_vote.vote.conviction().is_locked(), Error::<T>::VoteIsLocked);
+
+					let tally = status
+						.tallies
+						.iter_mut()
+						.find(|tally| tally.who == *participant)
+						.ok_or(Error::<T>::ParticipantNotFound)?;
+					tally
+						.remove(
+							spot_vote.vote.vote_who().nay,
+							&spot_vote.spots,
+							spot_vote.vote.conviction(),
+						)
+						.ok_or(Error::<T>::TallyOverflow)?;
+
+					ReferendumInfoOf::<T>::insert(spot_id, ReferendumInfo::Ongoing(status));
 				}
 			}
 			Ok(())
-		})
+		})?;
+
+		Self::deposit_event(Event::ContinuumVoteRemoved(who.clone(), spot_id, participant.clone()));
+		Ok(())
+	}
+
+	/// Remove the votes cast on the referendum of spot ending at `end`, or on any referendum of
+	/// spot if the referendum was shut down
+	fn clear_referendum_votes(spot_id: SpotId, end: Option<T::BlockNumber>) {
+		for voter in ReferendumVoters::<T>::take(spot_id) {
+			VotingOf::<T>::mutate_exists(&voter, |maybe_voting| {
+				if let Some(voting) = maybe_voting {
+					voting.votes.retain(|(voted_spot_id, spot_vote)| {
+						*voted_spot_id != spot_id || end.map_or(false, |end| spot_vote.end != end)
+					});
+					if voting.votes.is_empty() {
+						*maybe_voting = None;
+					}
+				}
+			});
+		}
 	}
 
 	fn referendum_status(spot_id: SpotId) -> Result<ReferendumStatus<T::AccountId, T::BlockNumber>, DispatchError> {
@@ -721,11 +1262,58 @@ impl<T: Config> Pallet<T> {
 		Self::transfer_spot(spot_id, from, to)
 	}
 
-	fn check_approved(tally: &ContinuumSpotTally<T::AccountId>) -> bool {
-		let nay_ratio = tally.turnout.checked_div(tally.nays).unwrap_or(0);
-		let nay_percent = nay_ratio.checked_mul(100).unwrap_or(0);
+	/// Price of spot from the pricing model before adjustment and floor, the highest of the
+	/// distance price and the premium zones covering the spot
+	fn model_spot_price(pricing: &SpotPricing<BalanceOf<T>>, coordinate: (i32, i32)) -> BalanceOf<T> {
+		let distance = ContinuumSpot::distance_from_origin(coordinate);
+		let distance_price = pricing
+			.origin_price
+			.saturating_sub(pricing.distance_step.saturating_mul(distance.into()));
+
+		Self::get_premium_zones()
+			.into_iter()
+			.filter(|zone| zone.contains(coordinate))
+			.fold(distance_price, |price, zone| price.max(zone.price))
+	}
+
+	/// Current price of spot at the coordinate
+	pub fn get_spot_price(coordinate: (i32, i32)) -> BalanceOf<T> {
+		let pricing = match Self::get_spot_pricing() {
+			Some(pricing) => pricing,
+			None => return SpotPrice::<T>::get(),
+		};
+		let model_price = Self::model_spot_price(&pricing, coordinate);
+
+		let recent_sales = Self::get_recent_spot_sales();
+		let (total_sales, total_model_prices) = recent_sales.iter().fold(
+			(BalanceOf::<T>::zero(), BalanceOf::<T>::zero()),
+			|(total_sales, total_model_prices), (sale_price, model_price)| {
+				(
+					total_sales.saturating_add(*sale_price),
+					total_model_prices.saturating_add(*model_price),
+				)
+			},
+		);
+
+		// Scale model price by the ratio of recent sale prices to their model prices, within the
+		// maximum adjustment so a few outlier sales cannot run the price away
+		let price = if !pricing.dynamic_adjustment || total_sales.is_zero() || total_model_prices.is_zero() {
+			model_price
+		} else {
+			let max_adjustment: BalanceOf<T> = T::MaxSpotPriceAdjustment::get().max(1).into();
+			let max_price = model_price.saturating_mul(max_adjustment);
+			let min_price = model_price / max_adjustment;
+			multiply_by_rational(
+				model_price.saturated_into(),
+				total_sales.saturated_into(),
+				total_model_prices.saturated_into(),
+			)
+			.map(|price| price.saturated_into::<BalanceOf<T>>())
+			.unwrap_or(max_price)
+			.clamp(min_price, max_price)
+		};
 
-		nay_percent > 51
+		price.max(pricing.floor_price)
 	}
 
 	fn check_spot_ownership(spot_id: Option<SpotId>, coordinate: (i32, i32)) -> Result<SpotId, DispatchError> {
@@ -764,7 +1352,7 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
-impl<T: Config> Continuum<T::AccountId> for Pallet<T> {
+impl<T: Config> Continuum<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	fn transfer_spot(
 		spot_id: SpotId,
 		from: &T::AccountId,
@@ -774,11 +1362,15 @@ impl<T: Config> Continuum<T::AccountId> for Pallet<T> {
 			!T::AuctionHandler::check_item_in_auction(ItemId::Spot(spot_id, to.1.clone())),
 			Error::<T>::SpotIsInAuction
 		);
+		ensure!(
+			ContinuumSpots::<T>::contains_key(spot_id),
+			Error::<T>::ContinuumSpotNotFound
+		);
 		ContinuumSpots::<T>::try_mutate(spot_id, |maybe_spot| -> Result<SpotId, DispatchError> {
 			let treasury = Self::account_id();
 			if *from != treasury {
 				ensure!(
-					T::MetaverseInfoSource::check_ownership(&from, &to.1),
+					T::MetaverseInfoSource::has_role(&from, &to.1, MetaverseRole::LandManager),
 					Error::<T>::NoPermission
 				)
 			}
@@ -787,4 +1379,31 @@ impl<T: Config> Continuum<T::AccountId> for Pallet<T> {
 			Ok(spot_id)
 		})
 	}
+
+	fn record_spot_sale(spot_id: SpotId, price: BalanceOf<T>) {
+		let spot = match ContinuumSpots::<T>::try_get(spot_id) {
+			Ok(spot) => spot,
+			Err(_) => return,
+		};
+		SpotAcquisitions::<T>::insert(spot_id, (price, frame_system::Pallet::<T>::block_number()));
+
+		// Sales are only compared against the pricing model
+		let pricing = match Self::get_spot_pricing() {
+			Some(pricing) => pricing,
+			None => return,
+		};
+
+		let model_price = Self::model_spot_price(&pricing, (spot.x, spot.y));
+		if model_price.is_zero() {
+			return;
+		}
+
+		RecentSpotSales::<T>::mutate(|recent_sales| {
+			if !recent_sales.is_empty() && recent_sales.len() >= T::MaxRecentSpotSales::get() as usize {
+				recent_sales.remove(0);
+			}
+			let _ = recent_sales.try_push((price, model_price));
+		});
+		Self::deposit_event(Event::SpotSaleRecorded(spot_id, price));
+	}
 }
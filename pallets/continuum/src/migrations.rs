@@ -0,0 +1,150 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{
+	storage::migration::storage_key_iter,
+	traits::{Get, PalletInfoAccess},
+	weights::Weight,
+	Twox64Concat,
+};
+use sp_std::convert::TryInto;
+
+use super::*;
+
+/// Reads of counting a vote: the spot, its neighbouring coordinates and spots, the referendum,
+/// its voters and the voting of the account.
+const VOTE_READS: u64 = 20;
+
+/// Expression of interest before deposits were introduced.
+#[derive(Decode)]
+struct SpotEOIV0<AccountId> {
+	spot_id: SpotId,
+	participants: Vec<AccountId>,
+}
+
+/// Tally before votes were weighted by neighbouring spots, it was never updated by votes.
+#[derive(Decode)]
+struct ContinuumSpotTallyV0<AccountId> {
+	_nays: u8,
+	_who: AccountId,
+	_turnout: u8,
+}
+
+#[derive(Decode)]
+struct ReferendumStatusV0<AccountId, BlockNumber> {
+	end: BlockNumber,
+	spot_id: SpotId,
+	_tallies: Vec<ContinuumSpotTallyV0<AccountId>>,
+}
+
+#[derive(Decode)]
+enum ReferendumInfoV0<AccountId, BlockNumber> {
+	Ongoing(ReferendumStatusV0<AccountId, BlockNumber>),
+	Finished { approved: bool, end: BlockNumber },
+}
+
+/// Vote before convictions were introduced.
+#[derive(Decode)]
+enum AccountVoteV0<AccountId> {
+	Standard { vote: Vote<AccountId> },
+}
+
+/// Votes of an account before the counted neighbouring spots were recorded.
+#[derive(Decode)]
+struct VotingV0<AccountId> {
+	votes: Vec<(SpotId, AccountVoteV0<AccountId>)>,
+}
+
+/// Add deposits to expressions of interest, one tally per participant to ongoing referendums and
+/// the counted neighbouring spots to votes. Votes on ongoing referendums are counted again with
+/// the spots their voter owns now and no conviction, the other votes are dropped.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let mut reads: u64 = 0;
+	let mut writes: u64 = 0;
+
+	// No deposit was reserved for the participants, the ones above the bounds are dropped
+	EOISlots::<T>::translate::<Vec<SpotEOIV0<T::AccountId>>, _>(|session, old_eoi_slots| {
+		reads = reads.saturating_add(1);
+		writes = writes.saturating_add(1);
+		let mut eoi_slots: Vec<SpotEOI<T>> = old_eoi_slots
+			.into_iter()
+			.map(|eoi| {
+				let mut participants: Vec<(T::AccountId, BalanceOf<T>)> =
+					eoi.participants.into_iter().map(|who| (who, Zero::zero())).collect();
+				participants.truncate(T::MaxEOIParticipants::get() as usize);
+				SpotEOI {
+					spot_id: eoi.spot_id,
+					participants: participants.try_into().unwrap_or_default(),
+					total_deposit: Zero::zero(),
+					registered_at: session,
+				}
+			})
+			.collect();
+		eoi_slots.truncate(T::MaxEOISpots::get() as usize);
+		eoi_slots.try_into().ok()
+	});
+
+	let session_duration = T::SessionDuration::get();
+	ReferendumInfoOf::<T>::translate::<ReferendumInfoV0<T::AccountId, T::BlockNumber>, _>(|spot_id, old_info| {
+		reads = reads.saturating_add(1);
+		writes = writes.saturating_add(1);
+		match old_info {
+			ReferendumInfoV0::Ongoing(old_status) => {
+				// Participants are kept in the GNP slots of the rotation that started the referendum
+				reads = reads.saturating_add(1);
+				let mut status = ReferendumStatus {
+					end: old_status.end,
+					spot_id: old_status.spot_id,
+					tallies: Vec::new(),
+				};
+				let gnp_slots = GNPSlots::<T>::get(old_status.end.saturating_sub(session_duration)).unwrap_or_default();
+				for slot in gnp_slots.into_iter().filter(|slot| slot.spot_id == spot_id) {
+					for participant in slot.participants {
+						if !status.tallies.iter().any(|tally| tally.who == participant) {
+							status.tallies.push(ContinuumSpotTally::new(participant));
+						}
+					}
+				}
+				Some(ReferendumInfo::Ongoing(status))
+			}
+			ReferendumInfoV0::Finished { approved, end } => Some(ReferendumInfo::Finished { approved, end }),
+		}
+	});
+
+	let pallet_prefix = <Pallet<T> as PalletInfoAccess>::name();
+	let old_voting_of: Vec<(T::AccountId, VotingV0<T::AccountId>)> =
+		storage_key_iter::<T::AccountId, VotingV0<T::AccountId>, Twox64Concat>(pallet_prefix.as_bytes(), b"VotingOf")
+			.drain()
+			.collect();
+	for (who, voting) in old_voting_of {
+		reads = reads.saturating_add(1);
+		writes = writes.saturating_add(1);
+		for (spot_id, AccountVoteV0::Standard { vote }) in voting.votes {
+			reads = reads.saturating_add(VOTE_READS);
+			let vote = AccountVote::Standard {
+				vote,
+				conviction: Conviction::None,
+			};
+			// Votes that are no longer eligible are dropped
+			if Pallet::<T>::try_vote(&who, spot_id, vote).is_ok() {
+				writes = writes.saturating_add(3);
+			}
+		}
+	}
+
+	T::DbWeight::get().reads_writes(reads, writes)
+}
@@ -20,7 +20,7 @@
 use super::*;
 use crate as continuum;
 use auction_manager::{Auction, AuctionInfo, CheckAuctionItemHandler};
-use bc_primitives::{MetaverseInfo, MetaverseTrait};
+use bc_primitives::{MetaverseInfo, MetaverseRole, MetaverseTrait};
 use frame_support::pallet_prelude::{GenesisBuild, Hooks};
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
 use frame_system::EnsureSignedBy;
@@ -44,9 +44,15 @@ pub type BlockNumber = u64;
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const CHARLIE: AccountId = 3;
+pub const DAVE: AccountId = 4;
+pub const LAND_MANAGER: AccountId = 5;
 pub const ALICE_METAVERSE_ID: MetaverseId = 1;
 pub const BOB_METAVERSE_ID: MetaverseId = 2;
 pub const CHARLIE_METAVERSE_ID: MetaverseId = 3;
+pub const FROZEN_METAVERSE_ID: MetaverseId = 4;
+pub const DESTROYED_METAVERSE_ID: MetaverseId = 5;
+pub const DAVE_METAVERSE_ID: MetaverseId = 0;
+pub const UNAUCTIONABLE_SPOT_ID: SpotId = 1;
 
 ord_parameter_types! {
 	pub const One: AccountId = ALICE;
@@ -100,7 +106,11 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 	type Balance = Balance;
 
 	fn auction_info(_id: u64) -> Option<AuctionInfo<u128, Self::Balance, u64>> {
-		None
+		Some(AuctionInfo {
+			bid: None,
+			start: 0,
+			end: Some(100),
+		})
 	}
 
 	fn update_auction(_id: u64, _info: AuctionInfo<u128, Self::Balance, u64>) -> DispatchResult {
@@ -118,33 +128,34 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 
 	fn create_auction(
 		_auction_type: AuctionType,
-		_item_id: ItemId,
+		item_id: ItemId,
 		_end: Option<u64>,
 		_recipient: u128,
 		_initial_amount: Self::Balance,
 		_start: u64,
 		_listing_level: ListingLevel<AccountId>,
 	) -> Result<u64, DispatchError> {
-		Ok(1)
+		match item_id {
+			ItemId::Spot(UNAUCTIONABLE_SPOT_ID, _) => Err(DispatchError::Other("Spot can't be auctioned")),
+			_ => Ok(1),
+		}
 	}
 
 	fn remove_auction(_id: u64, _item_id: ItemId) {}
 
-	fn auction_bid_handler(
-		_now: u64,
-		_id: u64,
-		_new_bid: (u128, Self::Balance),
-		_last_bid: Option<(u128, Self::Balance)>,
-	) -> DispatchResult {
+	fn set_reserve_price(_id: u64, _reserve_price: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn place_bid(_who: u128, _id: u64, _value: Self::Balance) -> DispatchResult {
 		Ok(())
 	}
 
-	fn local_auction_bid_handler(
+	fn auction_bid_handler(
 		_now: u64,
 		_id: u64,
 		_new_bid: (u128, Self::Balance),
 		_last_bid: Option<(u128, Self::Balance)>,
-		_social_currency_id: FungibleTokenId,
 	) -> DispatchResult {
 		Ok(())
 	}
@@ -173,6 +184,13 @@ parameter_types! {
 	pub const SessionDuration: BlockNumber = 10;
 	// Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 10;
+	pub const GNPApprovalThreshold: Perbill = Perbill::from_percent(50);
+	pub const MaxContinuumPremiumZones: u32 = 10;
+	pub const MaxRecentContinuumSpotSales: u32 = 2;
+	pub const MaxContinuumSpotPriceAdjustment: u32 = 4;
+	pub const MinimumEOIDeposit: Balance = 10;
+	pub const MaxEOISpots: u32 = 3;
+	pub const MaxEOIParticipants: u32 = 3;
 }
 
 pub struct MetaverseInfoSource {}
@@ -183,12 +201,28 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 			ALICE => *metaverse_id == ALICE_METAVERSE_ID,
 			BOB => *metaverse_id == BOB_METAVERSE_ID,
 			CHARLIE => *metaverse_id == CHARLIE_METAVERSE_ID,
+			DAVE => *metaverse_id == DAVE_METAVERSE_ID,
 			_ => false,
 		}
 	}
 
-	fn get_metaverse(_metaverse_id: u64) -> Option<MetaverseInfo<u128>> {
-		None
+	fn has_role(who: &AccountId, metaverse_id: &MetaverseId, role: MetaverseRole) -> bool {
+		match *who {
+			LAND_MANAGER => *metaverse_id == ALICE_METAVERSE_ID && role == MetaverseRole::LandManager,
+			_ => Self::check_ownership(who, metaverse_id),
+		}
+	}
+
+	fn get_metaverse(metaverse_id: u64) -> Option<MetaverseInfo<u128>> {
+		match metaverse_id {
+			DESTROYED_METAVERSE_ID => None,
+			_ => Some(MetaverseInfo {
+				owner: ALICE,
+				metadata: vec![],
+				currency_id: FungibleTokenId::NativeToken(0),
+				is_frozen: metaverse_id == FROZEN_METAVERSE_ID,
+			}),
+		}
 	}
 
 	fn get_metaverse_token(_metaverse_id: u64) -> Option<FungibleTokenId> {
@@ -210,6 +244,13 @@ impl Config for Runtime {
 	type ContinuumTreasury = ContinuumTreasuryPalletId;
 	type Currency = Balances;
 	type MetaverseInfoSource = MetaverseInfoSource;
+	type GNPApprovalThreshold = GNPApprovalThreshold;
+	type MaxPremiumZones = MaxContinuumPremiumZones;
+	type MaxRecentSpotSales = MaxRecentContinuumSpotSales;
+	type MaxSpotPriceAdjustment = MaxContinuumSpotPriceAdjustment;
+	type MinimumEOIDeposit = MinimumEOIDeposit;
+	type MaxEOISpots = MaxEOISpots;
+	type MaxEOIParticipants = MaxEOIParticipants;
 }
 
 pub type ContinuumModule = Pallet<Runtime>;
@@ -248,7 +289,7 @@ impl ExtBuilder {
 			.unwrap();
 
 		pallet_balances::GenesisConfig::<Runtime> {
-			balances: vec![(ALICE, 100000), (BOB, 500), (CHARLIE, 100000)],
+			balances: vec![(ALICE, 100000), (BOB, 500), (CHARLIE, 100000), (LAND_MANAGER, 500)],
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
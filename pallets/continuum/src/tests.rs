@@ -18,9 +18,11 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, storage, traits::Hooks};
 use mock::BlockNumber as MBlockNumber;
 use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+use sp_std::convert::TryInto;
 
 #[test]
 fn find_neighborhood_spot_should_work() {
@@ -50,10 +52,15 @@ fn register_interest_should_work() {
 		let origin = Origin::signed(ALICE);
 
 		System::set_block_number(1);
-		assert_ok!(ContinuumModule::register_interest(origin, ALICE_METAVERSE_ID, (0, 0)));
+		assert_ok!(ContinuumModule::register_interest(
+			origin,
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
 		assert_eq!(
 			last_event(),
-			Event::Continuum(crate::Event::NewExpressOfInterestAdded(ALICE, 0))
+			Event::Continuum(crate::Event::NewExpressOfInterestAdded(ALICE, 0, 10))
 		)
 	})
 }
@@ -64,12 +71,174 @@ fn register_interest_should_not_work_for_non_owner() {
 		let origin = Origin::signed(ALICE);
 		System::set_block_number(1);
 		assert_noop!(
-			ContinuumModule::register_interest(origin, BOB_METAVERSE_ID, (0, 0)),
+			ContinuumModule::register_interest(origin, BOB_METAVERSE_ID, (0, 0), 10),
 			Error::<Runtime>::NoPermission
 		);
 	})
 }
 
+#[test]
+fn register_interest_should_work_for_land_manager() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			ContinuumModule::register_interest(Origin::signed(LAND_MANAGER), BOB_METAVERSE_ID, (0, 0), 10),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(LAND_MANAGER),
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_eq!(Balances::reserved_balance(LAND_MANAGER), 10);
+	})
+}
+
+#[test]
+fn register_interest_should_reserve_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ContinuumModule::register_interest(Origin::signed(ALICE), ALICE_METAVERSE_ID, (0, 0), 9),
+			Error::<Runtime>::EOIDepositTooLow
+		);
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			20
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 20);
+		assert_noop!(
+			ContinuumModule::register_interest(Origin::signed(ALICE), ALICE_METAVERSE_ID, (0, 0), 20),
+			Error::<Runtime>::EOIAlreadyExists
+		);
+	})
+}
+
+#[test]
+fn register_interest_should_respect_eoi_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		for &(who, metaverse_id) in [
+			(ALICE, ALICE_METAVERSE_ID),
+			(BOB, BOB_METAVERSE_ID),
+			(CHARLIE, CHARLIE_METAVERSE_ID),
+		]
+		.iter()
+		{
+			assert_ok!(ContinuumModule::register_interest(
+				Origin::signed(who),
+				metaverse_id,
+				(0, 0),
+				10
+			));
+		}
+		assert_noop!(
+			ContinuumModule::register_interest(Origin::signed(LAND_MANAGER), ALICE_METAVERSE_ID, (0, 0), 10),
+			Error::<Runtime>::TooManyEOIParticipants
+		);
+
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(1, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(2, 0),
+			10
+		));
+		assert_noop!(
+			ContinuumModule::register_interest(Origin::signed(ALICE), ALICE_METAVERSE_ID, (3, 0), 10),
+			Error::<Runtime>::TooManyEOISpots
+		);
+	})
+}
+
+#[test]
+fn rotate_session_should_rank_eoi_by_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_new_auction_rate(Origin::root(), 1));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(BOB),
+			BOB_METAVERSE_ID,
+			(1, 0),
+			50
+		));
+		System::set_block_number(2);
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(CHARLIE),
+			CHARLIE_METAVERSE_ID,
+			(2, 0),
+			50
+		));
+
+		run_to_block(10);
+
+		// Bob registered first among the highest deposits
+		let active_auction_slots = ContinuumModule::get_active_auction_slots(10).unwrap();
+		assert_eq!(active_auction_slots.len(), 1);
+		assert_eq!(active_auction_slots[0].spot_id, 1);
+		assert_eq!(ContinuumModule::get_eoi_deposit(1, BOB), 50);
+		assert_eq!(Balances::reserved_balance(BOB), 50);
+
+		// Unselected EOI are refunded
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		let events = frame_system::Pallet::<Runtime>::events();
+		assert!(events
+			.iter()
+			.any(|record| record.event == Event::Continuum(crate::Event::EOISelectedForAuction(1, 50))));
+		assert!(events
+			.iter()
+			.any(|record| record.event == Event::Continuum(crate::Event::EOIDepositRefunded(CHARLIE, 2, 50))));
+	})
+}
+
+#[test]
+fn failed_rotation_should_be_reverted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_new_auction_rate(Origin::root(), 1));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(BOB),
+			BOB_METAVERSE_ID,
+			(1, 0),
+			50
+		));
+
+		// Next session index overflows
+		let now = MBlockNumber::max_value() - 5;
+		System::set_block_number(now);
+		ContinuumModule::on_initialize(now);
+
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::AuctionSlotRotationFailed(
+				now,
+				DispatchError::Other("Overflow")
+			))
+		);
+		assert_eq!(ContinuumModule::current_session(), 0);
+		assert_eq!(ContinuumModule::get_active_auction_slots(now), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(Balances::reserved_balance(BOB), 50);
+	})
+}
+
 #[test]
 fn try_vote_works_if_voter_is_neighbour() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -79,8 +248,13 @@ fn try_vote_works_if_voter_is_neighbour() {
 		System::set_block_number(1);
 
 		// Alice & Bob register for slot 0,0
-		assert_ok!(ContinuumModule::register_interest(alice, ALICE_METAVERSE_ID, (0, 0)));
-		assert_ok!(ContinuumModule::register_interest(bob, BOB_METAVERSE_ID, (0, 0)));
+		assert_ok!(ContinuumModule::register_interest(
+			alice,
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(bob, BOB_METAVERSE_ID, (0, 0), 10));
 
 		// Charlie buy neighbor slot using buy now option
 		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
@@ -145,7 +319,8 @@ fn try_vote_works_if_voter_is_neighbour() {
 			&CHARLIE,
 			0,
 			AccountVote::Standard {
-				vote: Vote { nay: true, who: ALICE }
+				vote: Vote { nay: true, who: ALICE },
+				conviction: Conviction::None,
 			}
 		));
 	})
@@ -160,8 +335,13 @@ fn try_vote_should_fail_if_not_truly_neighbor() {
 		System::set_block_number(1);
 
 		// Alice & Bob register for slot 0,0
-		assert_ok!(ContinuumModule::register_interest(alice, ALICE_METAVERSE_ID, (0, 0)));
-		assert_ok!(ContinuumModule::register_interest(bob, BOB_METAVERSE_ID, (0, 0)));
+		assert_ok!(ContinuumModule::register_interest(
+			alice,
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(bob, BOB_METAVERSE_ID, (0, 0), 10));
 
 		run_to_block(10);
 		// Start auction slot on continuum
@@ -220,7 +400,8 @@ fn try_vote_should_fail_if_not_truly_neighbor() {
 				&CHARLIE,
 				0,
 				AccountVote::Standard {
-					vote: Vote { nay: true, who: BOB }
+					vote: Vote { nay: true, who: BOB },
+					conviction: Conviction::None,
 				}
 			),
 			Error::<Runtime>::NoPermission
@@ -236,8 +417,13 @@ fn rotate_session_should_work() {
 
 		System::set_block_number(1);
 
-		assert_ok!(ContinuumModule::register_interest(alice, ALICE_METAVERSE_ID, (0, 0)));
-		assert_ok!(ContinuumModule::register_interest(bob, BOB_METAVERSE_ID, (0, 0)));
+		assert_ok!(ContinuumModule::register_interest(
+			alice,
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(bob, BOB_METAVERSE_ID, (0, 0), 10));
 
 		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
 		assert_ok!(ContinuumModule::buy_continuum_spot(
@@ -302,7 +488,8 @@ fn rotate_session_should_work() {
 			&CHARLIE,
 			0,
 			AccountVote::Standard {
-				vote: Vote { nay: true, who: ALICE }
+				vote: Vote { nay: true, who: ALICE },
+				conviction: Conviction::None,
 			}
 		));
 
@@ -324,6 +511,480 @@ fn rotate_session_should_work() {
 	})
 }
 
+fn start_gnp_referendum_with_neighbours(neighbours: Vec<(AccountId, mock::MetaverseId, (i32, i32))>) {
+	System::set_block_number(1);
+
+	// Alice & Bob register for slot 0,0
+	assert_ok!(ContinuumModule::register_interest(
+		Origin::signed(ALICE),
+		ALICE_METAVERSE_ID,
+		(0, 0),
+		10
+	));
+	assert_ok!(ContinuumModule::register_interest(
+		Origin::signed(BOB),
+		BOB_METAVERSE_ID,
+		(0, 0),
+		10
+	));
+
+	assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
+	for (who, metaverse_id, coordinate) in neighbours {
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(who),
+			coordinate,
+			metaverse_id
+		));
+	}
+
+	// EOI moves to auction slots at block 10 and GNP starts at block 20
+	run_to_block(20);
+}
+
+fn nay_vote(who: AccountId) -> AccountVote<AccountId> {
+	AccountVote::Standard {
+		vote: Vote { nay: true, who },
+		conviction: Conviction::None,
+	}
+}
+
+fn spot_tally(spot_id: SpotId, who: AccountId) -> (u32, u32) {
+	let status = ContinuumModule::referendum_status(spot_id).unwrap();
+	let tally = status.tallies.into_iter().find(|tally| tally.who == who).unwrap();
+	(tally.nays, tally.turnout)
+}
+
+#[test]
+fn vote_should_be_weighted_by_neighbouring_spots() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![
+			(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1)),
+			(CHARLIE, CHARLIE_METAVERSE_ID, (0, 1)),
+		]);
+
+		assert_ok!(ContinuumModule::vote(Origin::signed(CHARLIE), 0, nay_vote(ALICE)));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::ContinuumVoteCast(CHARLIE, 0, ALICE, true, 2))
+		);
+		assert_eq!(spot_tally(0, ALICE), (2, 2));
+		assert_eq!(spot_tally(0, BOB), (0, 0));
+
+		assert_noop!(
+			ContinuumModule::vote(Origin::signed(CHARLIE), 0, nay_vote(ALICE)),
+			Error::<Runtime>::AlreadyVoted
+		);
+	})
+}
+
+#[test]
+fn vote_should_fail_for_self_or_non_participant() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![
+			(ALICE, ALICE_METAVERSE_ID, (1, 1)),
+			(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1)),
+		]);
+
+		assert_noop!(
+			ContinuumModule::vote(Origin::signed(ALICE), 0, nay_vote(ALICE)),
+			Error::<Runtime>::CannotVoteForSelf
+		);
+		assert_noop!(
+			ContinuumModule::vote(Origin::signed(ALICE), 0, nay_vote(CHARLIE)),
+			Error::<Runtime>::ParticipantNotFound
+		);
+	})
+}
+
+#[test]
+fn remove_vote_should_update_tally() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1))]);
+
+		assert_ok!(ContinuumModule::vote(Origin::signed(CHARLIE), 0, nay_vote(ALICE)));
+		assert_eq!(spot_tally(0, ALICE), (1, 1));
+
+		assert_ok!(ContinuumModule::remove_vote(Origin::signed(CHARLIE), 0, ALICE));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::ContinuumVoteRemoved(CHARLIE, 0, ALICE))
+		);
+		assert_eq!(spot_tally(0, ALICE), (0, 0));
+		assert!(ContinuumModule::get_voting_info(CHARLIE).votes.is_empty());
+
+		assert_noop!(
+			ContinuumModule::remove_vote(Origin::signed(CHARLIE), 0, ALICE),
+			Error::<Runtime>::VoteNotFound
+		);
+	})
+}
+
+#[test]
+fn vote_with_conviction_should_be_weighted_and_locked() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1))]);
+
+		assert_ok!(ContinuumModule::vote(
+			Origin::signed(CHARLIE),
+			0,
+			AccountVote::Standard {
+				vote: Vote { nay: true, who: ALICE },
+				conviction: Conviction::Locked2x,
+			}
+		));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::ContinuumVoteCast(CHARLIE, 0, ALICE, true, 2))
+		);
+		assert_eq!(spot_tally(0, ALICE), (2, 2));
+
+		assert_noop!(
+			ContinuumModule::remove_vote(Origin::signed(CHARLIE), 0, ALICE),
+			Error::<Runtime>::VoteIsLocked
+		);
+	})
+}
+
+#[test]
+fn vote_should_count_each_neighbouring_spot_once() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1))]);
+
+		assert_ok!(ContinuumModule::vote(Origin::signed(CHARLIE), 0, nay_vote(BOB)));
+		assert_eq!(spot_tally(0, BOB), (1, 1));
+
+		// Neighbouring spot changes hands while the referendum is ongoing
+		let neighbour_spot_id = ContinuumModule::get_continuum_position((-1, 1));
+		assert_ok!(ContinuumModule::release_spot(
+			Origin::signed(CHARLIE),
+			neighbour_spot_id
+		));
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(ALICE),
+			(-1, 1),
+			ALICE_METAVERSE_ID
+		));
+
+		assert_noop!(
+			ContinuumModule::vote(Origin::signed(ALICE), 0, nay_vote(BOB)),
+			Error::<Runtime>::AlreadyVoted
+		);
+
+		// Spot can vote again with its new owner once the previous vote is removed
+		assert_ok!(ContinuumModule::remove_vote(Origin::signed(CHARLIE), 0, BOB));
+		assert_ok!(ContinuumModule::vote(Origin::signed(ALICE), 0, nay_vote(BOB)));
+		assert_eq!(spot_tally(0, BOB), (1, 1));
+	})
+}
+
+#[test]
+fn vote_should_skip_unowned_neighbouring_spots() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1))]);
+
+		let neighbour_spot_id = ContinuumModule::get_continuum_position((-1, 1));
+		assert_ok!(ContinuumModule::release_spot(
+			Origin::signed(CHARLIE),
+			neighbour_spot_id
+		));
+		assert_eq!(ContinuumModule::get_continuum_spot(neighbour_spot_id).metaverse_id, 0);
+
+		// Owner of metaverse 0 doesn't own the released spot
+		assert_noop!(
+			ContinuumModule::vote(Origin::signed(DAVE), 0, nay_vote(ALICE)),
+			Error::<Runtime>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn finalize_vote_should_reject_participant_above_threshold() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![
+			(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1)),
+			(ALICE, ALICE_METAVERSE_ID, (1, 1)),
+		]);
+
+		// Alice is rejected with 1 nay out of 1, Bob is kept with 1 nay out of 2
+		assert_ok!(ContinuumModule::vote(Origin::signed(CHARLIE), 0, nay_vote(ALICE)));
+		assert_ok!(ContinuumModule::vote(Origin::signed(CHARLIE), 0, nay_vote(BOB)));
+		assert_ok!(ContinuumModule::vote(
+			Origin::signed(ALICE),
+			0,
+			AccountVote::Standard {
+				vote: Vote { nay: false, who: BOB },
+				conviction: Conviction::None,
+			}
+		));
+		assert_eq!(spot_tally(0, BOB), (1, 2));
+		assert_eq!(ContinuumModule::get_referendum_voters(0), vec![CHARLIE, ALICE]);
+
+		run_to_block(30);
+
+		// Votes are cleared once the referendum finishes
+		assert!(ContinuumModule::get_referendum_voters(0).is_empty());
+		assert!(!VotingOf::<Runtime>::contains_key(CHARLIE));
+		assert!(!VotingOf::<Runtime>::contains_key(ALICE));
+
+		assert_eq!(
+			ContinuumModule::get_continuum_referendum(0),
+			Some(ReferendumInfo::Finished {
+				approved: true,
+				end: 30
+			})
+		);
+		assert_eq!(ContinuumModule::get_active_gnp_slots(20), None);
+		assert!(frame_system::Pallet::<Runtime>::events()
+			.iter()
+			.any(|record| record.event == Event::Continuum(crate::Event::FinalizedVote(0))));
+
+		// Rejected participant is refunded
+		assert_eq!(ContinuumModule::get_spot_auction(0), Some(1));
+		assert_eq!(ContinuumModule::get_eoi_deposit(0, ALICE), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(ContinuumModule::get_eoi_deposit(0, BOB), 10);
+	})
+}
+
+#[test]
+fn finalize_vote_should_evict_spot_of_rejected_participant() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1))]);
+
+		// Alice buys the spot while the referendum is ongoing
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(ALICE),
+			(0, 0),
+			ALICE_METAVERSE_ID
+		));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance - 100);
+		assert_ok!(ContinuumModule::vote(Origin::signed(CHARLIE), 0, nay_vote(ALICE)));
+
+		run_to_block(30);
+
+		assert_eq!(ContinuumModule::get_continuum_spot(0).metaverse_id, 0);
+		assert_eq!(ContinuumModule::get_continuum_position((0, 0)), 0);
+		assert_eq!(ContinuumModule::get_spot_acquisition(0), None);
+		// Acquisition price and EOI deposit are refunded
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 10);
+		assert!(frame_system::Pallet::<Runtime>::events()
+			.iter()
+			.any(|record| record.event == Event::Continuum(crate::Event::SpotEvicted(0, ALICE_METAVERSE_ID, 100))));
+	})
+}
+
+#[test]
+fn finalize_vote_should_evict_spot_of_rejected_land_manager() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// Land manager registers on behalf of Alice's metaverse
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(LAND_MANAGER),
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(BOB),
+			BOB_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(CHARLIE),
+			(-1, 1),
+			CHARLIE_METAVERSE_ID
+		));
+		run_to_block(20);
+
+		// Alice's metaverse takes the spot while the referendum is ongoing
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(ALICE),
+			(0, 0),
+			ALICE_METAVERSE_ID
+		));
+		assert_ok!(ContinuumModule::vote(
+			Origin::signed(CHARLIE),
+			0,
+			nay_vote(LAND_MANAGER)
+		));
+
+		run_to_block(30);
+
+		assert_eq!(ContinuumModule::get_continuum_spot(0).metaverse_id, 0);
+		// Acquisition price is refunded to the metaverse owner
+		assert_eq!(Balances::free_balance(ALICE), alice_balance);
+		assert_eq!(Balances::reserved_balance(LAND_MANAGER), 0);
+		assert!(frame_system::Pallet::<Runtime>::events()
+			.iter()
+			.any(|record| record.event == Event::Continuum(crate::Event::SpotEvicted(0, ALICE_METAVERSE_ID, 100))));
+	})
+}
+
+#[test]
+fn finalize_vote_should_refund_deposits_when_spot_auction_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(BOB),
+			BOB_METAVERSE_ID,
+			(5, 5),
+			10
+		));
+		assert_eq!(ContinuumModule::get_continuum_position((5, 5)), UNAUCTIONABLE_SPOT_ID);
+
+		run_to_block(30);
+
+		// Auction of the other spot is still created
+		assert_eq!(ContinuumModule::get_spot_auction(0), Some(1));
+		assert_eq!(ContinuumModule::get_eoi_deposit(0, ALICE), 10);
+
+		assert_eq!(ContinuumModule::get_spot_auction(UNAUCTIONABLE_SPOT_ID), None);
+		assert_eq!(ContinuumModule::get_eoi_deposit(UNAUCTIONABLE_SPOT_ID, BOB), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert!(frame_system::Pallet::<Runtime>::events()
+			.iter()
+			.any(|record| record.event
+				== Event::Continuum(crate::Event::EOIDepositRefunded(BOB, UNAUCTIONABLE_SPOT_ID, 10))));
+	})
+}
+
+#[test]
+fn eoi_selection_should_refund_deposits_of_previous_spot_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(BOB),
+			BOB_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+
+		run_to_block(30);
+		assert_eq!(ContinuumModule::get_spot_auction(0), Some(1));
+
+		// New round of the spot replaces the spot auction
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(CHARLIE),
+			CHARLIE_METAVERSE_ID,
+			(0, 0),
+			10
+		));
+		run_to_block(40);
+
+		assert_eq!(ContinuumModule::get_spot_auction(0), None);
+		assert_eq!(ContinuumModule::get_eoi_deposit(0, ALICE), 0);
+		assert_eq!(ContinuumModule::get_eoi_deposit(0, BOB), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(ContinuumModule::get_eoi_deposit(0, CHARLIE), 10);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 10);
+	})
+}
+
+#[test]
+fn migrate_to_v1_should_translate_eoi_and_count_votes_again() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![(CHARLIE, CHARLIE_METAVERSE_ID, (-1, 1))]);
+		let neighbour_spot_id = ContinuumModule::get_continuum_position((-1, 1));
+
+		// Storage encoded before deposits and neighbour weighted tallies
+		let old_eoi_slots: Vec<(SpotId, Vec<AccountId>)> = vec![(0, vec![ALICE])];
+		storage::unhashed::put_raw(&EOISlots::<Runtime>::hashed_key_for(20), &old_eoi_slots.encode());
+		let old_tallies: Vec<(u8, AccountId, u8)> = vec![(1, 0, 1)];
+		storage::unhashed::put_raw(
+			&ReferendumInfoOf::<Runtime>::hashed_key_for(0),
+			&(0u8, 30u64, 0u64, old_tallies).encode(),
+		);
+		let old_votes: Vec<(SpotId, (u8, Vote<AccountId>))> = vec![(0, (0, Vote { nay: true, who: BOB }))];
+		storage::unhashed::put_raw(&VotingOf::<Runtime>::hashed_key_for(CHARLIE), &old_votes.encode());
+
+		migrations::migrate_to_v1::<Runtime>();
+
+		assert_eq!(
+			ContinuumModule::get_eoi_set(20).into_inner(),
+			vec![SpotEOI {
+				spot_id: 0,
+				participants: vec![(ALICE, 0)].try_into().unwrap(),
+				total_deposit: 0,
+				registered_at: 20,
+			}]
+		);
+		assert_eq!(spot_tally(0, ALICE), (0, 0));
+		assert_eq!(spot_tally(0, BOB), (1, 1));
+		assert_eq!(
+			ContinuumModule::get_voting_info(CHARLIE).votes,
+			vec![(
+				0,
+				SpotVote {
+					vote: nay_vote(BOB),
+					spots: vec![neighbour_spot_id],
+					end: 30,
+				}
+			)]
+		);
+	})
+}
+
+#[test]
+fn apply_eoi_deposit_should_bid_on_spot_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![]);
+		assert_noop!(
+			ContinuumModule::apply_eoi_deposit(Origin::signed(ALICE), 0),
+			Error::<Runtime>::SpotAuctionNotFound
+		);
+
+		run_to_block(30);
+
+		assert_ok!(ContinuumModule::apply_eoi_deposit(Origin::signed(ALICE), 0));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::EOIDepositAppliedToBid(ALICE, 0, 1, 10))
+		);
+		assert_eq!(ContinuumModule::get_eoi_deposit(0, ALICE), 0);
+		assert_noop!(
+			ContinuumModule::apply_eoi_deposit(Origin::signed(ALICE), 0),
+			Error::<Runtime>::EOIDepositNotFound
+		);
+
+		// Bob withdraws the deposit instead
+		assert_ok!(ContinuumModule::withdraw_eoi_deposit(Origin::signed(BOB), 0));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::EOIDepositRefunded(BOB, 0, 10))
+		);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	})
+}
+
+#[test]
+fn apply_eoi_deposit_should_fail_when_auction_ended() {
+	ExtBuilder::default().build().execute_with(|| {
+		start_gnp_referendum_with_neighbours(vec![]);
+		run_to_block(100);
+
+		assert_noop!(
+			ContinuumModule::apply_eoi_deposit(Origin::signed(ALICE), 0),
+			Error::<Runtime>::SpotAuctionEnded
+		);
+	})
+}
+
 #[test]
 fn buy_now_continuum_should_fail_when_not_owner() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -374,3 +1035,231 @@ fn buy_now_continuum_should_fail_if_buy_now_setting_is_disabled() {
 		);
 	})
 }
+
+#[test]
+fn release_spot_should_refund_on_curve() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
+		assert_ok!(ContinuumModule::set_release_refund_curve(
+			Origin::root(),
+			Some(SpotRefundCurve {
+				initial_refund: Perbill::from_percent(50),
+				decay_period: 100,
+			})
+		));
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(ALICE),
+			(0, 1),
+			ALICE_METAVERSE_ID
+		));
+		assert_noop!(
+			ContinuumModule::release_spot(Origin::signed(BOB), 0),
+			Error::<Runtime>::NoPermission
+		);
+
+		// Half of the curve has decayed
+		System::set_block_number(51);
+		assert_ok!(ContinuumModule::release_spot(Origin::signed(ALICE), 0));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::SpotReleased(ALICE, 0, ALICE_METAVERSE_ID, 25))
+		);
+		assert_eq!(Balances::free_balance(ALICE), 100000 - 100 + 25);
+		assert_eq!(ContinuumModule::get_continuum_spot(0).metaverse_id, 0);
+		assert_eq!(ContinuumModule::get_continuum_position((0, 1)), 0);
+		assert_eq!(ContinuumModule::get_spot_acquisition(0), None);
+		assert_noop!(
+			ContinuumModule::release_spot(Origin::signed(ALICE), 0),
+			Error::<Runtime>::SpotIsNotOwned
+		);
+
+		// Released spot can be bought again
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(BOB),
+			(0, 1),
+			BOB_METAVERSE_ID
+		));
+		assert_eq!(ContinuumModule::get_continuum_spot(0).metaverse_id, BOB_METAVERSE_ID);
+	})
+}
+
+#[test]
+fn release_spot_without_refund_curve_should_not_refund() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(ALICE),
+			(0, 1),
+			ALICE_METAVERSE_ID
+		));
+		assert_ok!(ContinuumModule::release_spot(Origin::signed(ALICE), 0));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::SpotReleased(ALICE, 0, ALICE_METAVERSE_ID, 0))
+		);
+		assert_eq!(Balances::free_balance(ALICE), 100000 - 100);
+	})
+}
+
+#[test]
+fn reclaim_spot_should_only_work_for_frozen_or_destroyed_metaverse() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(ALICE),
+			(0, 1),
+			ALICE_METAVERSE_ID
+		));
+		assert_noop!(ContinuumModule::reclaim_spot(Origin::signed(ALICE), 0), BadOrigin);
+		assert_noop!(
+			ContinuumModule::reclaim_spot(Origin::root(), 0),
+			Error::<Runtime>::MetaverseIsActive
+		);
+		assert_noop!(
+			ContinuumModule::reclaim_spot(Origin::root(), 1),
+			Error::<Runtime>::ContinuumSpotNotFound
+		);
+
+		ContinuumSpots::<Runtime>::mutate(0, |spot| spot.metaverse_id = FROZEN_METAVERSE_ID);
+		assert_ok!(ContinuumModule::reclaim_spot(Origin::root(), 0));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::SpotReclaimed(0, FROZEN_METAVERSE_ID))
+		);
+
+		ContinuumSpots::<Runtime>::mutate(0, |spot| spot.metaverse_id = DESTROYED_METAVERSE_ID);
+		assert_ok!(ContinuumModule::reclaim_spot(Origin::root(), 0));
+		assert_eq!(ContinuumModule::get_continuum_spot(0).metaverse_id, 0);
+	})
+}
+
+fn spot_pricing(dynamic_adjustment: bool) -> SpotPricing<Balance> {
+	SpotPricing {
+		origin_price: 1000,
+		distance_step: 100,
+		floor_price: 300,
+		dynamic_adjustment,
+	}
+}
+
+#[test]
+fn spot_price_should_use_flat_price_without_pricing_model() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(ContinuumModule::get_spot_price((0, 0)), 100);
+		assert_eq!(ContinuumModule::get_spot_price((50, -50)), 100);
+	})
+}
+
+#[test]
+fn set_spot_pricing_should_fail_for_non_root() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ContinuumModule::set_spot_pricing(Origin::signed(ALICE), spot_pricing(false)),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn spot_price_should_decrease_with_distance_down_to_floor() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_spot_pricing(Origin::root(), spot_pricing(false)));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::SpotPricingUpdated(spot_pricing(false)))
+		);
+
+		assert_eq!(ContinuumModule::get_spot_price((0, 0)), 1000);
+		assert_eq!(ContinuumModule::get_spot_price((2, -1)), 800);
+		assert_eq!(ContinuumModule::get_spot_price((-9, 3)), 300);
+
+		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(ALICE),
+			(1, 1),
+			ALICE_METAVERSE_ID
+		));
+		assert_eq!(Balances::free_balance(ALICE), 100000 - 900);
+	})
+}
+
+#[test]
+fn spot_price_should_apply_premium_zones() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_spot_pricing(Origin::root(), spot_pricing(false)));
+
+		let zone = PremiumZone {
+			min: (5, 5),
+			max: (6, 6),
+			price: 2000,
+		};
+		assert_ok!(ContinuumModule::set_premium_zones(Origin::root(), vec![zone.clone()]));
+		assert_eq!(last_event(), Event::Continuum(crate::Event::PremiumZonesUpdated(1)));
+
+		assert_eq!(ContinuumModule::get_spot_price((5, 6)), 2000);
+		assert_eq!(ContinuumModule::get_spot_price((7, 6)), 300);
+
+		assert_noop!(
+			ContinuumModule::set_premium_zones(Origin::root(), vec![zone; 11]),
+			Error::<Runtime>::TooManyPremiumZones
+		);
+	})
+}
+
+#[test]
+fn spot_price_should_follow_recent_auction_sales() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_spot_pricing(Origin::root(), spot_pricing(true)));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(1, 0),
+			10
+		));
+
+		// Spot at distance 1 is priced at 900 by the model
+		ContinuumModule::record_spot_sale(0, 1800);
+		assert_eq!(last_event(), Event::Continuum(crate::Event::SpotSaleRecorded(0, 1800)));
+		assert_eq!(ContinuumModule::get_spot_price((0, 0)), 2000);
+
+		ContinuumModule::record_spot_sale(0, 450);
+		assert_eq!(ContinuumModule::get_spot_price((0, 0)), 1250);
+
+		// Oldest sale is dropped
+		ContinuumModule::record_spot_sale(0, 900);
+		assert_eq!(ContinuumModule::get_recent_spot_sales().len(), 2);
+		assert_eq!(ContinuumModule::get_spot_price((0, 0)), 750);
+		assert_eq!(ContinuumModule::get_spot_price((-9, 3)), 300);
+	})
+}
+
+#[test]
+fn spot_price_adjustment_should_be_capped() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::set_spot_pricing(Origin::root(), spot_pricing(true)));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(1, 0),
+			10
+		));
+
+		// Sales far above the model price raise it by at most 4 times
+		ContinuumModule::record_spot_sale(0, Balance::max_value());
+		ContinuumModule::record_spot_sale(0, Balance::max_value());
+		assert_eq!(ContinuumModule::get_spot_price((0, 0)), 4000);
+
+		// Sales far below the model price lower it by at most 4 times
+		ContinuumModule::record_spot_sale(0, 1);
+		ContinuumModule::record_spot_sale(0, 1);
+		assert_eq!(ContinuumModule::get_spot_price((0, 0)), 300);
+		assert_ok!(ContinuumModule::set_spot_pricing(
+			Origin::root(),
+			SpotPricing {
+				floor_price: 0,
+				..spot_pricing(true)
+			}
+		));
+		assert_eq!(ContinuumModule::get_spot_price((0, 0)), 250);
+	})
+}
@@ -17,15 +17,16 @@
 
 //! Miscellaneous additional datatypes.
 
-use crate::{AccountVote, Vote};
 use codec::{Decode, Encode};
 use primitives::{MetaverseId, SpotId};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{One, Zero};
-use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
+use sp_runtime::{DispatchError, Perbill, RuntimeDebug};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+use crate::vote::Conviction;
+
 pub type ReferendumIndex = u64;
 
 /// Spot Struct
@@ -48,6 +49,11 @@ impl ContinuumSpot {
 		return neighbors.unwrap_or(Vec::new());
 	}
 
+	/// Number of rings between the coordinate and the continuum origin
+	pub fn distance_from_origin(coordinate: (i32, i32)) -> u32 {
+		coordinate.0.unsigned_abs().max(coordinate.1.unsigned_abs())
+	}
+
 	//Move coordinate by another coordinate
 	pub fn move_coordinate(from_coordinate: (i32, i32), coordinate: (i32, i32)) -> Result<(i32, i32), DispatchError> {
 		let new_x = from_coordinate.0.checked_add(coordinate.0).ok_or("Overflow")?;
@@ -57,74 +63,111 @@ impl ContinuumSpot {
 	}
 }
 
-/// Info regarding an ongoing referendum.
+/// Governance configured pricing model of continuum spots
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SpotPricing<Balance> {
+	/// Price of the spot at the continuum origin
+	pub origin_price: Balance,
+	/// Price decrease per ring of distance from the continuum origin
+	pub distance_step: Balance,
+	/// Minimum price of any spot
+	pub floor_price: Balance,
+	/// Whether prices follow the recent auction sales
+	pub dynamic_adjustment: bool,
+}
+
+/// Rectangular area of the continuum priced at least at its premium price
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PremiumZone<Balance> {
+	/// Lowest x and y of the zone
+	pub min: (i32, i32),
+	/// Highest x and y of the zone
+	pub max: (i32, i32),
+	pub price: Balance,
+}
+
+impl<Balance> PremiumZone<Balance> {
+	pub fn contains(&self, coordinate: (i32, i32)) -> bool {
+		(self.min.0 <= coordinate.0 && coordinate.0 <= self.max.0)
+			&& (self.min.1 <= coordinate.1 && coordinate.1 <= self.max.1)
+	}
+}
+
+/// Share of the purchase price refunded when a spot is released, decaying linearly to nothing over
+/// the decay period
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SpotRefundCurve<BlockNumber> {
+	/// Share refunded when the spot is released right after purchase
+	pub initial_refund: Perbill,
+	/// Blocks after purchase until nothing is refunded
+	pub decay_period: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> SpotRefundCurve<BlockNumber> {
+	/// Refund of the purchase price after the elapsed blocks
+	pub fn refund<Balance: AtLeast32BitUnsigned + Copy>(&self, price: Balance, elapsed: BlockNumber) -> Balance {
+		if elapsed >= self.decay_period {
+			return Zero::zero();
+		}
+		let remaining = Perbill::from_rational(self.decay_period - elapsed, self.decay_period);
+		self.initial_refund.mul_floor(remaining.mul_floor(price))
+	}
+}
+
+/// Tally of the votes on a participant of a Continuum spot referendum.
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct ContinuumSpotTally<AccountId> {
-	/// The number of nay votes, expressed in terms of post-conviction lock-vote.
-	pub(crate) nays: u8,
+	/// The participant being voted on.
 	pub(crate) who: AccountId,
-	/// The amount of funds currently expressing its opinion. Pre-conviction.
-	pub(crate) turnout: u8,
+	/// The number of nay votes, one per neighbouring spot weighted by conviction.
+	pub(crate) nays: u32,
+	/// The number of votes cast, one per neighbouring spot weighted by conviction.
+	pub(crate) turnout: u32,
+	/// Neighbouring spots already counted, each spot votes once whoever owns it.
+	pub(crate) voted_spots: Vec<SpotId>,
 }
 
 impl<AccountId> ContinuumSpotTally<AccountId> {
 	/// Create a new tally.
-	pub fn new(vote: Vote<AccountId>) -> Self {
+	pub fn new(who: AccountId) -> Self {
 		Self {
-			who: vote.who,
+			who,
 			nays: Zero::zero(),
 			turnout: Zero::zero(),
+			voted_spots: Vec::new(),
 		}
 	}
 
-	/// Add an account's vote into the tally.
-	pub fn add(&mut self, vote: AccountVote<AccountId>) -> Option<()> {
-		match vote {
-			AccountVote::Standard { vote } => {
-				self.turnout = self.turnout.checked_add(One::one())?;
-				self.nays = self.nays.checked_add(One::one())?;
-				self.who = vote.who;
-			}
-		}
-		Some(())
-	}
-
-	/// Remove an account's vote from the tally.
-	pub fn remove(&mut self, vote: AccountVote<AccountId>) -> Option<()> {
-		match vote {
-			AccountVote::Standard { vote: _ } => {
-				self.turnout = self.turnout.checked_add(Zero::zero())?;
-				self.nays = self.nays.checked_add(Zero::zero())?;
-			}
-		}
-		Some(())
+	/// Whether the vote of the neighbouring spot is already counted.
+	pub fn has_voted(&self, spot_id: &SpotId) -> bool {
+		self.voted_spots.contains(spot_id)
 	}
 
-	/// Increment some amount of votes.
-	pub fn increase(&mut self, approve: bool) -> Option<()> {
-		self.turnout = self.turnout.saturating_add(Zero::zero());
-		match approve {
-			false => self.nays = self.nays.saturating_add(Zero::zero()),
-			true => (),
+	/// Add the votes of the neighbouring spots with the conviction into the tally.
+	pub fn add(&mut self, nay: bool, spots: &[SpotId], conviction: Conviction) -> Option<()> {
+		let votes = conviction.votes(spots.len() as u32);
+		self.turnout = self.turnout.checked_add(votes)?;
+		if nay {
+			self.nays = self.nays.checked_add(votes)?;
 		}
+		self.voted_spots.extend_from_slice(spots);
 		Some(())
 	}
 
-	/// Decrement some amount of votes.
-	pub fn reduce(&mut self, approve: bool) -> Option<()> {
-		self.turnout = self.turnout.saturating_sub(Zero::zero());
-		match approve {
-			true => (),
-			false => self.nays = self.nays.saturating_add(Zero::zero()),
+	/// Remove the votes of the neighbouring spots with the conviction from the tally.
+	pub fn remove(&mut self, nay: bool, spots: &[SpotId], conviction: Conviction) -> Option<()> {
+		let votes = conviction.votes(spots.len() as u32);
+		self.turnout = self.turnout.checked_sub(votes)?;
+		if nay {
+			self.nays = self.nays.checked_sub(votes)?;
 		}
+		self.voted_spots.retain(|spot_id| !spots.contains(spot_id));
 		Some(())
 	}
 
-	pub fn result(&mut self) -> Option<bool> {
-		let total_nay = self.nays.checked_div(self.turnout).unwrap().saturating_mul(100);
-		let approve_threshold = 49;
-
-		Some(total_nay > approve_threshold)
+	/// Whether the share of nay votes in the turnout is above the threshold.
+	pub fn is_rejected(&self, threshold: Perbill) -> bool {
+		!self.turnout.is_zero() && Perbill::from_rational(self.nays, self.turnout) > threshold
 	}
 }
 
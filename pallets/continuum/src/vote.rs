@@ -20,29 +20,80 @@ impl<AccountId: From<u32> + Default> Default for Vote<AccountId> {
 	}
 }
 
+/// Conviction of a Continuum vote
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum Conviction {
+	/// Vote counts with the neighbouring weight and can be removed while the referendum is ongoing
+	None,
+	/// Vote counts with double the neighbouring weight but can't be removed until the referendum
+	/// ends
+	Locked2x,
+}
+
+impl Default for Conviction {
+	fn default() -> Self {
+		Conviction::None
+	}
+}
+
+impl Conviction {
+	/// Votes counted in the tally for the given neighbouring weight
+	pub fn votes(self, weight: u32) -> u32 {
+		match self {
+			Conviction::None => weight,
+			Conviction::Locked2x => weight.saturating_mul(2),
+		}
+	}
+
+	/// Whether the vote is locked until the referendum ends
+	pub fn is_locked(self) -> bool {
+		self != Conviction::None
+	}
+}
+
+/// Vote of an account on a participant of a Continuum spot referendum
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SpotVote<AccountId, BlockNumber> {
+	pub vote: AccountVote<AccountId>,
+	/// Neighbouring spots counted in the tally, each weighted by the conviction of the vote
+	pub spots: Vec<SpotId>,
+	/// End of the referendum the vote was cast in
+	pub end: BlockNumber,
+}
+
 /// Keep track of voting activities of an account
 #[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
-pub struct Voting<AccountId> {
-	pub votes: Vec<(SpotId, AccountVote<AccountId>)>,
+pub struct Voting<AccountId, BlockNumber> {
+	/// Votes sorted by spot and participant
+	pub votes: Vec<(SpotId, SpotVote<AccountId, BlockNumber>)>,
 }
 
 /// A vote for a referendum of a particular account.
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub enum AccountVote<AccountId> {
 	/// A standard continuum vote
-	Standard { vote: Vote<AccountId> },
+	Standard {
+		vote: Vote<AccountId>,
+		conviction: Conviction,
+	},
 }
 
-// impl<AccountId: From<u32> + Default> Default for AccountVote<AccountId> {
-//     fn default() -> Self {
-//         AccountVote::Standard { vote: Default::default() }
-//     }
-// }
-
 impl<AccountId> AccountVote<AccountId> {
 	pub fn vote_who(self) -> Vote<AccountId> {
 		match self {
-			AccountVote::Standard { vote } => vote,
+			AccountVote::Standard { vote, .. } => vote,
+		}
+	}
+
+	pub fn participant(&self) -> &AccountId {
+		match self {
+			AccountVote::Standard { vote, .. } => &vote.who,
+		}
+	}
+
+	pub fn conviction(&self) -> Conviction {
+		match self {
+			AccountVote::Standard { conviction, .. } => *conviction,
 		}
 	}
 }
@@ -40,6 +40,7 @@ pub use weights::WeightInfo;
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 mod rate;
@@ -61,8 +62,11 @@ pub mod pallet {
 
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::config]
@@ -122,6 +126,16 @@ pub mod pallet {
 	#[pallet::getter(fn get_estates)]
 	pub(super) type Estates<T: Config> = StorageMap<_, Twox64Concat, EstateId, Vec<(i32, i32)>, OptionQuery>;
 
+	/// Number of estates per metaverse
+	#[pallet::storage]
+	#[pallet::getter(fn metaverse_estates_count)]
+	pub type MetaverseEstatesCount<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, u64, ValueQuery>;
+
+	/// Number of land units deployed per metaverse
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_land_units_count)]
+	pub type MetaverseLandUnitsCount<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, u64, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn get_estate_owner)]
 	pub type EstateOwner<T: Config> =
@@ -707,6 +721,12 @@ pub mod pallet {
 			);
 
 			let land_units = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(
+				land_units
+					.iter()
+					.all(|land_unit| LandUnits::<T>::contains_key(metaverse_id, land_unit)),
+				Error::<T>::LandUnitDoesNotExist
+			);
 
 			EstateOwner::<T>::try_mutate_exists(&who, &estate_id, |estate_by_owner| {
 				//ensure there is record of the estate owner with estate id and account id
@@ -724,6 +744,7 @@ pub mod pallet {
 					.checked_sub(One::one())
 					.ok_or("Overflow adding new count to total estates")?;
 				AllEstatesCount::<T>::put(new_total_estates_count);
+				MetaverseEstatesCount::<T>::mutate(metaverse_id, |count| *count = count.saturating_sub(One::one()));
 
 				// Update land units relationship
 				for land_unit in land_units.clone() {
@@ -1081,6 +1102,17 @@ pub mod pallet {
 				0
 			}
 		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+
+			if StorageVersion::get::<Pallet<T>>() < 1 {
+				weight = weight.saturating_add(migrations::migrate_to_v1::<T>());
+				StorageVersion::new(1).put::<Pallet<T>>();
+			}
+
+			weight
+		}
 	}
 }
 
@@ -1126,6 +1158,9 @@ impl<T: Config> Pallet<T> {
 		);
 
 		LandUnits::<T>::insert(metaverse_id, coordinate, beneficiary.clone());
+		if !existing_land_units {
+			MetaverseLandUnitsCount::<T>::mutate(metaverse_id, |count| *count = count.saturating_add(One::one()));
+		}
 		Ok(())
 	}
 
@@ -1141,6 +1176,7 @@ impl<T: Config> Pallet<T> {
 			.checked_add(One::one())
 			.ok_or("Overflow adding new count to total estates")?;
 		AllEstatesCount::<T>::put(new_total_estates_count);
+		MetaverseEstatesCount::<T>::mutate(metaverse_id, |count| *count = count.saturating_add(One::one()));
 
 		// Update estates
 		Estates::<T>::insert(new_estate_id, coordinates.clone());
@@ -0,0 +1,63 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::weights::Weight;
+use sp_std::collections::btree_map::BTreeMap;
+
+use super::*;
+
+/// Backfill the estate and land unit counters of every metaverse. Land units of an estate are
+/// held by the estate account, which links the estate to its metaverse.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let mut reads: u64 = 0;
+
+	let mut land_units_count: BTreeMap<MetaverseId, u64> = BTreeMap::new();
+	for (metaverse_id, _, _) in LandUnits::<T>::iter() {
+		reads = reads.saturating_add(1);
+		let count = land_units_count.entry(metaverse_id).or_default();
+		*count = count.saturating_add(1);
+	}
+
+	let mut estates_count: BTreeMap<MetaverseId, u64> = BTreeMap::new();
+	for (estate_id, coordinates) in Estates::<T>::iter() {
+		reads = reads.saturating_add(1);
+		let coordinate = match coordinates.first() {
+			Some(coordinate) => coordinate,
+			None => continue,
+		};
+
+		let estate_account: T::AccountId = T::LandTreasury::get().into_sub_account(estate_id);
+		for metaverse_id in land_units_count.keys() {
+			reads = reads.saturating_add(1);
+			if LandUnits::<T>::try_get(metaverse_id, coordinate).ok().as_ref() == Some(&estate_account) {
+				let count = estates_count.entry(*metaverse_id).or_default();
+				*count = count.saturating_add(1);
+				break;
+			}
+		}
+	}
+
+	let writes = (land_units_count.len() as u64).saturating_add(estates_count.len() as u64);
+	for (metaverse_id, count) in land_units_count {
+		MetaverseLandUnitsCount::<T>::insert(metaverse_id, count);
+	}
+	for (metaverse_id, count) in estates_count {
+		MetaverseEstatesCount::<T>::insert(metaverse_id, count);
+	}
+
+	T::DbWeight::get().reads_writes(reads, writes)
+}
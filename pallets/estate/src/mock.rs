@@ -110,6 +110,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 		}
 	}
 
+	fn has_role(who: &AccountId, metaverse_id: &MetaverseId, _role: MetaverseRole) -> bool {
+		Self::check_ownership(who, metaverse_id)
+	}
+
 	fn get_metaverse(_metaverse_id: u64) -> Option<MetaverseInfo<u128>> {
 		None
 	}
@@ -159,21 +163,19 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 
 	fn remove_auction(_id: u64, _item_id: ItemId) {}
 
-	fn auction_bid_handler(
-		_now: u64,
-		_id: u64,
-		_new_bid: (u128, Self::Balance),
-		_last_bid: Option<(u128, Self::Balance)>,
-	) -> DispatchResult {
+	fn set_reserve_price(_id: u64, _reserve_price: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn place_bid(_who: u128, _id: u64, _value: Self::Balance) -> DispatchResult {
 		Ok(())
 	}
 
-	fn local_auction_bid_handler(
+	fn auction_bid_handler(
 		_now: u64,
 		_id: u64,
 		_new_bid: (u128, Self::Balance),
 		_last_bid: Option<(u128, Self::Balance)>,
-		_social_currency_id: FungibleTokenId,
 	) -> DispatchResult {
 		Ok(())
 	}
@@ -500,6 +500,8 @@ fn dissolve_estate_should_work() {
 			EstateModule::get_estates(estate_id),
 			Some(vec![COORDINATE_IN_1, COORDINATE_IN_2])
 		); //vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		assert_eq!(EstateModule::metaverse_estates_count(METAVERSE_ID), 1);
+		assert_eq!(EstateModule::get_metaverse_land_units_count(METAVERSE_ID), 2);
 		assert_eq!(EstateModule::get_estate_owner(BENEFICIARY_ID, estate_id), Some(()));
 		assert_eq!(
 			EstateModule::get_user_land_units(&BENEFICIARY_ID, &METAVERSE_ID).len(),
@@ -515,6 +517,7 @@ fn dissolve_estate_should_work() {
 
 		assert_eq!(EstateModule::all_estates_count(), 0);
 		assert_eq!(EstateModule::get_estates(estate_id), None);
+		assert_eq!(EstateModule::metaverse_estates_count(METAVERSE_ID), 0);
 		assert_eq!(EstateModule::get_estate_owner(BENEFICIARY_ID, estate_id), None);
 		assert_eq!(
 			EstateModule::get_user_land_units(&BENEFICIARY_ID, &METAVERSE_ID).len(),
@@ -543,6 +546,64 @@ fn dissolve_estate_should_reject_non_owner() {
 	});
 }
 
+#[test]
+fn dissolve_estate_should_reject_wrong_metaverse() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+
+		// Mint estate
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+
+		assert_err!(
+			EstateModule::dissolve_estate(Origin::signed(BENEFICIARY_ID), 0, METAVERSE_ID + 1),
+			Error::<Runtime>::LandUnitDoesNotExist
+		);
+		assert_eq!(EstateModule::metaverse_estates_count(METAVERSE_ID), 1);
+	});
+}
+
+#[test]
+fn migrate_to_v1_should_backfill_metaverse_counters() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::set_max_bounds(
+			Origin::root(),
+			BOB_METAVERSE_ID,
+			MAX_BOUND
+		));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BOB,
+			BOB_METAVERSE_ID,
+			COORDINATE_IN_1
+		));
+		assert_eq!(EstateModule::get_metaverse_land_units_count(BOB_METAVERSE_ID), 1);
+
+		// Counters did not exist before the upgrade
+		MetaverseEstatesCount::<Runtime>::remove(METAVERSE_ID);
+		MetaverseLandUnitsCount::<Runtime>::remove(METAVERSE_ID);
+		MetaverseLandUnitsCount::<Runtime>::remove(BOB_METAVERSE_ID);
+
+		migrations::migrate_to_v1::<Runtime>();
+
+		assert_eq!(EstateModule::metaverse_estates_count(METAVERSE_ID), 1);
+		assert_eq!(EstateModule::metaverse_estates_count(BOB_METAVERSE_ID), 0);
+		assert_eq!(EstateModule::get_metaverse_land_units_count(METAVERSE_ID), 2);
+		assert_eq!(EstateModule::get_metaverse_land_units_count(BOB_METAVERSE_ID), 1);
+	});
+}
+
 #[test]
 fn add_land_unit_to_estate_should_reject_non_owner() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -33,7 +33,7 @@ use frame_support::{
 		WithdrawReasons,
 	},
 };
-use metaverse_primitive::MetaverseTrait;
+use metaverse_primitive::{MetaverseRole, MetaverseTrait};
 use primitives::{MetaverseId, ProposalId, ReferendumId};
 use sp_runtime::traits::{Dispatchable, Hash, Saturating, Zero};
 use sp_std::prelude::*;
@@ -242,7 +242,7 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Metaverse owner can update referendum parameters
+		/// Metaverse owner or admin can update referendum parameters
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		pub fn update_referendum_parameters(
 			origin: OriginFor<T>,
@@ -251,7 +251,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 			ensure!(
-				T::MetaverseInfo::check_ownership(&from, &metaverse_id),
+				T::MetaverseInfo::has_role(&from, &metaverse_id, MetaverseRole::Admin),
 				Error::<T>::AccountIsNotMetaverseOwner
 			);
 			<ReferendumParametersOf<T>>::remove(metaverse_id);
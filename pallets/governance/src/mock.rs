@@ -14,7 +14,7 @@ use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, Hash, IdentityLookup},
 };
 
-use metaverse_primitive::{MetaverseInfo as MetaversePrimitiveInfo, MetaverseLandTrait, MetaverseTrait};
+use metaverse_primitive::{MetaverseInfo as MetaversePrimitiveInfo, MetaverseLandTrait, MetaverseRole, MetaverseTrait};
 use primitives::{Amount, FungibleTokenId};
 
 use crate as governance;
@@ -126,6 +126,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfo {
 		}
 	}
 
+	fn has_role(who: &AccountId, country_id: &CountryId, _role: MetaverseRole) -> bool {
+		Self::check_ownership(who, country_id)
+	}
+
 	fn get_metaverse(_metaverse_id: u64) -> Option<MetaversePrimitiveInfo<AccountId>> {
 		None
 	}
@@ -175,8 +179,14 @@ ord_parameter_types! {
 parameter_types! {
 	pub const MetaverseFundPalletId: PalletId = PalletId(*b"bit/fund");
 	pub const MaxTokenMetadata: u32 = 1024;
+	pub const MaxMetadataHistory: u32 = 2;
+	pub const MetaverseTransferExpiry: BlockNumber = 10;
 	pub const MinContribution: Balance = 1;
 	pub const MaxNumberOfStakersPerMetaverse: u32 = 512;
+	pub const MaxMetaverseRewardsPerBlock: u32 = 50;
+	pub const UnbondingRounds: u32 = 2;
+	pub const MaxUnbondingChunks: u32 = 8;
+	pub const MaxRolesPerMetaverse: u32 = 32;
 }
 
 impl pallet_metaverse::Config for Runtime {
@@ -185,11 +195,18 @@ impl pallet_metaverse::Config for Runtime {
 	type MultiCurrency = Currencies;
 	type MetaverseTreasury = MetaverseFundPalletId;
 	type MaxMetaverseMetadata = MaxTokenMetadata;
+	type MaxMetadataHistory = MaxMetadataHistory;
+	type MetaverseTransferExpiry = MetaverseTransferExpiry;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureSignedBy<One, AccountId>;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
+	type StakingRewardSource = ();
+	type MaxMetaverseRewardsPerBlock = MaxMetaverseRewardsPerBlock;
+	type UnbondingRounds = UnbondingRounds;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
+	type MaxRolesPerMetaverse = MaxRolesPerMetaverse;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,105 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the metaverse pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use primitives::MetaverseId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+};
+
+pub use metaverse_runtime_api::{MetaverseApi as MetaverseRuntimeApi, MetaverseSummary};
+
+/// Maximum number of metaverses returned in a single page
+const MAX_METAVERSES_PER_PAGE: u32 = 100;
+
+#[rpc]
+pub trait MetaverseApi<BlockHash, AccountId, Balance> {
+	/// Existing metaverses among the `limit` ids starting from `start`, ordered by id. The next
+	/// page starts from `start + limit`
+	#[rpc(name = "metaverse_getMetaverses")]
+	fn metaverses(
+		&self,
+		start: MetaverseId,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<MetaverseSummary<AccountId, Balance>>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query metaverses.
+pub struct Metaverse<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Metaverse<C, B> {
+	/// Create new `Metaverse` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance> MetaverseApi<<Block as BlockT>::Hash, AccountId, Balance> for Metaverse<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: MetaverseRuntimeApi<Block, AccountId, Balance, <Block as BlockT>::Hash, NumberFor<Block>>,
+	AccountId: Codec,
+	Balance: Codec,
+{
+	fn metaverses(
+		&self,
+		start: MetaverseId,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<MetaverseSummary<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.metaverses(&at, start, limit.min(MAX_METAVERSES_PER_PAGE))
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to query metaverses.".into(),
+				data: Some(e.to_string().into()),
+			})
+	}
+}
@@ -0,0 +1,69 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the metaverse pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+pub use bc_primitives::{MetaverseMetadata, MetaverseMetadataVersion, MetaverseProfile};
+use codec::{Codec, Decode, Encode};
+use primitives::{FungibleTokenId, MetaverseId};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Metaverse with its key stats collected across pallets
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MetaverseSummary<AccountId, Balance> {
+	pub metaverse_id: MetaverseId,
+	pub owner: AccountId,
+	pub metadata: MetaverseMetadata,
+	pub currency_id: FungibleTokenId,
+	pub is_frozen: bool,
+	/// Account that registered the metaverse for staking, if registered
+	pub staking_registration: Option<AccountId>,
+	/// Total staked on the metaverse in the current round
+	pub total_stake: Balance,
+	pub land_units_count: u64,
+	pub estates_count: u64,
+	/// Total issuance of the metaverse social token, if one has been issued
+	pub social_token_supply: Option<Balance>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait MetaverseApi<AccountId, Balance, Hash, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// Existing metaverses among the `limit` ids starting from `start`, ordered by id. The next
+		/// page starts from `start + limit`
+		fn metaverses(start: MetaverseId, limit: u32) -> Vec<MetaverseSummary<AccountId, Balance>>;
+
+		/// Current metadata and profile of the metaverse
+		fn metaverse_metadata(metaverse_id: MetaverseId) -> Option<(MetaverseMetadata, MetaverseProfile)>;
+
+		/// Recorded metadata versions of the metaverse, oldest first and the current version last
+		fn metaverse_metadata_versions(metaverse_id: MetaverseId) -> Vec<MetaverseMetadataVersion<Hash, BlockNumber>>;
+	}
+}
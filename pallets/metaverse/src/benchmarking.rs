@@ -28,7 +28,7 @@ use sp_std::vec;
 pub use crate::Pallet as MetaverseModule;
 use crate::*;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::{Currency, Get};
+use frame_support::traits::{Currency, Get, Hooks};
 use frame_system::RawOrigin;
 use primitives::Balance;
 use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
@@ -57,6 +57,19 @@ fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	caller
 }
 
+fn grant_roles<T: Config>(owner: &T::AccountId, count: u32) {
+	for i in 0..count {
+		let delegate = funded_account::<T>("delegate", i);
+		crate::Pallet::<T>::grant_metaverse_role(
+			RawOrigin::Signed(owner.clone()).into(),
+			0,
+			delegate,
+			MetaverseRole::LandManager,
+		)
+		.unwrap();
+	}
+}
+
 benchmarks! {
 	// create_metaverse
 	create_metaverse{
@@ -79,11 +92,15 @@ benchmarks! {
 
 	// transfer_metaverse
 	transfer_metaverse {
+		let r in 0 .. T::MaxRolesPerMetaverse::get();
+
 		let caller = funded_account::<T>("caller", 0);
 		let target = funded_account::<T>("target", 0);
 
 		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
-	}: _(RawOrigin::Signed(caller.clone()), target.clone(), 0)
+		crate::Pallet::<T>::register_metaverse(RawOrigin::Signed(caller.clone()).into(), 0);
+		grant_roles::<T>(&caller, r);
+	}: _(RawOrigin::Root, target.clone(), 0)
 	verify {
 		let metaverse = crate::Pallet::<T>::get_metaverse(0);
 		match metaverse {
@@ -99,6 +116,46 @@ benchmarks! {
 		}
 	}
 
+	// propose_metaverse_transfer
+	propose_metaverse_transfer{
+		let caller = funded_account::<T>("caller", 0);
+		let target = funded_account::<T>("target", 0);
+
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
+	}: _(RawOrigin::Signed(caller), target.clone(), 0)
+	verify {
+		assert!(crate::Pallet::<T>::get_pending_metaverse_transfer(0).is_some());
+	}
+
+	// accept_metaverse_transfer
+	accept_metaverse_transfer{
+		let r in 0 .. T::MaxRolesPerMetaverse::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let target = funded_account::<T>("target", 0);
+
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
+		crate::Pallet::<T>::register_metaverse(RawOrigin::Signed(caller.clone()).into(), 0);
+		grant_roles::<T>(&caller, r);
+		crate::Pallet::<T>::propose_metaverse_transfer(RawOrigin::Signed(caller).into(), target.clone(), 0);
+	}: _(RawOrigin::Signed(target.clone()), 0)
+	verify {
+		assert!(crate::Pallet::<T>::check_ownership(&target, &0));
+		assert_eq!(crate::Pallet::<T>::get_metaverse_role_count(0), 0);
+	}
+
+	// cancel_metaverse_transfer
+	cancel_metaverse_transfer{
+		let caller = funded_account::<T>("caller", 0);
+		let target = funded_account::<T>("target", 0);
+
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
+		crate::Pallet::<T>::propose_metaverse_transfer(RawOrigin::Signed(caller.clone()).into(), target, 0);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(crate::Pallet::<T>::get_pending_metaverse_transfer(0).is_none());
+	}
+
 	// freeze_metaverse
 	freeze_metaverse{
 		let caller = funded_account::<T>("caller", 0);
@@ -153,6 +210,25 @@ benchmarks! {
 		assert_eq!(crate::Pallet::<T>::get_metaverse(0), None);
 	}
 
+	// update_metaverse_metadata
+	update_metaverse_metadata{
+		let m in 1 .. T::MaxMetaverseMetadata::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
+
+		let field = vec![1u8; m as usize];
+		let profile = MetaverseProfile {
+			name: field.clone(),
+			description_uri: field.clone(),
+			logo_cid: field.clone(),
+			website: field.clone(),
+		};
+	}: _(RawOrigin::Signed(caller.clone()), 0, field.clone(), profile)
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_metaverse(0).map(|metaverse| metaverse.metadata), Some(field));
+	}
+
 	// register_metaverse
 	register_metaverse{
 		let caller = funded_account::<T>("caller", 0);
@@ -203,6 +279,142 @@ benchmarks! {
 		let staking_info = crate::Pallet::<T>::staking_info(caller);
 		assert_eq!(staking_info, amount.into());
 	}
+
+	// withdraw_unbonded
+	withdraw_unbonded{
+		let c in 1 .. T::MaxUnbondingChunks::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let amount = <<T as Config>::MinStakingAmount as Get<BalanceOf<T>>>::get();
+		let chunks: Vec<UnlockChunk<BalanceOf<T>>> = (0 .. c)
+			.map(|_| UnlockChunk { value: amount, unlock_round: 0 })
+			.collect();
+		Unbonding::<T>::insert(&caller, BoundedVec::try_from(chunks).unwrap());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(crate::Pallet::<T>::unbonding(&caller).is_empty());
+	}
+
+	// rebond
+	rebond{
+		let c in 1 .. T::MaxUnbondingChunks::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let amount = <<T as Config>::MinStakingAmount as Get<BalanceOf<T>>>::get();
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
+		crate::Pallet::<T>::register_metaverse(RawOrigin::Signed(caller.clone()).into(), 0);
+
+		let chunks: Vec<UnlockChunk<BalanceOf<T>>> = (0 .. c)
+			.map(|i| UnlockChunk { value: amount, unlock_round: i + 1 })
+			.collect();
+		Unbonding::<T>::insert(&caller, BoundedVec::try_from(chunks).unwrap());
+		let total = crate::Pallet::<T>::total_unbonding(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), 0, total)
+	verify {
+		assert!(crate::Pallet::<T>::unbonding(&caller).is_empty());
+		assert_eq!(crate::Pallet::<T>::staking_info(caller), total);
+	}
+
+	// set_staking_round_emission
+	set_staking_round_emission{
+		let amount: BalanceOf<T> = dollar(10).unique_saturated_into();
+	}: _(RawOrigin::Root, amount)
+	verify {
+		assert_eq!(crate::Pallet::<T>::staking_round_emission(), amount);
+	}
+
+	// claim_staking_reward
+	claim_staking_reward{
+		let s in 1 .. T::MaxNumberOfStakersPerMetaverse::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let amount = <<T as Config>::MinStakingAmount as Get<BalanceOf<T>>>::get();
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
+		crate::Pallet::<T>::register_metaverse(RawOrigin::Signed(caller.clone()).into(), 0);
+		for i in 0 .. s {
+			let staker = funded_account::<T>("staker", i);
+			crate::Pallet::<T>::stake(RawOrigin::Signed(staker).into(), 0, (amount+1u32.into()).into());
+		}
+
+		let round = crate::Pallet::<T>::staking_round().current;
+		let reward: BalanceOf<T> = dollar(10).unique_saturated_into();
+		T::MultiCurrency::deposit(FungibleTokenId::MiningResource(0), &crate::Pallet::<T>::get_staking_reward_account(), reward);
+		MetaverseStakingRewards::<T>::insert(0, round, reward);
+	}: _(RawOrigin::Signed(caller.clone()), 0, round)
+	verify {
+		assert_eq!(crate::Pallet::<T>::get_metaverse_staking_reward(0, round), None);
+	}
+
+	// new_staking_round
+	new_staking_round{
+		let caller = funded_account::<T>("caller", 0);
+		let amount = <<T as Config>::MinStakingAmount as Get<BalanceOf<T>>>::get();
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), caller.clone(), vec![1]);
+		crate::Pallet::<T>::register_metaverse(RawOrigin::Signed(caller.clone()).into(), 0);
+		crate::Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 0, (amount+1u32.into()).into());
+		crate::Pallet::<T>::set_staking_round_emission(RawOrigin::Root.into(), dollar(10).unique_saturated_into());
+
+		let round = crate::Pallet::<T>::staking_round();
+		let now = round.first + round.length.into();
+	}: {
+		crate::Pallet::<T>::on_initialize(now);
+	}
+	verify {
+		assert_eq!(crate::Pallet::<T>::staking_round().current, round.current + 1);
+	}
+
+	// grant_metaverse_role
+	grant_metaverse_role{
+		let owner = funded_account::<T>("owner", 0);
+		let admin = funded_account::<T>("admin", 0);
+		let target = funded_account::<T>("target", 0);
+
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), owner.clone(), vec![1]);
+		crate::Pallet::<T>::grant_metaverse_role(RawOrigin::Signed(owner).into(), 0, admin.clone(), MetaverseRole::Admin);
+	}: _(RawOrigin::Signed(admin), 0, target.clone(), MetaverseRole::LandManager)
+	verify {
+		assert!(crate::Pallet::<T>::has_role(&target, &0, MetaverseRole::LandManager));
+	}
+
+	// revoke_metaverse_role
+	revoke_metaverse_role{
+		let owner = funded_account::<T>("owner", 0);
+		let admin = funded_account::<T>("admin", 0);
+		let target = funded_account::<T>("target", 0);
+
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), owner.clone(), vec![1]);
+		crate::Pallet::<T>::grant_metaverse_role(RawOrigin::Signed(owner).into(), 0, admin.clone(), MetaverseRole::Admin);
+		crate::Pallet::<T>::grant_metaverse_role(RawOrigin::Signed(admin.clone()).into(), 0, target.clone(), MetaverseRole::LandManager);
+	}: _(RawOrigin::Signed(admin), 0, target.clone(), MetaverseRole::LandManager)
+	verify {
+		assert!(!crate::Pallet::<T>::has_role(&target, &0, MetaverseRole::LandManager));
+	}
+
+	// settle_round_rewards
+	settle_round_rewards{
+		let m in 1 .. T::MaxMetaverseRewardsPerBlock::get();
+
+		let amount = <<T as Config>::MinStakingAmount as Get<BalanceOf<T>>>::get();
+		for i in 0 .. m {
+			let owner = funded_account::<T>("owner", i);
+			crate::Pallet::<T>::create_metaverse(RawOrigin::Root.into(), owner.clone(), vec![1]);
+			crate::Pallet::<T>::register_metaverse(RawOrigin::Signed(owner.clone()).into(), i.into());
+			crate::Pallet::<T>::stake(RawOrigin::Signed(owner).into(), i.into(), (amount+1u32.into()).into());
+		}
+
+		let round = crate::Pallet::<T>::staking_round();
+		MetaverseStakingSnapshots::<T>::mutate(round.current, |snapshot| {
+			if let Some(snapshot) = snapshot {
+				snapshot.rewards = dollar(10).unique_saturated_into();
+			}
+		});
+		PendingRewardSettlement::<T>::put((round.current, 0));
+	}: {
+		crate::Pallet::<T>::on_initialize(round.first);
+	}
+	verify {
+		assert_eq!(crate::Pallet::<T>::pending_reward_settlement(), None);
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
@@ -23,19 +23,20 @@ use frame_support::{
 	ensure,
 	pallet_prelude::*,
 	traits::{Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency},
-	PalletId,
+	transactional, PalletId,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
 use orml_traits::MultiCurrency;
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
-	DispatchError, Perbill,
+	traits::{AccountIdConversion, CheckedAdd, Hash as HashT, One, Saturating, Zero},
+	ArithmeticError, DispatchError, DispatchResult, Perbill,
 };
-use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryFrom, prelude::*};
 
 use bc_primitives::*;
 use bc_primitives::{MetaverseInfo, MetaverseTrait};
 pub use pallet::*;
+use primitives::staking::RoundInfo;
 use primitives::{FungibleTokenId, MetaverseId, RoundIndex};
 pub use weights::WeightInfo;
 
@@ -61,6 +62,8 @@ pub struct MetaverseStakingSnapshot<Balance> {
 
 const LOCK_STAKING: LockIdentifier = *b"stakelok";
 
+const STAKING_REWARD_ID: [u8; 8] = *b"bcstkrwd";
+
 /// Storing the reward detail of metaverse that store the list of stakers for each metaverse
 /// This will be used to reward metaverse owner and the stakers.
 #[derive(Clone, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
@@ -73,13 +76,31 @@ pub struct MetaverseStakingPoints<AccountId: Ord, Balance: HasCompact> {
 	claimed_rewards: Balance,
 }
 
+/// A metaverse transfer waiting for the recipient to accept
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MetaverseTransferProposal<AccountId, BlockNumber> {
+	/// Owner proposing the transfer
+	pub from: AccountId,
+	/// Account that needs to accept the transfer
+	pub to: AccountId,
+	/// Last block at which the transfer can be accepted
+	pub expires_at: BlockNumber,
+}
+
+/// A chunk of unstaked balance that stays locked until `unlock_round`
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UnlockChunk<Balance> {
+	/// Amount of unstaked balance
+	pub value: Balance,
+	/// Round from which the balance can be withdrawn
+	pub unlock_round: RoundIndex,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use orml_traits::MultiCurrencyExtended;
-	use sp_runtime::traits::{CheckedAdd, Saturating};
-	use sp_runtime::ArithmeticError;
+	use sp_std::convert::TryInto;
 
-	use primitives::staking::RoundInfo;
 	use primitives::RoundIndex;
 
 	use super::*;
@@ -106,6 +127,12 @@ pub mod pallet {
 		type MetaverseTreasury: Get<PalletId>;
 		#[pallet::constant]
 		type MaxMetaverseMetadata: Get<u32>;
+		/// Number of blocks a metaverse transfer proposal stays valid
+		#[pallet::constant]
+		type MetaverseTransferExpiry: Get<Self::BlockNumber>;
+		/// Maximum number of metadata versions kept per metaverse
+		#[pallet::constant]
+		type MaxMetadataHistory: Get<u32>;
 		/// Minimum contribution
 		#[pallet::constant]
 		type MinContribution: Get<BalanceOf<Self>>;
@@ -117,6 +144,20 @@ pub mod pallet {
 		type MinStakingAmount: Get<BalanceOf<Self>>;
 		/// Maximum amount of stakers per metaverse
 		type MaxNumberOfStakersPerMetaverse: Get<u32>;
+		/// Source of the mining resource emitted as staking rewards
+		type StakingRewardSource: MiningResourceAllocation<Self::AccountId, BalanceOf<Self>>;
+		/// Maximum number of metaverses settled per block once a staking round ends
+		#[pallet::constant]
+		type MaxMetaverseRewardsPerBlock: Get<u32>;
+		/// Number of rounds unstaked balance stays locked before it can be withdrawn
+		#[pallet::constant]
+		type UnbondingRounds: Get<RoundIndex>;
+		/// Maximum number of unbonding chunks per staker
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+		/// Maximum number of roles delegated on a metaverse
+		#[pallet::constant]
+		type MaxRolesPerMetaverse: Get<u32>;
 		/// Weight implementation for estate extrinsics
 		type WeightInfo: WeightInfo;
 	}
@@ -141,6 +182,46 @@ pub mod pallet {
 	#[pallet::getter(fn get_freezing_metaverse)]
 	pub(super) type FreezedMetaverses<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, (), OptionQuery>;
 
+	/// Metaverse transfers waiting for the recipient to accept
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_metaverse_transfer)]
+	pub type PendingMetaverseTransfers<T: Config> =
+		StorageMap<_, Twox64Concat, MetaverseId, MetaverseTransferProposal<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Structured profile of metaverse
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_profile)]
+	pub type MetaverseProfiles<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, MetaverseProfile, OptionQuery>;
+
+	/// Most recent metadata versions of metaverse, oldest first and the current version last
+	#[pallet::storage]
+	#[pallet::getter(fn get_metadata_history)]
+	pub type MetaverseMetadataHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		MetaverseId,
+		BoundedVec<MetaverseMetadataVersion<T::Hash, T::BlockNumber>, T::MaxMetadataHistory>,
+		ValueQuery,
+	>;
+
+	/// Roles delegated by metaverse owner to other accounts
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_role)]
+	pub type MetaverseRoles<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MetaverseId,
+		Blake2_128Concat,
+		(T::AccountId, MetaverseRole),
+		(),
+		OptionQuery,
+	>;
+
+	/// Number of roles delegated on metaverse
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_role_count)]
+	pub type MetaverseRoleCount<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, u32, ValueQuery>;
+
 	/// Metaverse staking related storage
 
 	/// Staking round info
@@ -178,6 +259,33 @@ pub mod pallet {
 	#[pallet::getter(fn staking_info)]
 	pub(crate) type StakingInfo<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// Unstaked balance of individual staker waiting to be unlocked
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding)]
+	pub type Unbonding<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<UnlockChunk<BalanceOf<T>>, T::MaxUnbondingChunks>,
+		ValueQuery,
+	>;
+
+	/// Mining resource emitted as staking rewards at the end of each round
+	#[pallet::storage]
+	#[pallet::getter(fn staking_round_emission)]
+	pub type StakingRoundEmission<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Finished round and the next metaverse id waiting to be settled for that round
+	#[pallet::storage]
+	#[pallet::getter(fn pending_reward_settlement)]
+	pub type PendingRewardSettlement<T: Config> = StorageValue<_, (RoundIndex, MetaverseId), OptionQuery>;
+
+	/// Unclaimed staking reward of individual metaverse per finished round
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_staking_reward)]
+	pub type MetaverseStakingRewards<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, MetaverseId, Twox64Concat, RoundIndex, BalanceOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -191,6 +299,28 @@ pub mod pallet {
 		MetaverseStaked(T::AccountId, MetaverseId, BalanceOf<T>),
 		MetaverseUnstaked(T::AccountId, MetaverseId, BalanceOf<T>),
 		MetaverseStakingRewarded(T::AccountId, MetaverseId, RoundIndex, BalanceOf<T>),
+		/// Staking round emission updated [amount]
+		StakingRoundEmissionUpdated(BalanceOf<T>),
+		/// New staking round started [round, first block]
+		NewStakingRound(RoundIndex, T::BlockNumber),
+		/// Staking rewards of finished round funded [round, amount]
+		StakingRoundRewardsFunded(RoundIndex, BalanceOf<T>),
+		/// Staking reward of metaverse ready to be claimed [metaverse, round, amount]
+		MetaverseStakingRewardScheduled(MetaverseId, RoundIndex, BalanceOf<T>),
+		/// Unbonded balance unlocked [who, amount]
+		UnbondedWithdrawn(T::AccountId, BalanceOf<T>),
+		/// Unbonding balance staked again [who, metaverse, amount]
+		MetaverseRebonded(T::AccountId, MetaverseId, BalanceOf<T>),
+		/// Metaverse role granted [metaverse, who, role]
+		MetaverseRoleGranted(MetaverseId, T::AccountId, MetaverseRole),
+		/// Metaverse role revoked [metaverse, who, role]
+		MetaverseRoleRevoked(MetaverseId, T::AccountId, MetaverseRole),
+		/// Metaverse metadata updated [metaverse, version, hash]
+		MetaverseMetadataUpdated(MetaverseId, u32, T::Hash),
+		/// Metaverse transfer proposed [metaverse, from, to, expires at]
+		MetaverseTransferProposed(MetaverseId, T::AccountId, T::AccountId, T::BlockNumber),
+		/// Pending metaverse transfer cancelled [metaverse]
+		MetaverseTransferCancelled(MetaverseId),
 	}
 
 	#[pallet::error]
@@ -229,6 +359,30 @@ pub mod pallet {
 		MetaverseStakingAlreadyPaid,
 		/// Metaverse has no stake
 		MetaverseHasNoStake,
+		/// No staking reward to claim for this metaverse and round
+		NoStakingRewardToClaim,
+		/// Too many unbonding chunks, withdraw unlocked balance first
+		MaxUnbondingChunksExceeded,
+		/// No unbonded balance ready to be withdrawn
+		NothingToWithdraw,
+		/// No unbonding balance to stake again
+		NothingToRebond,
+		/// Account already holds this role
+		RoleAlreadyGranted,
+		/// Account does not hold this role
+		RoleNotFound,
+		/// Metaverse already has the maximum number of delegated roles
+		TooManyRoles,
+		/// Frozen metaverse cannot be transferred
+		MetaverseIsFrozen,
+		/// Metaverse cannot be transferred to its owner
+		CannotTransferToSelf,
+		/// No pending transfer for this metaverse
+		NoPendingMetaverseTransfer,
+		/// Metaverse transfer proposal has expired
+		MetaverseTransferExpired,
+		/// Recipient cannot cover the staking registration deposit of the metaverse
+		RecipientCannotCoverStakingDeposit,
 	}
 
 	#[pallet::call]
@@ -273,39 +427,109 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::transfer_metaverse())]
+		/// Force the transfer of metaverse to another account without acceptance, only council
+		/// can force a transfer. Owners go through `propose_metaverse_transfer`.
+		#[pallet::weight(T::WeightInfo::transfer_metaverse(T::MaxRolesPerMetaverse::get()))]
 		pub fn transfer_metaverse(
 			origin: OriginFor<T>,
 			to: T::AccountId,
 			metaverse_id: MetaverseId,
+		) -> DispatchResultWithPostInfo {
+			T::MetaverseCouncil::ensure_origin(origin)?;
+
+			let metaverse_info = Self::get_metaverse(metaverse_id).ok_or(Error::<T>::MetaverseInfoNotFound)?;
+			ensure!(!metaverse_info.is_frozen, Error::<T>::MetaverseIsFrozen);
+
+			if metaverse_info.owner == to {
+				// No change needed
+				return Ok(().into());
+			}
+
+			let role_count = Self::do_transfer_metaverse(&metaverse_info.owner, &to, metaverse_id)?;
+
+			Ok(Some(T::WeightInfo::transfer_metaverse(role_count)).into())
+		}
+
+		/// Propose to transfer metaverse to another account. The transfer only happens once the
+		/// recipient accepts it before the proposal expires.
+		#[pallet::weight(T::WeightInfo::propose_metaverse_transfer())]
+		pub fn propose_metaverse_transfer(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			metaverse_id: MetaverseId,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			// Get owner of the metaverse
-			MetaverseOwner::<T>::try_mutate_exists(
-				&who,
-				&metaverse_id,
-				|metaverse_by_owner| -> DispatchResultWithPostInfo {
-					// Ensure there is record of the metaverse owner with metaverse id, account
-					// id and delete them
-					ensure!(metaverse_by_owner.is_some(), Error::<T>::NoPermission);
-
-					if who == to {
-						// No change needed
-						return Ok(().into());
-					}
-
-					*metaverse_by_owner = None;
-					MetaverseOwner::<T>::insert(to.clone(), metaverse_id.clone(), ());
-
-					Metaverses::<T>::try_mutate_exists(&metaverse_id, |metaverse| -> DispatchResultWithPostInfo {
-						let mut metaverse_record = metaverse.as_mut().ok_or(Error::<T>::NoPermission)?;
-						metaverse_record.owner = to.clone();
-						Self::deposit_event(Event::<T>::TransferredMetaverse(metaverse_id, who.clone(), to.clone()));
-
-						Ok(().into())
-					})
+
+			let metaverse_info = Self::get_metaverse(metaverse_id).ok_or(Error::<T>::MetaverseInfoNotFound)?;
+			ensure!(metaverse_info.owner == who, Error::<T>::NoPermission);
+			ensure!(!metaverse_info.is_frozen, Error::<T>::MetaverseIsFrozen);
+			ensure!(who != to, Error::<T>::CannotTransferToSelf);
+
+			let expires_at =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::MetaverseTransferExpiry::get());
+			PendingMetaverseTransfers::<T>::insert(
+				metaverse_id,
+				MetaverseTransferProposal {
+					from: who.clone(),
+					to: to.clone(),
+					expires_at,
 				},
-			)
+			);
+
+			Self::deposit_event(Event::<T>::MetaverseTransferProposed(metaverse_id, who, to, expires_at));
+
+			Ok(().into())
+		}
+
+		/// Accept a pending metaverse transfer proposed to the origin account
+		#[pallet::weight(T::WeightInfo::accept_metaverse_transfer(T::MaxRolesPerMetaverse::get()))]
+		pub fn accept_metaverse_transfer(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let proposal =
+				Self::get_pending_metaverse_transfer(metaverse_id).ok_or(Error::<T>::NoPendingMetaverseTransfer)?;
+			ensure!(proposal.to == who, Error::<T>::NoPermission);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= proposal.expires_at,
+				Error::<T>::MetaverseTransferExpired
+			);
+
+			let metaverse_info = Self::get_metaverse(metaverse_id).ok_or(Error::<T>::MetaverseInfoNotFound)?;
+			// Ownership may have changed since the proposal was made
+			ensure!(metaverse_info.owner == proposal.from, Error::<T>::NoPermission);
+			ensure!(!metaverse_info.is_frozen, Error::<T>::MetaverseIsFrozen);
+
+			let role_count = Self::do_transfer_metaverse(&proposal.from, &who, metaverse_id)?;
+
+			Ok(Some(T::WeightInfo::accept_metaverse_transfer(role_count)).into())
+		}
+
+		/// Cancel a pending metaverse transfer. The owner and the recipient can cancel at any
+		/// time, anyone can clear an expired proposal.
+		#[pallet::weight(T::WeightInfo::cancel_metaverse_transfer())]
+		pub fn cancel_metaverse_transfer(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let proposal =
+				Self::get_pending_metaverse_transfer(metaverse_id).ok_or(Error::<T>::NoPendingMetaverseTransfer)?;
+			ensure!(
+				who == proposal.from
+					|| who == proposal.to
+					|| <frame_system::Pallet<T>>::block_number() > proposal.expires_at,
+				Error::<T>::NoPermission
+			);
+
+			PendingMetaverseTransfers::<T>::remove(metaverse_id);
+
+			Self::deposit_event(Event::<T>::MetaverseTransferCancelled(metaverse_id));
+
+			Ok(().into())
 		}
 
 		#[pallet::weight(T::WeightInfo::freeze_metaverse())]
@@ -351,10 +575,134 @@ pub mod pallet {
 
 			MetaverseOwner::<T>::remove(metaverse_info.owner, &metaverse_id);
 			Metaverses::<T>::remove(&metaverse_id);
+			MetaverseRoles::<T>::remove_prefix(&metaverse_id, None);
+			MetaverseRoleCount::<T>::remove(&metaverse_id);
+			MetaverseProfiles::<T>::remove(&metaverse_id);
+			MetaverseMetadataHistory::<T>::remove(&metaverse_id);
+			PendingMetaverseTransfers::<T>::remove(&metaverse_id);
 			Self::deposit_event(Event::<T>::MetaverseDestroyed(metaverse_id));
 			Ok(().into())
 		}
 
+		/// Update metadata and profile of metaverse, recording the new version in its history.
+		/// Only metaverse owner and admins can update the metadata.
+		#[pallet::weight(T::WeightInfo::update_metaverse_metadata(Self::longest_metadata_field(&metadata, &profile)))]
+		pub fn update_metaverse_metadata(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			metadata: MetaverseMetadata,
+			profile: MetaverseProfile,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Metaverses::<T>::contains_key(&metaverse_id),
+				Error::<T>::MetaverseInfoNotFound
+			);
+			ensure!(
+				Self::has_role(&who, &metaverse_id, MetaverseRole::Admin),
+				Error::<T>::NoPermission
+			);
+
+			let max_metadata = T::MaxMetaverseMetadata::get() as usize;
+			ensure!(
+				metadata.len() <= max_metadata
+					&& profile.name.len() <= max_metadata
+					&& profile.description_uri.len() <= max_metadata
+					&& profile.logo_cid.len() <= max_metadata
+					&& profile.website.len() <= max_metadata,
+				Error::<T>::MaxMetadataExceeded
+			);
+
+			Metaverses::<T>::try_mutate(&metaverse_id, |maybe_metaverse| -> DispatchResult {
+				let metaverse_info = maybe_metaverse.as_mut().ok_or(Error::<T>::MetaverseInfoNotFound)?;
+
+				// Metaverses created before history was kept start from their existing metadata
+				if Self::get_metadata_history(metaverse_id).is_empty() {
+					Self::record_metadata_version(
+						metaverse_id,
+						&metaverse_info.metadata,
+						&Self::get_metaverse_profile(metaverse_id).unwrap_or_default(),
+					);
+				}
+
+				metaverse_info.metadata = metadata.clone();
+				Ok(())
+			})?;
+
+			MetaverseProfiles::<T>::insert(metaverse_id, profile.clone());
+			let (version, hash) = Self::record_metadata_version(metaverse_id, &metadata, &profile);
+
+			Self::deposit_event(Event::<T>::MetaverseMetadataUpdated(metaverse_id, version, hash));
+
+			Ok(().into())
+		}
+
+		/// Grant a role on metaverse to an account.
+		/// Metaverse owner can grant every role, admins can grant every role except admin.
+		#[pallet::weight(T::WeightInfo::grant_metaverse_role())]
+		pub fn grant_metaverse_role(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			who: T::AccountId,
+			role: MetaverseRole,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Metaverses::<T>::contains_key(&metaverse_id),
+				Error::<T>::MetaverseInfoNotFound
+			);
+			ensure!(
+				Self::can_manage_role(&sender, metaverse_id, role),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				!MetaverseRoles::<T>::contains_key(&metaverse_id, (&who, role)),
+				Error::<T>::RoleAlreadyGranted
+			);
+
+			MetaverseRoleCount::<T>::try_mutate(&metaverse_id, |count| -> DispatchResult {
+				ensure!(*count < T::MaxRolesPerMetaverse::get(), Error::<T>::TooManyRoles);
+				*count += 1;
+				Ok(())
+			})?;
+			MetaverseRoles::<T>::insert(&metaverse_id, (&who, role), ());
+
+			Self::deposit_event(Event::<T>::MetaverseRoleGranted(metaverse_id, who, role));
+
+			Ok(().into())
+		}
+
+		/// Revoke a role on metaverse from an account.
+		/// Metaverse owner can revoke every role, admins can revoke every role except admin and
+		/// any account can renounce its own role.
+		#[pallet::weight(T::WeightInfo::revoke_metaverse_role())]
+		pub fn revoke_metaverse_role(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			who: T::AccountId,
+			role: MetaverseRole,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				sender == who || Self::can_manage_role(&sender, metaverse_id, role),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				MetaverseRoles::<T>::contains_key(&metaverse_id, (&who, role)),
+				Error::<T>::RoleNotFound
+			);
+
+			MetaverseRoles::<T>::remove(&metaverse_id, (&who, role));
+			MetaverseRoleCount::<T>::mutate(&metaverse_id, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::<T>::MetaverseRoleRevoked(metaverse_id, who, role));
+
+			Ok(().into())
+		}
+
 		/// Register metaverse for staking
 		/// only metaverse owner can register for staking
 		#[pallet::weight(T::WeightInfo::register_metaverse())]
@@ -395,86 +743,27 @@ pub mod pallet {
 			);
 
 			// Get the staking ledger or create an entry if it doesn't exist.
-			let mut staking_info = Self::staking_info(&who);
+			let staking_info = Self::staking_info(&who);
 
 			// Ensure that staker has enough balance to stake.
 			let free_balance = T::Currency::free_balance(&who).saturating_sub(T::MinStakingAmount::get());
 
 			// Remove already locked funds from the free balance
-			let available_balance = free_balance.saturating_sub(staking_info);
+			let available_balance = free_balance
+				.saturating_sub(staking_info)
+				.saturating_sub(Self::total_unbonding(&who));
 			let stake_amount = value.min(available_balance);
 			ensure!(stake_amount > Zero::zero(), Error::<T>::NotEnoughBalanceToStake);
 
-			// Get the latest round staking point info or create it if metaverse hasn't been staked yet so far.
-			let current_staking_round: RoundInfo<T::BlockNumber> = Self::staking_round();
-
-			if !MetaverseRoundStake::<T>::contains_key(&metaverse_id, current_staking_round.current) {
-				let stakers: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
-
-				let new_metaverse_stake_per_round: MetaverseStakingPoints<T::AccountId, BalanceOf<T>> =
-					MetaverseStakingPoints {
-						total: 0u32.into(),
-						claimed_rewards: 0u32.into(),
-						stakers: stakers,
-					};
+			Self::do_stake(&who, metaverse_id, stake_amount)?;
 
-				// Update staked information for contract in current round
-				MetaverseRoundStake::<T>::insert(
-					metaverse_id.clone(),
-					current_staking_round.current,
-					new_metaverse_stake_per_round,
-				);
-			}
-
-			// Get staking info of metaverse and current round
-			let mut metaverse_stake_per_round: MetaverseStakingPoints<T::AccountId, BalanceOf<T>> =
-				Self::get_metaverse_stake_per_round(&metaverse_id, current_staking_round.current)
-					.ok_or(Error::<T>::MetaverseStakingInfoNotFound)?;
-
-			// Ensure that we can add additional staker for the metaverse.
-			ensure!(
-				metaverse_stake_per_round.stakers.len() < T::MaxNumberOfStakersPerMetaverse::get() as usize,
-				Error::<T>::MaximumAmountOfStakersPerMetaverse
-			);
-			// Increment ledger and total staker value for a metaverse.
-			staking_info = staking_info
-				.checked_add(&stake_amount)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			let individual_staker = metaverse_stake_per_round.stakers.entry(who.clone()).or_default();
-			*individual_staker = individual_staker
-				.checked_add(&stake_amount)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			ensure!(
-				*individual_staker >= T::MinStakingAmount::get(),
-				Error::<T>::MinimumStakingAmountRequired,
-			);
-
-			// Update total staked value in current round
-			MetaverseStakingSnapshots::<T>::mutate(current_staking_round.current, |may_be_staking_snapshot| {
-				if let Some(snapshot) = may_be_staking_snapshot {
-					snapshot.staked = snapshot.staked.saturating_add(stake_amount)
-				}
-			});
-
-			// Update staking info of origin
-			Self::update_staking_info(&who, staking_info);
-
-			// Update staked information for contract in current round
-			MetaverseRoundStake::<T>::insert(
-				metaverse_id.clone(),
-				current_staking_round.current,
-				metaverse_stake_per_round,
-			);
-
-			Self::deposit_event(Event::<T>::MetaverseStaked(who.clone(), metaverse_id, stake_amount));
 			Ok(().into())
 		}
 
-		/// Unstake and withdraw balance of the origin account.
+		/// Unstake balance of the origin account.
 		/// If user unstake below minimum staking amount, the entire staking of that origin will be
-		/// removed Unstake will on be kicked off from the begining of the next round.
+		/// removed. Unstaked balance stays locked for `UnbondingRounds` rounds before it can be
+		/// withdrawn with `withdraw_unbonded`.
 		#[pallet::weight(T::WeightInfo::unstake_and_withdraw())]
 		pub fn unstake_and_withdraw(
 			origin: OriginFor<T>,
@@ -494,7 +783,7 @@ pub mod pallet {
 
 			// Get staking info of metaverse and current round
 			let mut metaverse_stake_per_round: MetaverseStakingPoints<T::AccountId, BalanceOf<T>> =
-				Self::get_metaverse_stake_per_round(&metaverse_id, current_staking_round.current)
+				Self::current_metaverse_stake(metaverse_id, current_staking_round.current)
 					.ok_or(Error::<T>::MetaverseStakingInfoNotFound)?;
 
 			ensure!(
@@ -516,6 +805,20 @@ pub mod pallet {
 				value
 			};
 
+			let unlock_round = current_staking_round.current.saturating_add(T::UnbondingRounds::get());
+			Unbonding::<T>::try_mutate(&who, |chunks| -> DispatchResult {
+				match chunks.iter_mut().find(|chunk| chunk.unlock_round == unlock_round) {
+					Some(chunk) => chunk.value = chunk.value.saturating_add(amount_to_unstake),
+					None => chunks
+						.try_push(UnlockChunk {
+							value: amount_to_unstake,
+							unlock_round,
+						})
+						.map_err(|_| Error::<T>::MaxUnbondingChunksExceeded)?,
+				}
+				Ok(())
+			})?;
+
 			let staking_info = Self::staking_info(&who);
 			Self::update_staking_info(&who, staking_info.saturating_sub(amount_to_unstake));
 
@@ -542,10 +845,141 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Unlock all unbonding chunks of the origin account that reached their unlock round
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded(T::MaxUnbondingChunks::get()))]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let current_round = Self::staking_round().current;
+			let mut chunks = Self::unbonding(&who);
+			let chunk_count = chunks.len() as u32;
+			let mut withdrawn: BalanceOf<T> = Zero::zero();
+			chunks.retain(|chunk| {
+				if chunk.unlock_round <= current_round {
+					withdrawn = withdrawn.saturating_add(chunk.value);
+					false
+				} else {
+					true
+				}
+			});
+			ensure!(!withdrawn.is_zero(), Error::<T>::NothingToWithdraw);
+
+			if chunks.is_empty() {
+				Unbonding::<T>::remove(&who);
+			} else {
+				Unbonding::<T>::insert(&who, chunks);
+			}
+			Self::update_staking_info(&who, Self::staking_info(&who));
+
+			Self::deposit_event(Event::<T>::UnbondedWithdrawn(who, withdrawn));
+
+			Ok(Some(T::WeightInfo::withdraw_unbonded(chunk_count)).into())
+		}
+
+		/// Stake unbonding balance of the origin account again, starting from the most recently
+		/// unstaked chunk
+		#[pallet::weight(T::WeightInfo::rebond(T::MaxUnbondingChunks::get()))]
+		#[transactional]
+		pub fn rebond(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			#[pallet::compact] value: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				RegisteredMetaverse::<T>::contains_key(&metaverse_id),
+				Error::<T>::NotRegisteredForStaking
+			);
+
+			let mut chunks = Self::unbonding(&who).into_inner();
+			let chunk_count = chunks.len() as u32;
+			let rebond_amount = value.min(Self::total_unbonding(&who));
+			ensure!(!rebond_amount.is_zero(), Error::<T>::NothingToRebond);
+
+			let mut remaining = rebond_amount;
+			while let Some(chunk) = chunks.last_mut() {
+				if chunk.value <= remaining {
+					remaining = remaining.saturating_sub(chunk.value);
+					chunks.pop();
+				} else {
+					chunk.value = chunk.value.saturating_sub(remaining);
+					remaining = Zero::zero();
+				}
+
+				if remaining.is_zero() {
+					break;
+				}
+			}
+
+			if chunks.is_empty() {
+				Unbonding::<T>::remove(&who);
+			} else {
+				let chunks: BoundedVec<_, T::MaxUnbondingChunks> =
+					chunks.try_into().map_err(|_| Error::<T>::MaxUnbondingChunksExceeded)?;
+				Unbonding::<T>::insert(&who, chunks);
+			}
+
+			Self::do_stake(&who, metaverse_id, rebond_amount)?;
+
+			Self::deposit_event(Event::<T>::MetaverseRebonded(who, metaverse_id, rebond_amount));
+
+			Ok(Some(T::WeightInfo::rebond(chunk_count)).into())
+		}
+
+		/// Set the amount of mining resource emitted as staking rewards for every finished round
+		#[pallet::weight(T::WeightInfo::set_staking_round_emission())]
+		pub fn set_staking_round_emission(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::MetaverseCouncil::ensure_origin(origin)?;
+
+			StakingRoundEmission::<T>::put(amount);
+
+			Self::deposit_event(Event::<T>::StakingRoundEmissionUpdated(amount));
+
+			Ok(().into())
+		}
+
+		/// Pay out the staking reward of a metaverse for a finished round to all of its stakers.
+		/// Anyone can trigger the payout, rewards of past rounds stay claimable until paid.
+		#[pallet::weight(T::WeightInfo::claim_staking_reward(T::MaxNumberOfStakersPerMetaverse::get()))]
+		#[transactional]
+		pub fn claim_staking_reward(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			round: RoundIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let reward =
+				Self::get_metaverse_staking_reward(metaverse_id, round).ok_or(Error::<T>::NoStakingRewardToClaim)?;
+
+			let paid_stakers = Self::pay_staker(metaverse_id, round, reward)?;
+			MetaverseStakingRewards::<T>::remove(metaverse_id, round);
+
+			Ok(Some(T::WeightInfo::claim_staking_reward(paid_stakers)).into())
+		}
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// Settle the previous round before moving on to the next one
+			if let Some((round, next_metaverse_id)) = Self::pending_reward_settlement() {
+				return Self::settle_round_rewards(round, next_metaverse_id);
+			}
+
+			let round = Self::staking_round();
+			if round.should_update(now) {
+				Self::start_new_round(now, round)
+			} else {
+				T::DbWeight::get().reads(2)
+			}
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -556,6 +990,8 @@ impl<T: Config> Pallet<T> {
 			Ok(current_id)
 		})?;
 
+		Self::record_metadata_version(metaverse_id, &metadata, &MetaverseProfile::default());
+
 		let metaverse_info = MetaverseInfo {
 			owner: owner.clone(),
 			currency_id: FungibleTokenId::NativeToken(0),
@@ -576,23 +1012,329 @@ impl<T: Config> Pallet<T> {
 		T::MetaverseTreasury::get().into_account()
 	}
 
-	/// Update staking info of origin
+	/// Add stake of origin to metaverse in the current round and lock it
+	fn do_stake(who: &T::AccountId, metaverse_id: MetaverseId, stake_amount: BalanceOf<T>) -> DispatchResult {
+		let mut staking_info = Self::staking_info(who);
+
+		// Get the latest round staking point info or create it if metaverse hasn't been staked yet so far.
+		let current_staking_round: RoundInfo<T::BlockNumber> = Self::staking_round();
+
+		// Get staking info of metaverse and current round
+		let mut metaverse_stake_per_round: MetaverseStakingPoints<T::AccountId, BalanceOf<T>> =
+			Self::current_metaverse_stake(metaverse_id, current_staking_round.current).unwrap_or(
+				MetaverseStakingPoints {
+					total: Zero::zero(),
+					claimed_rewards: Zero::zero(),
+					stakers: BTreeMap::new(),
+				},
+			);
+
+		// Ensure that we can add additional staker for the metaverse.
+		ensure!(
+			metaverse_stake_per_round.stakers.contains_key(who)
+				|| metaverse_stake_per_round.stakers.len() < T::MaxNumberOfStakersPerMetaverse::get() as usize,
+			Error::<T>::MaximumAmountOfStakersPerMetaverse
+		);
+		// Increment ledger and total staker value for a metaverse.
+		staking_info = staking_info
+			.checked_add(&stake_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let individual_staker = metaverse_stake_per_round.stakers.entry(who.clone()).or_default();
+		*individual_staker = individual_staker
+			.checked_add(&stake_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			*individual_staker >= T::MinStakingAmount::get(),
+			Error::<T>::MinimumStakingAmountRequired,
+		);
+
+		metaverse_stake_per_round.total = metaverse_stake_per_round
+			.total
+			.checked_add(&stake_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		// Update total staked value in current round
+		MetaverseStakingSnapshots::<T>::mutate(current_staking_round.current, |may_be_staking_snapshot| {
+			let snapshot = may_be_staking_snapshot.get_or_insert_with(Default::default);
+			snapshot.staked = snapshot.staked.saturating_add(stake_amount)
+		});
+
+		// Update staking info of origin
+		Self::update_staking_info(who, staking_info);
+
+		// Update staked information for contract in current round
+		MetaverseRoundStake::<T>::insert(
+			metaverse_id.clone(),
+			current_staking_round.current,
+			metaverse_stake_per_round,
+		);
+
+		Self::deposit_event(Event::<T>::MetaverseStaked(who.clone(), metaverse_id, stake_amount));
+		Ok(())
+	}
+
+	/// Move metaverse ownership to the recipient together with its staking registration and
+	/// return the number of delegated roles cleared.
+	/// Social token treasury in tokenization is keyed by metaverse and follows the new owner.
+	#[transactional]
+	fn do_transfer_metaverse(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		metaverse_id: MetaverseId,
+	) -> Result<u32, DispatchError> {
+		let deposit = T::MetaverseRegistrationDeposit::get();
+		let registrant = Self::get_registered_metaverse(metaverse_id);
+		if registrant.is_some() {
+			ensure!(
+				T::Currency::can_reserve(to, deposit),
+				Error::<T>::RecipientCannotCoverStakingDeposit
+			);
+		}
+
+		Metaverses::<T>::try_mutate_exists(&metaverse_id, |metaverse| -> DispatchResult {
+			let metaverse_record = metaverse.as_mut().ok_or(Error::<T>::NoPermission)?;
+			metaverse_record.owner = to.clone();
+			Ok(())
+		})?;
+
+		if let Some(registrant) = registrant {
+			T::Currency::reserve(to, deposit)?;
+			T::Currency::unreserve(&registrant, deposit);
+			RegisteredMetaverse::<T>::insert(metaverse_id, to.clone());
+		}
+
+		MetaverseOwner::<T>::remove(from, metaverse_id);
+		MetaverseOwner::<T>::insert(to, metaverse_id, ());
+		// Roles delegated by the previous owner do not carry over
+		let role_count = MetaverseRoleCount::<T>::take(&metaverse_id);
+		MetaverseRoles::<T>::remove_prefix(&metaverse_id, None);
+		PendingMetaverseTransfers::<T>::remove(metaverse_id);
+
+		Self::deposit_event(Event::<T>::TransferredMetaverse(metaverse_id, from.clone(), to.clone()));
+
+		Ok(role_count)
+	}
+
+	/// Append a metadata version to the history of metaverse, dropping the oldest versions beyond
+	/// `MaxMetadataHistory`
+	fn record_metadata_version(
+		metaverse_id: MetaverseId,
+		metadata: &MetaverseMetadata,
+		profile: &MetaverseProfile,
+	) -> (u32, T::Hash) {
+		let hash = T::Hashing::hash_of(&(metadata, profile));
+
+		let version = MetaverseMetadataHistory::<T>::mutate(metaverse_id, |history| {
+			let version = history.last().map_or(0, |latest| latest.version.saturating_add(1));
+
+			let mut versions = sp_std::mem::take(history).into_inner();
+			versions.push(MetaverseMetadataVersion {
+				version,
+				hash,
+				updated_at: <frame_system::Pallet<T>>::block_number(),
+			});
+			let excess = versions.len().saturating_sub(T::MaxMetadataHistory::get() as usize);
+			versions.drain(..excess);
+			*history = BoundedVec::try_from(versions).unwrap_or_default();
+
+			version
+		});
+
+		(version, hash)
+	}
+
+	/// Length of the longest metadata or profile field, the field length
+	/// `update_metaverse_metadata` is benchmarked with
+	fn longest_metadata_field(metadata: &MetaverseMetadata, profile: &MetaverseProfile) -> u32 {
+		[
+			metadata.len(),
+			profile.name.len(),
+			profile.description_uri.len(),
+			profile.logo_cid.len(),
+			profile.website.len(),
+		]
+		.iter()
+		.copied()
+		.max()
+		.unwrap_or_default() as u32
+	}
+
+	/// Current metadata and profile of metaverse
+	pub fn get_metaverse_metadata(metaverse_id: MetaverseId) -> Option<(MetaverseMetadata, MetaverseProfile)> {
+		Self::get_metaverse(metaverse_id).map(|metaverse_info| {
+			(
+				metaverse_info.metadata,
+				Self::get_metaverse_profile(metaverse_id).unwrap_or_default(),
+			)
+		})
+	}
+
+	/// Recorded metadata versions of metaverse, oldest first and the current version last
+	pub fn get_metaverse_metadata_versions(
+		metaverse_id: MetaverseId,
+	) -> Vec<MetaverseMetadataVersion<T::Hash, T::BlockNumber>> {
+		Self::get_metadata_history(metaverse_id).into_inner()
+	}
+
+	/// Existing metaverses among the `limit` ids starting from `start`, ordered by id. Destroyed
+	/// metaverses leave gaps in a page, the next page starts from `start + limit`.
+	pub fn get_metaverses_paginated(start: MetaverseId, limit: u32) -> Vec<(MetaverseId, MetaverseInfo<T::AccountId>)> {
+		let end = Self::next_metaverse_id().min(start.saturating_add(limit.into()));
+		(start..end)
+			.filter_map(|metaverse_id| {
+				Self::get_metaverse(metaverse_id).map(|metaverse_info| (metaverse_id, metaverse_info))
+			})
+			.collect()
+	}
+
+	/// Staking registration and total stake of metaverse in the current round
+	pub fn get_metaverse_staking_info(metaverse_id: MetaverseId) -> (Option<T::AccountId>, BalanceOf<T>) {
+		let total_stake = Self::current_metaverse_stake(metaverse_id, Self::staking_round().current)
+			.map(|staking_points| staking_points.total)
+			.unwrap_or_else(Zero::zero);
+
+		(Self::get_registered_metaverse(metaverse_id), total_stake)
+	}
+
+	/// Check if account can grant or revoke the role on metaverse
+	fn can_manage_role(who: &T::AccountId, metaverse_id: MetaverseId, role: MetaverseRole) -> bool {
+		Self::check_ownership(who, &metaverse_id)
+			|| (role != MetaverseRole::Admin && Self::has_role(who, &metaverse_id, MetaverseRole::Admin))
+	}
+
+	/// Update staking info of origin, the lock covers both staked and unbonding balance
 	fn update_staking_info(who: &T::AccountId, staking_info: BalanceOf<T>) {
 		if staking_info.is_zero() {
 			StakingInfo::<T>::remove(&who);
-			T::Currency::remove_lock(LOCK_STAKING, &who);
 		} else {
-			T::Currency::set_lock(LOCK_STAKING, &who, staking_info, WithdrawReasons::all());
 			StakingInfo::<T>::insert(who, staking_info);
 		}
+
+		let locked = staking_info.saturating_add(Self::total_unbonding(who));
+		if locked.is_zero() {
+			T::Currency::remove_lock(LOCK_STAKING, &who);
+		} else {
+			T::Currency::set_lock(LOCK_STAKING, &who, locked, WithdrawReasons::all());
+		}
+	}
+
+	/// Total unstaked balance of staker that is still locked
+	pub fn total_unbonding(who: &T::AccountId) -> BalanceOf<T> {
+		Self::unbonding(who)
+			.iter()
+			.fold(Zero::zero(), |total: BalanceOf<T>, chunk| {
+				total.saturating_add(chunk.value)
+			})
+	}
+
+	/// The account holding mining resource emitted for staking rewards
+	pub fn get_staking_reward_account() -> T::AccountId {
+		T::MetaverseTreasury::get().into_sub_account(STAKING_REWARD_ID)
+	}
+
+	/// Staking points of metaverse in the current round, carried forward from the previous round
+	/// if nobody has staked or unstaked since
+	fn current_metaverse_stake(
+		metaverse_id: MetaverseId,
+		current_round: RoundIndex,
+	) -> Option<MetaverseStakingPoints<T::AccountId, BalanceOf<T>>> {
+		Self::get_metaverse_stake_per_round(&metaverse_id, current_round).or_else(|| {
+			Self::get_metaverse_stake_per_round(&metaverse_id, current_round.saturating_sub(1)).map(|previous| {
+				MetaverseStakingPoints {
+					total: previous.total,
+					stakers: previous.stakers,
+					claimed_rewards: Zero::zero(),
+				}
+			})
+		})
+	}
+
+	/// Close the current round, fund its emission from mining and queue the metaverses for
+	/// settlement
+	fn start_new_round(now: T::BlockNumber, mut round: RoundInfo<T::BlockNumber>) -> Weight {
+		let finished_round = round.current;
+		let mut snapshot = Self::get_metaverse_staking_snapshots(finished_round).unwrap_or_default();
+		let emission = Self::staking_round_emission();
+
+		if !snapshot.staked.is_zero()
+			&& !emission.is_zero()
+			&& T::StakingRewardSource::fund_staking_rewards(&Self::get_staking_reward_account(), emission).is_ok()
+		{
+			snapshot.rewards = emission;
+			Self::deposit_event(Event::<T>::StakingRoundRewardsFunded(finished_round, emission));
+		}
+
+		round.update(now);
+		Round::<T>::put(round);
+
+		MetaverseStakingSnapshots::<T>::insert(
+			round.current,
+			MetaverseStakingSnapshot {
+				rewards: Zero::zero(),
+				staked: snapshot.staked,
+			},
+		);
+		MetaverseStakingSnapshots::<T>::insert(finished_round, snapshot);
+		PendingRewardSettlement::<T>::put((finished_round, 0));
+
+		Self::deposit_event(Event::<T>::NewStakingRound(round.current, now));
+
+		T::WeightInfo::new_staking_round()
 	}
 
-	/// This method will be exposed to mining trait to be executed to pay staker reward per round
+	/// Compute the reward share of up to `MaxMetaverseRewardsPerBlock` metaverses for a finished
+	/// round and carry their stakes into the following round
+	fn settle_round_rewards(round: RoundIndex, start_metaverse_id: MetaverseId) -> Weight {
+		let snapshot = Self::get_metaverse_staking_snapshots(round).unwrap_or_default();
+		let next_metaverse_id = Self::next_metaverse_id();
+		let max_settlements = T::MaxMetaverseRewardsPerBlock::get() as MetaverseId;
+		let end_metaverse_id = next_metaverse_id.min(start_metaverse_id.saturating_add(max_settlements));
+
+		for metaverse_id in start_metaverse_id..end_metaverse_id {
+			let metaverse_stake = match Self::get_metaverse_stake_per_round(&metaverse_id, round) {
+				Some(metaverse_stake) if !metaverse_stake.stakers.is_empty() => metaverse_stake,
+				_ => continue,
+			};
+
+			let reward = Perbill::from_rational(metaverse_stake.total, snapshot.staked) * snapshot.rewards;
+			if !snapshot.staked.is_zero() && !reward.is_zero() {
+				MetaverseStakingRewards::<T>::insert(metaverse_id, round, reward);
+				Self::deposit_event(Event::<T>::MetaverseStakingRewardScheduled(metaverse_id, round, reward));
+			}
+
+			let next_round = round.saturating_add(1);
+			if !MetaverseRoundStake::<T>::contains_key(metaverse_id, next_round) {
+				MetaverseRoundStake::<T>::insert(
+					metaverse_id,
+					next_round,
+					MetaverseStakingPoints {
+						total: metaverse_stake.total,
+						stakers: metaverse_stake.stakers,
+						claimed_rewards: Zero::zero(),
+					},
+				);
+			}
+		}
+
+		if end_metaverse_id >= next_metaverse_id {
+			PendingRewardSettlement::<T>::kill();
+		} else {
+			PendingRewardSettlement::<T>::put((round, end_metaverse_id));
+		}
+
+		let settled = end_metaverse_id.saturating_sub(start_metaverse_id);
+		T::WeightInfo::settle_round_rewards(settled as u32)
+	}
+
+	/// Pay the staking reward of a metaverse for a finished round to its stakers from the reward
+	/// pot, returning the number of stakers paid
 	fn pay_staker(
 		metaverse_id: MetaverseId,
 		round: RoundIndex,
 		total_reward: BalanceOf<T>,
-	) -> DispatchResultWithPostInfo {
+	) -> Result<u32, DispatchError> {
 		// Get staking info of metaverse and current round
 		let mut metaverse_stake_per_round: MetaverseStakingPoints<T::AccountId, BalanceOf<T>> =
 			Self::get_metaverse_stake_per_round(&metaverse_id, round)
@@ -622,13 +1364,19 @@ impl<T: Config> Pallet<T> {
 				staking_reward,
 			));
 
-			T::MultiCurrency::deposit(FungibleTokenId::MiningResource(0), staker, staking_reward);
+			T::MultiCurrency::transfer(
+				FungibleTokenId::MiningResource(0),
+				&Self::get_staking_reward_account(),
+				staker,
+				staking_reward,
+			)?;
 		}
 
+		let paid_stakers = metaverse_stake_per_round.stakers.len() as u32;
 		metaverse_stake_per_round.claimed_rewards = total_reward;
 		<MetaverseRoundStake<T>>::insert(&metaverse_id, round, metaverse_stake_per_round);
 
-		Ok(().into())
+		Ok(paid_stakers)
 	}
 }
 
@@ -637,6 +1385,12 @@ impl<T: Config> MetaverseTrait<T::AccountId> for Pallet<T> {
 		Self::get_metaverse_owner(who, metaverse_id) == Some(())
 	}
 
+	fn has_role(who: &T::AccountId, metaverse_id: &MetaverseId, role: MetaverseRole) -> bool {
+		Self::check_ownership(who, metaverse_id)
+			|| MetaverseRoles::<T>::contains_key(metaverse_id, (who, role))
+			|| MetaverseRoles::<T>::contains_key(metaverse_id, (who, MetaverseRole::Admin))
+	}
+
 	fn get_metaverse(metaverse_id: MetaverseId) -> Option<MetaverseInfo<T::AccountId>> {
 		Self::get_metaverse(metaverse_id)
 	}
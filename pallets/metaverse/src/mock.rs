@@ -1,11 +1,11 @@
 #![cfg(test)]
 
-use frame_support::traits::Nothing;
+use frame_support::traits::{Hooks, Nothing};
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
-use sp_runtime::{testing::Header, traits::IdentityLookup, Perbill};
+use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchResult, Perbill};
 
 use primitives::staking::RoundInfo;
 use primitives::Amount;
@@ -80,9 +80,23 @@ impl pallet_balances::Config for Runtime {
 parameter_types! {
 	pub const MetaverseFundPalletId: PalletId = PalletId(*b"bit/fund");
 	pub const MaxTokenMetadata: u32 = 1024;
+	pub const MaxMetadataHistory: u32 = 2;
+	pub const MetaverseTransferExpiry: BlockNumber = 10;
 	pub const MinContribution: Balance = 1;
 	pub const MinStakingAmount: Balance = 100;
 	pub const MaxNumberOfStakersPerMetaverse: u32 = 1;
+	pub const MaxMetaverseRewardsPerBlock: u32 = 1;
+	pub const UnbondingRounds: u32 = 2;
+	pub const MaxUnbondingChunks: u32 = 2;
+	pub const MaxRolesPerMetaverse: u32 = 2;
+}
+
+pub struct MockStakingRewardSource;
+
+impl MiningResourceAllocation<AccountId, Balance> for MockStakingRewardSource {
+	fn fund_staking_rewards(recipient: &AccountId, amount: Balance) -> DispatchResult {
+		Currencies::deposit(FungibleTokenId::MiningResource(0), recipient, amount)
+	}
 }
 
 ord_parameter_types! {
@@ -96,11 +110,18 @@ impl Config for Runtime {
 	type MultiCurrency = Currencies;
 	type MetaverseTreasury = MetaverseFundPalletId;
 	type MaxMetaverseMetadata = MaxTokenMetadata;
+	type MaxMetadataHistory = MaxMetadataHistory;
+	type MetaverseTransferExpiry = MetaverseTransferExpiry;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureSignedBy<One, AccountId>;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinStakingAmount;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
+	type StakingRewardSource = MockStakingRewardSource;
+	type MaxMetaverseRewardsPerBlock = MaxMetaverseRewardsPerBlock;
+	type UnbondingRounds = UnbondingRounds;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
+	type MaxRolesPerMetaverse = MaxRolesPerMetaverse;
 	type WeightInfo = ();
 }
 
@@ -198,3 +219,11 @@ pub fn last_event() -> Event {
 		.expect("Event expected")
 		.event
 }
+
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		Metaverse::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		Metaverse::on_initialize(System::block_number());
+	}
+}
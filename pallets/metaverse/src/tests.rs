@@ -18,7 +18,7 @@
 #![cfg(test)]
 
 use frame_support::{assert_err, assert_noop, assert_ok};
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256};
 
 use super::*;
 use mock::{Event, *};
@@ -65,7 +65,7 @@ fn transfer_metaverse_should_work() {
 		assert_eq!(last_event(), event);
 		// Make sure 2 ways transfer works
 		assert_ok!(MetaverseModule::transfer_metaverse(
-			Origin::signed(BOB),
+			Origin::signed(ALICE),
 			ALICE,
 			METAVERSE_ID
 		));
@@ -78,9 +78,19 @@ fn transfer_metaverse_should_work() {
 fn transfer_metaverse_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		// Owners can only transfer through a proposal the recipient accepts
 		assert_noop!(
-			MetaverseModule::transfer_metaverse(Origin::signed(BOB), ALICE, METAVERSE_ID),
-			Error::<Runtime>::NoPermission
+			MetaverseModule::transfer_metaverse(Origin::signed(BOB), BOB, METAVERSE_ID),
+			BadOrigin
+		);
+		assert_noop!(
+			MetaverseModule::transfer_metaverse(Origin::signed(ALICE), BOB, COUNTRY_ID_NOT_EXIST),
+			Error::<Runtime>::MetaverseInfoNotFound
+		);
+		assert_ok!(MetaverseModule::freeze_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_noop!(
+			MetaverseModule::transfer_metaverse(Origin::signed(ALICE), BOB, METAVERSE_ID),
+			Error::<Runtime>::MetaverseIsFrozen
 		);
 	})
 }
@@ -132,6 +142,40 @@ fn destroy_metaverse_should_work() {
 	})
 }
 
+#[test]
+fn get_metaverses_paginated_should_skip_destroyed_metaverse() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![2]));
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), BOB, vec![3]));
+		assert_ok!(MetaverseModule::freeze_metaverse(Origin::signed(ALICE), 1));
+		assert_ok!(MetaverseModule::destroy_metaverse(Origin::signed(ALICE), 1));
+
+		let metaverses = MetaverseModule::get_metaverses_paginated(0, 10);
+		assert_eq!(
+			metaverses
+				.iter()
+				.map(|(metaverse_id, _)| *metaverse_id)
+				.collect::<Vec<_>>(),
+			vec![0, 2]
+		);
+		assert_eq!(metaverses[1].1.owner, BOB);
+
+		// Only `limit` ids are scanned, destroyed metaverses leave gaps in a page
+		assert!(MetaverseModule::get_metaverses_paginated(1, 1).is_empty());
+		let metaverses = MetaverseModule::get_metaverses_paginated(1, 2);
+		assert_eq!(
+			metaverses
+				.iter()
+				.map(|(metaverse_id, _)| *metaverse_id)
+				.collect::<Vec<_>>(),
+			vec![2]
+		);
+		assert!(MetaverseModule::get_metaverses_paginated(3, 10).is_empty());
+		assert!(MetaverseModule::get_metaverses_paginated(MetaverseId::MAX, 10).is_empty());
+	})
+}
+
 #[test]
 fn destroy_metaverse_without_root_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -269,6 +313,21 @@ fn stake_should_work() {
 	})
 }
 
+#[test]
+fn get_metaverse_staking_info_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_eq!(MetaverseModule::get_metaverse_staking_info(METAVERSE_ID), (None, 0));
+
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 100000));
+		assert_eq!(
+			MetaverseModule::get_metaverse_staking_info(METAVERSE_ID),
+			(Some(ALICE), 100000)
+		);
+	})
+}
+
 #[test]
 fn stake_should_work_with_min_value() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -346,3 +405,759 @@ fn unstake_should_work() {
 		assert_eq!(*(metaverse_stake_per_round.stakers.entry(ALICE).or_default()), 9900u64);
 	})
 }
+
+#[test]
+fn set_staking_round_emission_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MetaverseModule::set_staking_round_emission(Origin::signed(BOB), 1000),
+			BadOrigin
+		);
+
+		assert_ok!(MetaverseModule::set_staking_round_emission(Origin::signed(ALICE), 1000));
+		assert_eq!(MetaverseModule::staking_round_emission(), 1000);
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::StakingRoundEmissionUpdated(1000))
+		);
+	})
+}
+
+#[test]
+fn staking_round_should_settle_rewards_per_metaverse() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![2]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), 1));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 30000));
+		assert_ok!(MetaverseModule::stake(Origin::signed(BOB), 1, 10000));
+		assert_ok!(MetaverseModule::set_staking_round_emission(Origin::signed(ALICE), 1000));
+
+		run_to_block(21);
+		assert_eq!(MetaverseModule::staking_round().current, 2);
+		assert_eq!(
+			Currencies::free_balance(
+				FungibleTokenId::MiningResource(0),
+				&MetaverseModule::get_staking_reward_account()
+			),
+			1000
+		);
+		assert_eq!(MetaverseModule::pending_reward_settlement(), Some((1, 0)));
+
+		// One metaverse is settled per block
+		run_to_block(22);
+		assert_eq!(
+			MetaverseModule::get_metaverse_staking_reward(METAVERSE_ID, 1),
+			Some(750)
+		);
+		assert_eq!(MetaverseModule::get_metaverse_staking_reward(1, 1), None);
+		assert_eq!(MetaverseModule::pending_reward_settlement(), Some((1, 1)));
+
+		run_to_block(23);
+		assert_eq!(MetaverseModule::get_metaverse_staking_reward(1, 1), Some(250));
+		assert_eq!(MetaverseModule::pending_reward_settlement(), None);
+
+		// Stakes are carried into the new round
+		let metaverse_stake_per_round: MetaverseStakingPoints<AccountId, Balance> =
+			MetaverseModule::get_metaverse_stake_per_round(METAVERSE_ID, 2).unwrap();
+		assert_eq!(metaverse_stake_per_round.stakers.get(&ALICE), Some(&30000));
+		assert_eq!(metaverse_stake_per_round.total, 30000);
+		assert_eq!(
+			MetaverseModule::get_metaverse_staking_snapshots(2).unwrap().staked,
+			40000
+		);
+	})
+}
+
+#[test]
+fn claim_staking_reward_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(BOB), METAVERSE_ID, 10000));
+		assert_ok!(MetaverseModule::set_staking_round_emission(Origin::signed(ALICE), 1000));
+
+		assert_noop!(
+			MetaverseModule::claim_staking_reward(Origin::signed(ALICE), METAVERSE_ID, 1),
+			Error::<Runtime>::NoStakingRewardToClaim
+		);
+
+		run_to_block(22);
+		run_to_block(42);
+		assert_eq!(MetaverseModule::staking_round().current, 3);
+
+		// Past rounds can be claimed lazily by anyone
+		assert_ok!(MetaverseModule::claim_staking_reward(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			1
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseStakingRewarded(BOB, METAVERSE_ID, 1, 1000))
+		);
+		assert_eq!(Currencies::free_balance(FungibleTokenId::MiningResource(0), &BOB), 1000);
+		assert_noop!(
+			MetaverseModule::claim_staking_reward(Origin::signed(ALICE), METAVERSE_ID, 1),
+			Error::<Runtime>::NoStakingRewardToClaim
+		);
+
+		run_to_block(43);
+		assert_ok!(MetaverseModule::claim_staking_reward(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			2
+		));
+		assert_eq!(Currencies::free_balance(FungibleTokenId::MiningResource(0), &BOB), 2000);
+	})
+}
+
+#[test]
+fn unstake_should_queue_unbonding_chunk() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 10000));
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			100
+		));
+
+		assert_eq!(
+			MetaverseModule::unbonding(ALICE).into_inner(),
+			vec![UnlockChunk {
+				value: 100,
+				unlock_round: 3
+			}]
+		);
+		assert_eq!(MetaverseModule::total_unbonding(&ALICE), 100);
+		assert_eq!(Balances::locks(ALICE)[0].amount, 10000);
+
+		assert_noop!(
+			MetaverseModule::withdraw_unbonded(Origin::signed(ALICE)),
+			Error::<Runtime>::NothingToWithdraw
+		);
+	})
+}
+
+#[test]
+fn withdraw_unbonded_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 10000));
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			100
+		));
+
+		run_to_block(41);
+		assert_eq!(MetaverseModule::staking_round().current, 3);
+
+		assert_ok!(MetaverseModule::withdraw_unbonded(Origin::signed(ALICE)));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::UnbondedWithdrawn(ALICE, 100))
+		);
+		assert_eq!(MetaverseModule::total_unbonding(&ALICE), 0);
+		assert_eq!(MetaverseModule::staking_info(ALICE), 9900);
+		assert_eq!(Balances::locks(ALICE)[0].amount, 9900);
+	})
+}
+
+#[test]
+fn unstake_should_fail_when_unbonding_chunks_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 10000));
+
+		// Chunks unlocking in the same round are merged
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			100
+		));
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			100
+		));
+		assert_eq!(MetaverseModule::unbonding(ALICE).len(), 1);
+
+		run_to_block(21);
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			100
+		));
+
+		run_to_block(41);
+		assert_noop!(
+			MetaverseModule::unstake_and_withdraw(Origin::signed(ALICE), METAVERSE_ID, 100),
+			Error::<Runtime>::MaxUnbondingChunksExceeded
+		);
+
+		assert_ok!(MetaverseModule::withdraw_unbonded(Origin::signed(ALICE)));
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			100
+		));
+	})
+}
+
+#[test]
+fn rebond_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 10000));
+
+		assert_noop!(
+			MetaverseModule::rebond(Origin::signed(ALICE), METAVERSE_ID, 100),
+			Error::<Runtime>::NothingToRebond
+		);
+
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			300
+		));
+		run_to_block(21);
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			200
+		));
+
+		// Most recently unstaked chunk is rebonded first
+		assert_ok!(MetaverseModule::rebond(Origin::signed(ALICE), METAVERSE_ID, 250));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseRebonded(ALICE, METAVERSE_ID, 250))
+		);
+		assert_eq!(
+			MetaverseModule::unbonding(ALICE).into_inner(),
+			vec![UnlockChunk {
+				value: 250,
+				unlock_round: 3
+			}]
+		);
+		assert_eq!(MetaverseModule::staking_info(ALICE), 9750);
+		assert_eq!(Balances::locks(ALICE)[0].amount, 10000);
+
+		let current_staking_round: RoundInfo<BlockNumber> = MetaverseModule::staking_round();
+		let metaverse_stake_per_round: MetaverseStakingPoints<AccountId, Balance> =
+			MetaverseModule::get_metaverse_stake_per_round(METAVERSE_ID, current_staking_round.current).unwrap();
+		assert_eq!(metaverse_stake_per_round.stakers.get(&ALICE), Some(&9750));
+		assert_eq!(metaverse_stake_per_round.total, 9750);
+	})
+}
+
+#[test]
+fn stake_should_not_use_unbonding_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(BOB), METAVERSE_ID, 19900));
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			10000
+		));
+
+		assert_noop!(
+			MetaverseModule::stake(Origin::signed(BOB), METAVERSE_ID, 10000),
+			Error::<Runtime>::NotEnoughBalanceToStake
+		);
+	})
+}
+
+#[test]
+fn grant_metaverse_role_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+
+		assert!(MetaverseModule::has_role(
+			&ALICE,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+		assert!(!MetaverseModule::has_role(
+			&BOB,
+			&METAVERSE_ID,
+			MetaverseRole::LandManager
+		));
+
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::LandManager
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseRoleGranted(
+				METAVERSE_ID,
+				BOB,
+				MetaverseRole::LandManager
+			))
+		);
+		assert!(MetaverseModule::has_role(
+			&BOB,
+			&METAVERSE_ID,
+			MetaverseRole::LandManager
+		));
+		assert!(!MetaverseModule::has_role(
+			&BOB,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+		assert!(!MetaverseModule::check_ownership(&BOB, &METAVERSE_ID));
+
+		assert_noop!(
+			MetaverseModule::grant_metaverse_role(Origin::signed(ALICE), METAVERSE_ID, BOB, MetaverseRole::LandManager),
+			Error::<Runtime>::RoleAlreadyGranted
+		);
+		assert_noop!(
+			MetaverseModule::grant_metaverse_role(
+				Origin::signed(ALICE),
+				COUNTRY_ID_NOT_EXIST,
+				BOB,
+				MetaverseRole::LandManager
+			),
+			Error::<Runtime>::MetaverseInfoNotFound
+		);
+		// Role holders that are not admins cannot delegate
+		assert_noop!(
+			MetaverseModule::grant_metaverse_role(
+				Origin::signed(BOB),
+				METAVERSE_ID,
+				FREEDY,
+				MetaverseRole::LandManager
+			),
+			Error::<Runtime>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn metaverse_admin_should_delegate_roles_except_admin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::Admin
+		));
+
+		// Admins hold every role
+		assert!(MetaverseModule::has_role(
+			&BOB,
+			&METAVERSE_ID,
+			MetaverseRole::LandManager
+		));
+
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			FREEDY,
+			MetaverseRole::Treasurer
+		));
+		assert!(MetaverseModule::has_role(
+			&FREEDY,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+
+		assert_noop!(
+			MetaverseModule::grant_metaverse_role(Origin::signed(BOB), METAVERSE_ID, FREEDY, MetaverseRole::Admin),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			MetaverseModule::revoke_metaverse_role(Origin::signed(FREEDY), METAVERSE_ID, BOB, MetaverseRole::Admin),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(MetaverseModule::revoke_metaverse_role(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			FREEDY,
+			MetaverseRole::Treasurer
+		));
+		assert!(!MetaverseModule::has_role(
+			&FREEDY,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+	})
+}
+
+#[test]
+fn revoke_metaverse_role_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::LandManager
+		));
+
+		// Role holder can renounce its own role
+		assert_ok!(MetaverseModule::revoke_metaverse_role(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::LandManager
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseRoleRevoked(
+				METAVERSE_ID,
+				BOB,
+				MetaverseRole::LandManager
+			))
+		);
+		assert!(!MetaverseModule::has_role(
+			&BOB,
+			&METAVERSE_ID,
+			MetaverseRole::LandManager
+		));
+
+		assert_noop!(
+			MetaverseModule::revoke_metaverse_role(
+				Origin::signed(ALICE),
+				METAVERSE_ID,
+				BOB,
+				MetaverseRole::LandManager
+			),
+			Error::<Runtime>::RoleNotFound
+		);
+	})
+}
+
+#[test]
+fn transfer_metaverse_should_clear_roles() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			FREEDY,
+			MetaverseRole::Admin
+		));
+
+		assert_ok!(MetaverseModule::transfer_metaverse(
+			Origin::signed(ALICE),
+			BOB,
+			METAVERSE_ID
+		));
+
+		assert!(!MetaverseModule::has_role(&FREEDY, &METAVERSE_ID, MetaverseRole::Admin));
+		assert!(!MetaverseModule::has_role(&ALICE, &METAVERSE_ID, MetaverseRole::Admin));
+		assert!(MetaverseModule::has_role(&BOB, &METAVERSE_ID, MetaverseRole::Admin));
+		assert_eq!(MetaverseModule::get_metaverse_role_count(METAVERSE_ID), 0);
+	})
+}
+
+#[test]
+fn grant_metaverse_role_should_respect_role_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::LandManager
+		));
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::Treasurer
+		));
+		assert_eq!(MetaverseModule::get_metaverse_role_count(METAVERSE_ID), 2);
+
+		assert_noop!(
+			MetaverseModule::grant_metaverse_role(
+				Origin::signed(ALICE),
+				METAVERSE_ID,
+				FREEDY,
+				MetaverseRole::LandManager
+			),
+			Error::<Runtime>::TooManyRoles
+		);
+
+		assert_ok!(MetaverseModule::revoke_metaverse_role(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::Treasurer
+		));
+		assert_eq!(MetaverseModule::get_metaverse_role_count(METAVERSE_ID), 1);
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			FREEDY,
+			MetaverseRole::LandManager
+		));
+	})
+}
+
+fn test_profile() -> MetaverseProfile {
+	MetaverseProfile {
+		name: b"Bit Country".to_vec(),
+		description_uri: b"ipfs://description".to_vec(),
+		logo_cid: b"logo".to_vec(),
+		website: b"https://bit.country".to_vec(),
+	}
+}
+
+#[test]
+fn update_metaverse_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		let created_versions = MetaverseModule::get_metaverse_metadata_versions(METAVERSE_ID);
+		assert_eq!(created_versions.len(), 1);
+		assert_eq!(created_versions[0].version, 0);
+
+		System::set_block_number(5);
+		assert_ok!(MetaverseModule::update_metaverse_metadata(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			vec![2],
+			test_profile()
+		));
+
+		let hash = BlakeTwo256::hash_of(&(vec![2u8], test_profile()));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseMetadataUpdated(METAVERSE_ID, 1, hash))
+		);
+		assert_eq!(
+			MetaverseModule::get_metaverse_metadata(METAVERSE_ID),
+			Some((vec![2], test_profile()))
+		);
+		assert_eq!(
+			MetaverseModule::get_metaverse_metadata_versions(METAVERSE_ID),
+			vec![
+				created_versions[0].clone(),
+				MetaverseMetadataVersion {
+					version: 1,
+					hash,
+					updated_at: 5
+				}
+			]
+		);
+	})
+}
+
+#[test]
+fn update_metaverse_metadata_should_keep_bounded_history() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::Admin
+		));
+
+		assert_ok!(MetaverseModule::update_metaverse_metadata(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			vec![2],
+			test_profile()
+		));
+		assert_ok!(MetaverseModule::update_metaverse_metadata(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			vec![3],
+			test_profile()
+		));
+
+		let versions: Vec<u32> = MetaverseModule::get_metaverse_metadata_versions(METAVERSE_ID)
+			.iter()
+			.map(|record| record.version)
+			.collect();
+		assert_eq!(versions, vec![1, 2]);
+	})
+}
+
+#[test]
+fn update_metaverse_metadata_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MetaverseModule::update_metaverse_metadata(Origin::signed(ALICE), METAVERSE_ID, vec![2], test_profile()),
+			Error::<Runtime>::MetaverseInfoNotFound
+		);
+
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			BOB,
+			MetaverseRole::LandManager
+		));
+
+		assert_noop!(
+			MetaverseModule::update_metaverse_metadata(Origin::signed(BOB), METAVERSE_ID, vec![2], test_profile()),
+			Error::<Runtime>::NoPermission
+		);
+
+		let mut oversized_profile = test_profile();
+		oversized_profile.website = vec![1; 1025];
+		assert_noop!(
+			MetaverseModule::update_metaverse_metadata(Origin::signed(ALICE), METAVERSE_ID, vec![2], oversized_profile),
+			Error::<Runtime>::MaxMetadataExceeded
+		);
+	})
+}
+
+#[test]
+fn two_step_metaverse_transfer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_eq!(Balances::reserved_balance(ALICE), 1);
+
+		assert_ok!(MetaverseModule::propose_metaverse_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			METAVERSE_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseTransferProposed(METAVERSE_ID, ALICE, BOB, 11))
+		);
+		// Ownership does not move until the recipient accepts
+		assert!(MetaverseModule::check_ownership(&ALICE, &METAVERSE_ID));
+
+		assert_ok!(MetaverseModule::accept_metaverse_transfer(
+			Origin::signed(BOB),
+			METAVERSE_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::TransferredMetaverse(METAVERSE_ID, ALICE, BOB))
+		);
+		assert!(MetaverseModule::check_ownership(&BOB, &METAVERSE_ID));
+		assert!(!MetaverseModule::check_ownership(&ALICE, &METAVERSE_ID));
+		assert_eq!(MetaverseModule::get_metaverse(METAVERSE_ID).unwrap().owner, BOB);
+		assert_eq!(MetaverseModule::get_pending_metaverse_transfer(METAVERSE_ID), None);
+
+		// Staking registration and its deposit move with the metaverse
+		assert_eq!(MetaverseModule::get_registered_metaverse(METAVERSE_ID), Some(BOB));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::reserved_balance(BOB), 1);
+	})
+}
+
+#[test]
+fn propose_metaverse_transfer_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+
+		assert_noop!(
+			MetaverseModule::propose_metaverse_transfer(Origin::signed(BOB), BOB, METAVERSE_ID),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			MetaverseModule::propose_metaverse_transfer(Origin::signed(ALICE), ALICE, METAVERSE_ID),
+			Error::<Runtime>::CannotTransferToSelf
+		);
+
+		assert_ok!(MetaverseModule::freeze_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_noop!(
+			MetaverseModule::propose_metaverse_transfer(Origin::signed(ALICE), BOB, METAVERSE_ID),
+			Error::<Runtime>::MetaverseIsFrozen
+		);
+	})
+}
+
+#[test]
+fn accept_metaverse_transfer_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+
+		assert_noop!(
+			MetaverseModule::accept_metaverse_transfer(Origin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::NoPendingMetaverseTransfer
+		);
+
+		// Recipient has no balance to take over the staking registration deposit
+		assert_ok!(MetaverseModule::propose_metaverse_transfer(
+			Origin::signed(ALICE),
+			FREEDY,
+			METAVERSE_ID
+		));
+		assert_noop!(
+			MetaverseModule::accept_metaverse_transfer(Origin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			MetaverseModule::accept_metaverse_transfer(Origin::signed(FREEDY), METAVERSE_ID),
+			Error::<Runtime>::RecipientCannotCoverStakingDeposit
+		);
+
+		assert_ok!(MetaverseModule::propose_metaverse_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			METAVERSE_ID
+		));
+		System::set_block_number(12);
+		assert_noop!(
+			MetaverseModule::accept_metaverse_transfer(Origin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::MetaverseTransferExpired
+		);
+	})
+}
+
+#[test]
+fn cancel_metaverse_transfer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), ALICE, vec![1]));
+		assert_ok!(MetaverseModule::propose_metaverse_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			METAVERSE_ID
+		));
+
+		assert_noop!(
+			MetaverseModule::cancel_metaverse_transfer(Origin::signed(FREEDY), METAVERSE_ID),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(MetaverseModule::cancel_metaverse_transfer(
+			Origin::signed(ALICE),
+			METAVERSE_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseTransferCancelled(METAVERSE_ID))
+		);
+		assert_noop!(
+			MetaverseModule::accept_metaverse_transfer(Origin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::NoPendingMetaverseTransfer
+		);
+
+		// Anyone can clear an expired proposal
+		assert_ok!(MetaverseModule::propose_metaverse_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			METAVERSE_ID
+		));
+		System::set_block_number(12);
+		assert_ok!(MetaverseModule::cancel_metaverse_transfer(
+			Origin::signed(FREEDY),
+			METAVERSE_ID
+		));
+	})
+}
@@ -45,13 +45,25 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for metaverse.
 pub trait WeightInfo {
     fn create_metaverse() -> Weight;
-    fn transfer_metaverse() -> Weight;
+    fn transfer_metaverse(r: u32) -> Weight;
     fn freeze_metaverse() -> Weight;
     fn unfreeze_metaverse() -> Weight;
     fn destroy_metaverse() -> Weight;
     fn register_metaverse() -> Weight;
     fn stake() -> Weight;
     fn unstake_and_withdraw() -> Weight;
+    fn update_metaverse_metadata(m: u32) -> Weight;
+    fn set_staking_round_emission() -> Weight;
+    fn claim_staking_reward(s: u32) -> Weight;
+    fn new_staking_round() -> Weight;
+    fn settle_round_rewards(m: u32) -> Weight;
+    fn withdraw_unbonded(c: u32) -> Weight;
+    fn rebond(c: u32) -> Weight;
+    fn grant_metaverse_role() -> Weight;
+    fn revoke_metaverse_role() -> Weight;
+    fn propose_metaverse_transfer() -> Weight;
+    fn accept_metaverse_transfer(r: u32) -> Weight;
+    fn cancel_metaverse_transfer() -> Weight;
 }
 
 /// Weights for metaverse using the for collator node and recommended hardware.
@@ -61,8 +73,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn create_metaverse() -> Weight {
         (38_000_000 as Weight).saturating_add(T::DbWeight::get().reads(4 as Weight)).saturating_add(T::DbWeight::get().writes(6 as Weight))
     }
-    fn transfer_metaverse() -> Weight {
-        (15_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    fn transfer_metaverse(r: u32) -> Weight {
+        (38_000_000 as Weight).saturating_add((1_200_000 as Weight).saturating_mul(r as Weight)).saturating_add(T::DbWeight::get().reads(5 as Weight)).saturating_add(T::DbWeight::get().writes(8 as Weight)).saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
     }
     fn freeze_metaverse() -> Weight {
         (11_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
@@ -82,6 +94,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn unstake_and_withdraw() -> Weight {
         (34_000_000 as Weight).saturating_add(T::DbWeight::get().reads(7 as Weight)).saturating_add(T::DbWeight::get().writes(5 as Weight))
     }
+    fn update_metaverse_metadata(m: u32) -> Weight {
+        (24_000_000 as Weight).saturating_add((5_000 as Weight).saturating_mul(m as Weight)).saturating_add(T::DbWeight::get().reads(6 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn set_staking_round_emission() -> Weight {
+        (12_000_000 as Weight).saturating_add(T::DbWeight::get().reads(0 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn claim_staking_reward(s: u32) -> Weight {
+        (30_000_000 as Weight).saturating_add((25_000_000 as Weight).saturating_mul(s as Weight)).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight))).saturating_add(T::DbWeight::get().writes(1 as Weight)).saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+    }
+    fn new_staking_round() -> Weight {
+        (45_000_000 as Weight).saturating_add(T::DbWeight::get().reads(8 as Weight)).saturating_add(T::DbWeight::get().writes(9 as Weight))
+    }
+    fn settle_round_rewards(m: u32) -> Weight {
+        (10_000_000 as Weight).saturating_add((20_000_000 as Weight).saturating_mul(m as Weight)).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(m as Weight))).saturating_add(T::DbWeight::get().writes(1 as Weight)).saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(m as Weight)))
+    }
+    fn withdraw_unbonded(c: u32) -> Weight {
+        (25_000_000 as Weight).saturating_add((300_000 as Weight).saturating_mul(c as Weight)).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn rebond(c: u32) -> Weight {
+        (42_000_000 as Weight).saturating_add((300_000 as Weight).saturating_mul(c as Weight)).saturating_add(T::DbWeight::get().reads(8 as Weight)).saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn grant_metaverse_role() -> Weight {
+        (16_000_000 as Weight).saturating_add(T::DbWeight::get().reads(4 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn revoke_metaverse_role() -> Weight {
+        (15_000_000 as Weight).saturating_add(T::DbWeight::get().reads(4 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn propose_metaverse_transfer() -> Weight {
+        (14_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn accept_metaverse_transfer(r: u32) -> Weight {
+        (40_000_000 as Weight).saturating_add((1_200_000 as Weight).saturating_mul(r as Weight)).saturating_add(T::DbWeight::get().reads(6 as Weight)).saturating_add(T::DbWeight::get().writes(8 as Weight)).saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+    fn cancel_metaverse_transfer() -> Weight {
+        (12_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
 
 // For backwards compatibility and tests
@@ -89,8 +137,8 @@ impl WeightInfo for () {
     fn create_metaverse() -> Weight {
         (38_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(4 as Weight)).saturating_add(RocksDbWeight::get().writes(6 as Weight))
     }
-    fn transfer_metaverse() -> Weight {
-        (15_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(2 as Weight)).saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    fn transfer_metaverse(r: u32) -> Weight {
+        (38_000_000 as Weight).saturating_add((1_200_000 as Weight).saturating_mul(r as Weight)).saturating_add(RocksDbWeight::get().reads(5 as Weight)).saturating_add(RocksDbWeight::get().writes(8 as Weight)).saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
     }
     fn freeze_metaverse() -> Weight {
         (11_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(1 as Weight)).saturating_add(RocksDbWeight::get().writes(1 as Weight))
@@ -110,4 +158,40 @@ impl WeightInfo for () {
     fn unstake_and_withdraw() -> Weight {
         (34_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(7 as Weight)).saturating_add(RocksDbWeight::get().writes(5 as Weight))
     }
+    fn update_metaverse_metadata(m: u32) -> Weight {
+        (24_000_000 as Weight).saturating_add((5_000 as Weight).saturating_mul(m as Weight)).saturating_add(RocksDbWeight::get().reads(6 as Weight)).saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn set_staking_round_emission() -> Weight {
+        (12_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(0 as Weight)).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn claim_staking_reward(s: u32) -> Weight {
+        (30_000_000 as Weight).saturating_add((25_000_000 as Weight).saturating_mul(s as Weight)).saturating_add(RocksDbWeight::get().reads(3 as Weight)).saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight))).saturating_add(RocksDbWeight::get().writes(1 as Weight)).saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+    }
+    fn new_staking_round() -> Weight {
+        (45_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(8 as Weight)).saturating_add(RocksDbWeight::get().writes(9 as Weight))
+    }
+    fn settle_round_rewards(m: u32) -> Weight {
+        (10_000_000 as Weight).saturating_add((20_000_000 as Weight).saturating_mul(m as Weight)).saturating_add(RocksDbWeight::get().reads(3 as Weight)).saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(m as Weight))).saturating_add(RocksDbWeight::get().writes(1 as Weight)).saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(m as Weight)))
+    }
+    fn withdraw_unbonded(c: u32) -> Weight {
+        (25_000_000 as Weight).saturating_add((300_000 as Weight).saturating_mul(c as Weight)).saturating_add(RocksDbWeight::get().reads(3 as Weight)).saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    fn rebond(c: u32) -> Weight {
+        (42_000_000 as Weight).saturating_add((300_000 as Weight).saturating_mul(c as Weight)).saturating_add(RocksDbWeight::get().reads(8 as Weight)).saturating_add(RocksDbWeight::get().writes(6 as Weight))
+    }
+    fn grant_metaverse_role() -> Weight {
+        (16_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(4 as Weight)).saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn revoke_metaverse_role() -> Weight {
+        (15_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(4 as Weight)).saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn propose_metaverse_transfer() -> Weight {
+        (14_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(1 as Weight)).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn accept_metaverse_transfer(r: u32) -> Weight {
+        (40_000_000 as Weight).saturating_add((1_200_000 as Weight).saturating_mul(r as Weight)).saturating_add(RocksDbWeight::get().reads(6 as Weight)).saturating_add(RocksDbWeight::get().writes(8 as Weight)).saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+    fn cancel_metaverse_transfer() -> Weight {
+        (12_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(1 as Weight)).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
 }
@@ -125,6 +125,8 @@ pub mod pallet {
 		/// Remove mining origin [who]
 		/// Add new mining origins [who]
 		RemoveMiningOrigin(T::AccountId),
+		/// Mining resource allocated to staking rewards [recipient, amount]
+		StakingRewardsFunded(T::AccountId, Balance),
 	}
 
 	#[pallet::error]
@@ -318,3 +320,28 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 }
+
+impl<T: Config> MiningResourceAllocation<T::AccountId, Balance> for Pallet<T> {
+	fn fund_staking_rewards(recipient: &T::AccountId, amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		let mining_treasury = Self::bit_mining_resource_account_id();
+		ensure!(
+			T::MiningCurrency::free_balance(Self::bit_mining_resource_currency_id(), &mining_treasury) >= amount,
+			Error::<T>::BalanceLow
+		);
+
+		T::MiningCurrency::transfer(
+			Self::bit_mining_resource_currency_id(),
+			&mining_treasury,
+			recipient,
+			amount,
+		)?;
+
+		Self::deposit_event(Event::StakingRewardsFunded(recipient.clone(), amount));
+
+		Ok(())
+	}
+}
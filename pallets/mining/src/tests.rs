@@ -157,3 +157,33 @@ fn deposit_mining_resource_should_work() {
 		assert_eq!(last_event(), event);
 	});
 }
+
+#[test]
+fn fund_staking_rewards_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(setup_minting_resource());
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), 1000));
+
+		assert_ok!(<MiningModule as MiningResourceAllocation<AccountId, Balance>>::fund_staking_rewards(&BOB, 400));
+
+		assert_eq!(get_mining_balance_of(&BOB), 400);
+		assert_eq!(
+			get_mining_balance_of(&MiningModule::bit_mining_resource_account_id()),
+			600
+		);
+		assert_eq!(
+			last_event(),
+			mock::Event::MiningModule(crate::Event::StakingRewardsFunded(BOB, 400))
+		);
+	});
+}
+
+#[test]
+fn fund_staking_rewards_should_fail_when_treasury_is_low() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			<MiningModule as MiningResourceAllocation<AccountId, Balance>>::fund_staking_rewards(&BOB, 400),
+			crate::Error::<Runtime>::BalanceLow
+		);
+	});
+}
@@ -0,0 +1,143 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the nft pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use primitives::{AssetId, GroupCollectionId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use nft_runtime_api::NftApi as NftRuntimeApi;
+
+/// Maximum number of assets returned in a single page
+const MAX_ASSETS_PER_PAGE: u32 = 100;
+
+#[rpc]
+pub trait NftApi<BlockHash, AccountId, ClassId> {
+	/// Assets owned by the account, optionally filtered by class or group collection, starting
+	/// after the `start` asset. The last asset of a page is the `start` of the next page
+	#[rpc(name = "nft_getAssetsByOwner")]
+	fn assets_by_owner(
+		&self,
+		owner: AccountId,
+		class_id: Option<ClassId>,
+		group_collection_id: Option<GroupCollectionId>,
+		start: Option<AssetId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<AssetId>>;
+
+	/// Number of assets owned by the account
+	#[rpc(name = "nft_getAssetsCountByOwner")]
+	fn assets_count_by_owner(&self, owner: AccountId, at: Option<BlockHash>) -> Result<u32>;
+
+	/// Account allowed to use the asset, if its usage rights have not expired
+	#[rpc(name = "nft_getAssetUser")]
+	fn asset_user(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<Option<AccountId>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Display) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(e.to_string().into()),
+	}
+}
+
+/// Provides RPC methods to query nft assets.
+pub struct Nft<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Nft<C, B> {
+	/// Create new `Nft` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AccountId, ClassId> NftApi<<Block as BlockT>::Hash, AccountId, ClassId> for Nft<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: NftRuntimeApi<Block, AccountId, ClassId>,
+	AccountId: Codec,
+	ClassId: Codec,
+{
+	fn assets_by_owner(
+		&self,
+		owner: AccountId,
+		class_id: Option<ClassId>,
+		group_collection_id: Option<GroupCollectionId>,
+		start: Option<AssetId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<AssetId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.assets_by_owner(
+			&at,
+			owner,
+			class_id,
+			group_collection_id,
+			start,
+			limit.min(MAX_ASSETS_PER_PAGE),
+		)
+		.map_err(|e| runtime_error("Unable to query assets by owner.", e))
+	}
+
+	fn assets_count_by_owner(&self, owner: AccountId, at: Option<<Block as BlockT>::Hash>) -> Result<u32> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.assets_count_by_owner(&at, owner)
+			.map_err(|e| runtime_error("Unable to query assets count by owner.", e))
+	}
+
+	fn asset_user(&self, asset_id: AssetId, at: Option<<Block as BlockT>::Hash>) -> Result<Option<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.asset_user(&at, asset_id)
+			.map_err(|e| runtime_error("Unable to query asset user.", e))
+	}
+}
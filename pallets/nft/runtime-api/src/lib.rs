@@ -0,0 +1,50 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the nft pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use primitives::{AssetId, GroupCollectionId};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait NftApi<AccountId, ClassId> where
+		AccountId: Codec,
+		ClassId: Codec,
+	{
+		/// Assets owned by the account in storage order, optionally filtered by class or group
+		/// collection, starting after the `start` asset and returning at most `limit` assets. The
+		/// last asset of a page is the `start` of the next page
+		fn assets_by_owner(
+			owner: AccountId,
+			class_id: Option<ClassId>,
+			group_collection_id: Option<GroupCollectionId>,
+			start: Option<AssetId>,
+			limit: u32,
+		) -> Vec<AssetId>;
+
+		/// Number of assets owned by the account
+		fn assets_count_by_owner(owner: AccountId) -> u32;
+
+		/// Account allowed to use the asset, if its usage rights have not expired
+		fn asset_user(asset_id: AssetId) -> Option<AccountId>;
+	}
+}
@@ -28,11 +28,11 @@ use crate::Call;
 use crate::Pallet as NftModule;
 pub use crate::*;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::Get;
+use frame_support::{traits::Get, BoundedVec};
 use frame_system::RawOrigin;
-use orml_traits::BasicCurrencyExtended;
+use orml_traits::{BasicCurrencyExtended, MultiCurrency};
 use primitives::Balance;
-use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto};
+use sp_runtime::traits::{AccountIdConversion, Hash as HashT, StaticLookup, UniqueSaturatedInto};
 
 pub struct Pallet<T: Config>(crate::Pallet<T>);
 
@@ -55,6 +55,56 @@ fn test_attributes(x: u8) -> Attributes {
 	attr
 }
 
+/// `i` batch items minted to distinct recipients, holding `b` bytes of metadata and attributes in total
+fn mint_batch_items<T: Config>(i: u32, b: u32) -> Vec<MintBatchItem<T::AccountId>> {
+	(0..i)
+		.map(|index| {
+			let bytes = b / i + if index == 0 { b % i } else { 0 };
+			let metadata_len = bytes / 2;
+			let mut attributes: Attributes = BTreeMap::new();
+			if bytes > metadata_len {
+				attributes.insert(vec![], vec![1u8; (bytes - metadata_len) as usize]);
+			}
+			let recipient: T::AccountId = account("recipient", index, SEED);
+			(vec![1u8; metadata_len as usize], attributes, Some(recipient))
+		})
+		.collect()
+}
+
+/// Pay royalties for asset 0 in `c` currencies so its supporters have rewards to settle in each
+fn accrue_supporter_royalties<T: Config>(payer: &T::AccountId, c: u32) -> Result<(), &'static str> {
+	for i in 0..c {
+		let currency_id = FungibleTokenId::FungibleToken(i.into());
+		<T as pallet::Config>::MultiCurrency::deposit(currency_id, payer, dollar(10).unique_saturated_into())?;
+		crate::Pallet::<T>::distribute_supporter_royalty(
+			payer,
+			&0u32.into(),
+			currency_id,
+			dollar(1).unique_saturated_into(),
+		)?;
+	}
+	Ok(())
+}
+
+/// Fund the caller with `c` currencies to fill a time capsule, the first being the native token
+fn time_capsule_contents<T: Config>(
+	caller: &T::AccountId,
+	c: u32,
+) -> Result<Vec<(FungibleTokenId, BalanceOf<T>)>, &'static str> {
+	let mut contents = Vec::new();
+	for i in 0..c {
+		let currency_id = if i == 0 {
+			FungibleTokenId::NativeToken(0)
+		} else {
+			let currency_id = FungibleTokenId::FungibleToken(i.into());
+			<T as pallet::Config>::MultiCurrency::deposit(currency_id, caller, dollar(10).unique_saturated_into())?;
+			currency_id
+		};
+		contents.push((currency_id, dollar(1).unique_saturated_into()));
+	}
+	Ok(contents)
+}
+
 benchmarks! {
 
 	// create NFT group
@@ -68,7 +118,7 @@ benchmarks! {
 		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
 
 		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
-	}: _(RawOrigin::Signed(caller), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable)
+	}: _(RawOrigin::Signed(caller), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None)
 
 	mint{
 		let caller = funded_account::<T>("caller", 0);
@@ -76,9 +126,23 @@ benchmarks! {
 
 		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
 		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
-		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
 	}: _(RawOrigin::Signed(caller), 0u32.into(), vec![1], test_attributes(1), 3 )
 
+	mint_batch{
+		let i in 1 .. T::MaxBatchMinting::get();
+		let b in 0 .. 2 * T::MaxMetadata::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
+
+		let items: BoundedVec<MintBatchItem<T::AccountId>, T::MaxBatchMinting> = mint_batch_items::<T>(i, b).try_into().unwrap();
+	}: _(RawOrigin::Signed(caller), 0u32.into(), items)
+
 	transfer{
 		let caller = funded_account::<T>("caller", 0);
 		let target = funded_account::<T>("target", 0);
@@ -86,7 +150,7 @@ benchmarks! {
 
 		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
 		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
-		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
 		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
 	}: _(RawOrigin::Signed(caller), target.clone(),  0u32.into() )
 
@@ -98,21 +162,101 @@ benchmarks! {
 
 		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
 		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
-		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
 		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
 		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
 	}: _(RawOrigin::Signed(caller), vec![(target1.clone(), 0u32.into()), (target2.clone(), 1u32.into())] )
 
 	sign_asset{
+		let c in 0 .. T::MaxSupporterRewardCurrencies::get();
+
 		let caller = funded_account::<T>("caller", 0);
 		let signer = funded_account::<T>("target", 0);
 		let initial_balance = dollar(1000);
 
 		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
 		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
-		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
 		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
-	}: _(RawOrigin::Signed(signer), 0u32.into(), 100u32.into() )
+		crate::Pallet::<T>::sign_asset(RawOrigin::Signed(signer.clone()).into(), 0u32.into(), T::MinimumSupportContribution::get())?;
+		accrue_supporter_royalties::<T>(&caller, c)?;
+	}: _(RawOrigin::Signed(signer), 0u32.into(), T::MinimumSupportContribution::get())
+
+	claim_supporter_rewards{
+		let c in 1 .. T::MaxSupporterRewardCurrencies::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let signer = funded_account::<T>("target", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
+		crate::Pallet::<T>::sign_asset(RawOrigin::Signed(signer.clone()).into(), 0u32.into(), T::MinimumSupportContribution::get())?;
+		accrue_supporter_royalties::<T>(&caller, c)?;
+	}: _(RawOrigin::Signed(signer), 0u32.into(), FungibleTokenId::FungibleToken(0))
+
+	burn{
+		let caller = funded_account::<T>("caller", 0);
+		let delegate = funded_account::<T>("delegate", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+		crate::Pallet::<T>::approve(RawOrigin::Signed(caller.clone()).into(), delegate, 0u32.into(), None)?;
+	}: _(RawOrigin::Signed(caller), 0u32.into())
+
+	create_time_capsule{
+		let c in 0 .. T::MaxBatchTransfer::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+		let contents = time_capsule_contents::<T>(&caller, c)?;
+	}: _(RawOrigin::Signed(caller), 0u32.into(), 10u32.into(), contents, T::Hashing::hash(&[1]))
+
+	open_capsule{
+		let c in 0 .. T::MaxBatchTransfer::get();
+		let m in 0 .. T::MaxMetadata::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 1);
+		let contents = time_capsule_contents::<T>(&caller, c)?;
+		let metadata = vec![1u8; m as usize];
+		crate::Pallet::<T>::create_time_capsule(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), 10u32.into(), contents, T::Hashing::hash(&metadata))?;
+		frame_system::Pallet::<T>::set_block_number(10u32.into());
+	}: _(RawOrigin::Signed(caller), 0u32.into(), metadata)
+
+	promotion_rewards{
+		let c in 1 .. T::MaxPromotionCampaigns::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, None);
+
+		let currency_id = FungibleTokenId::NativeToken(0);
+		<T as pallet::Config>::MultiCurrency::deposit(currency_id, &crate::Pallet::<T>::get_promotion_fund_account(), dollar(c).unique_saturated_into())?;
+		for _ in 0..c {
+			crate::Pallet::<T>::create_promotion_campaign(RawOrigin::Root.into(), 0u32.into(), 1000u32.into(), currency_id, dollar(1).unique_saturated_into(), dollar(1).unique_saturated_into(), dollar(1).unique_saturated_into(), vec![])?;
+		}
+	}: {
+		crate::Pallet::<T>::reward_promotion_campaigns(&caller, 0u32.into(), 1)?;
+	}
 
 }
 
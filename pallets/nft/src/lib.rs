@@ -26,16 +26,19 @@
 #![allow(clippy::upper_case_acronyms)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeLimit, Encode, EncodeLike};
 use frame_support::traits::Len;
 use frame_support::{
-	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	dispatch::{DispatchResult, DispatchResultWithPostInfo, WithPostDispatchInfo},
 	ensure,
 	pallet_prelude::*,
 	traits::{
 		schedule::{DispatchTime, Named as ScheduleNamed},
-		Currency, ExistenceRequirement, Get, LockIdentifier, ReservableCurrency,
+		Currency, ExistenceRequirement, Get, InstanceFilter, LockIdentifier, LockableCurrency, ReservableCurrency,
+		WithdrawReasons,
 	},
+	transactional,
+	weights::{GetDispatchInfo, PostDispatchInfo},
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
@@ -45,20 +48,23 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_runtime::traits::Saturating;
 use sp_runtime::RuntimeDebug;
+use sp_runtime::SaturatedConversion;
 use sp_runtime::{
-	traits::{AccountIdConversion, Dispatchable, One},
-	DispatchError,
+	traits::{AccountIdConversion, Dispatchable, Hash as HashT, IdentifyAccount, One, Verify, Zero},
+	DispatchError, FixedPointNumber, FixedU128, Perbill,
 };
+use sp_std::convert::TryInto;
 use sp_std::vec::Vec;
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
-use auction_manager::{Auction, CheckAuctionItemHandler};
+use auction_manager::{Auction, CheckAuctionItemHandler, NFTTrait};
 pub use pallet::*;
-use primitives::{AssetId, BlockNumber, GroupCollectionId, Hash};
+use primitives::{AssetId, BlockNumber, FungibleTokenId, GroupCollectionId, Hash};
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -68,8 +74,14 @@ pub mod weights;
 
 pub type NftMetadata = Vec<u8>;
 pub type Attributes = BTreeMap<Vec<u8>, Vec<u8>>;
+/// Metadata, attributes and optional recipient of an asset minted in batch
+pub type MintBatchItem<AccountId> = (NftMetadata, Attributes, Option<AccountId>);
 
 const TIMECAPSULE_ID: LockIdentifier = *b"bctimeca";
+/// Maximum depth of equipped assets below a root asset
+const MAX_NESTING_DEPTH: u32 = 5;
+/// Maximum nesting of the executable call decoded from a collection, same limit as extrinsics
+const MAX_EXECUTABLE_CALL_DEPTH: u32 = 256;
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 pub struct NftGroupCollectionData {
@@ -88,6 +100,8 @@ pub struct NftClassData<Balance> {
 	pub collection_type: CollectionType,
 	pub total_supply: u64,
 	pub initial_supply: u64,
+	/// Maximum number of tokens that can ever be minted, unlimited if none
+	pub max_supply: Option<u64>,
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -158,15 +172,133 @@ impl Default for CollectionType {
 	}
 }
 
+/// How often an asset of an executable collection can be executed
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ExecutionMode<BlockNumber> {
+	/// Asset can be executed any number of times
+	Unlimited,
+	/// Asset is burned when executed
+	SingleUse,
+	/// Asset can be executed again once the number of blocks has passed
+	Cooldown(BlockNumber),
+}
+
+impl<BlockNumber> Default for ExecutionMode<BlockNumber> {
+	fn default() -> Self {
+		ExecutionMode::Unlimited
+	}
+}
+
+/// Off-chain authorization from the class owner to mint a token to whoever redeems it
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MintVoucher<ClassId, Hash, Balance, BlockNumber> {
+	pub class_id: ClassId,
+	/// Hash of the token metadata provided at redemption
+	pub metadata_hash: Hash,
+	pub attributes: Attributes,
+	/// Price paid by the buyer to the class owner
+	pub price: Balance,
+	pub currency_id: FungibleTokenId,
+	/// Last block the voucher can be redeemed
+	pub expiry: BlockNumber,
+	/// Unique per class, a nonce can only be redeemed once
+	pub nonce: u64,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TimeCapsule<Balance, BlockNumber, Hash> {
+	/// Block the capsule can be opened from
+	pub unlock_block: BlockNumber,
+	/// Balances held by the capsule until it is opened
+	pub contents: Vec<(FungibleTokenId, Balance)>,
+	/// Hash of the sealed metadata, provided by the holder and revealed as token metadata when the
+	/// capsule is opened
+	pub metadata_hash: Hash,
+}
+
+/// Usage rights of an asset granted by its owner
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetUser<AccountId, BlockNumber> {
+	pub user: AccountId,
+	/// Block the usage rights expire at
+	pub expires: BlockNumber,
+	/// Whether the usage rights are kept when the asset changes owner
+	pub survives_transfer: bool,
+}
+
+/// Rental terms offered by the owner of an asset
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RentalOffer<Balance, BlockNumber> {
+	/// Number of blocks the renter can use the asset for
+	pub duration: BlockNumber,
+	pub currency_id: FungibleTokenId,
+	/// Rent paid by the renter to the owner
+	pub price: Balance,
+	/// Whether the usage rights are kept when the asset changes owner
+	pub survives_transfer: bool,
+}
+
+/// Contributions of supporters to the assets of a class, each released to the class owner
+/// linearly from the block it was made
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SupportFund<Balance, BlockNumber> {
+	/// Contributions still vesting and the block each vests from, oldest first
+	pub vesting: Vec<(Balance, BlockNumber)>,
+	/// Contributions fully vested
+	pub released: Balance,
+	/// Contributions withdrawn by the class owner
+	pub withdrawn: Balance,
+}
+
+/// Seed of the account holding supporter rewards until they are claimed
+pub const SUPPORTER_REWARD_ID: [u8; 8] = *b"bcsprrwd";
+
+/// Seed of the account funding promotion campaigns
+pub const PROMOTION_FUND_ID: [u8; 8] = *b"bcpromot";
+
+/// Domain tag of mint voucher signatures
+pub const VOUCHER_SIGNING_DOMAIN: &[u8] = b"bitcountry/nft-voucher";
+
+pub type PromotionCampaignId = u32;
+
+/// Governance created campaign rewarding minters for every token minted
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PromotionCampaign<Balance, BlockNumber> {
+	/// First block minted tokens are rewarded
+	pub start: BlockNumber,
+	/// Block the campaign stops rewarding at
+	pub end: BlockNumber,
+	pub currency_id: FungibleTokenId,
+	/// Reward paid for each minted token
+	pub reward_per_token: Balance,
+	/// Budget left to pay rewards, held by the campaign account
+	pub remaining_budget: Balance,
+	/// Max total reward paid to a single account
+	pub account_cap: Balance,
+	/// Group collections whose classes are rewarded, every collection if empty
+	pub eligible_collections: Vec<GroupCollectionId>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 
-	use primitives::{FungibleTokenId, ItemId};
+	use primitives::ItemId;
 
 	use super::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
 	#[pallet::config]
@@ -179,7 +311,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type DataDepositPerByte: Get<BalanceOf<Self>>;
 		/// Currency type for reserve/unreserve balance
-		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+		type Currency: Currency<Self::AccountId>
+			+ ReservableCurrency<Self::AccountId>
+			+ LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
 		//NFT Module Id
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -202,13 +336,54 @@ pub mod pallet {
 			CurrencyId = FungibleTokenId,
 			Balance = BalanceOf<Self>,
 		>;
-		/// Fungible token id for promotion incentive
+		/// Max number of promotion campaigns open at the same time
+		#[pallet::constant]
+		type MaxPromotionCampaigns: Get<u32>;
+		/// Runtime call dispatched by executable assets. Contract invocations are expressed as
+		/// calls to the contracts or EVM pallet.
+		type ExecutableCall: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo;
+		/// Filter on calls executable assets are allowed to dispatch
+		type ExecutableCallFilter: Parameter + Member + Default + InstanceFilter<Self::ExecutableCall>;
+		/// Signature of mint vouchers, sr25519 and ed25519 are supported by `MultiSignature`
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+		/// Public key of mint voucher signers
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+		/// Max number of supporters recorded per asset
+		#[pallet::constant]
+		type MaxSupporters: Get<u32>;
+		/// Blocks over which contributions vest before the class owner can withdraw them
+		#[pallet::constant]
+		type SupportVestingPeriod: Get<Self::BlockNumber>;
+		/// Max number of vesting schedules kept per class fund, later contributions are merged
+		/// into the most recent schedule
 		#[pallet::constant]
-		type MiningResourceId: Get<FungibleTokenId>;
-		/// Incentive for promotion
-		type PromotionIncentive: Get<BalanceOf<Self>>;
+		type MaxSupportSchedules: Get<u32>;
+		/// Minimum contribution of a supporter signing an asset
+		#[pallet::constant]
+		type MinimumSupportContribution: Get<BalanceOf<Self>>;
+		/// Share of secondary sale royalties paid to the supporters of the sold asset
+		#[pallet::constant]
+		type SupporterRoyaltyShare: Get<Perbill>;
+		/// Max number of currencies supporters of an asset earn royalty rewards in, royalties in
+		/// further currencies go to the class fund in full
+		#[pallet::constant]
+		type MaxSupporterRewardCurrencies: Get<u32>;
 	}
 
+	pub type SupportFundOf<T> = SupportFund<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+	pub type SupporterRewardIndexesOf<T> =
+		BoundedVec<(FungibleTokenId, FixedU128), <T as Config>::MaxSupporterRewardCurrencies>;
+	pub type PromotionCampaignOf<T> = PromotionCampaign<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+	pub type MintVoucherOf<T> = MintVoucher<
+		ClassIdOf<T>,
+		<T as frame_system::Config>::Hash,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
 	pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
 	pub type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -219,8 +394,14 @@ pub mod pallet {
 		StorageMap<_, Blake2_128Concat, AssetId, (ClassIdOf<T>, TokenIdOf<T>), OptionQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_assets_by_owner)]
-	pub(super) type AssetsByOwner<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Vec<AssetId>, ValueQuery>;
+	/// Index of assets by owner
+	pub(super) type AssetsByOwner<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_assets_count_by_owner)]
+	/// Number of assets owned by account
+	pub(super) type AssetsCountByOwner<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_group_collection)]
@@ -246,17 +427,170 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_asset_supporters)]
-	pub(super) type AssetSupporters<T: Config> =
-		StorageMap<_, Blake2_128Concat, AssetId, Vec<T::AccountId>, OptionQuery>;
+	/// Supporters of an asset and their total contribution
+	pub(super) type AssetSupporters<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxSupporters>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_class_support_fund)]
+	/// Contributions to the assets of a class held in the class fund
+	pub(super) type ClassSupportFunds<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, SupportFundOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_supporter_reward)]
+	/// Royalty rewards of a supporter by currency, waiting to be claimed
+	pub(super) type SupporterRewards<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, FungibleTokenId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_asset_support_total)]
+	/// Total contribution of the supporters of an asset
+	pub(super) type AssetSupportTotals<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_supporter_reward_indexes)]
+	/// Royalty rewards accrued per unit of contribution to the supporters of an asset, by currency
+	pub(super) type SupporterRewardIndexes<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, SupporterRewardIndexesOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_supporter_paid_indexes)]
+	/// Reward indexes of an asset up to which a supporter has been credited
+	pub(super) type SupporterPaidIndexes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		SupporterRewardIndexesOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_promotion_campaign_id)]
+	pub(super) type NextPromotionCampaignId<T: Config> = StorageValue<_, PromotionCampaignId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_promotion_campaign)]
+	/// Open promotion campaigns
+	pub(super) type PromotionCampaigns<T: Config> =
+		StorageMap<_, Twox64Concat, PromotionCampaignId, PromotionCampaignOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn promotion_campaign_count)]
+	/// Number of open promotion campaigns
+	pub(super) type PromotionCampaignCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_promotion_enabled)]
-	pub(super) type PromotionEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+	#[pallet::getter(fn get_promotion_reward)]
+	/// Rewards paid to an account by a promotion campaign
+	pub(super) type PromotionRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PromotionCampaignId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_locked_collection)]
 	pub(super) type LockedCollection<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, (), OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_metadata_updater)]
+	/// Account allowed to update token metadata and attributes on behalf of the class owner
+	pub(super) type MetadataUpdater<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_frozen_class_metadata)]
+	/// Classes whose tokens metadata and attributes are permanently immutable
+	pub(super) type FrozenClassMetadata<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_frozen_token_metadata)]
+	/// Tokens whose metadata and attributes are permanently immutable
+	pub(super) type FrozenTokenMetadata<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_equipped_asset)]
+	/// Assets equipped onto a parent asset by slot name
+	pub(super) type EquippedAssets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AssetId, Blake2_128Concat, NftMetadata, AssetId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_asset_parent)]
+	/// Parent asset and slot of an equipped asset
+	pub(super) type AssetParent<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, (AssetId, NftMetadata), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_execution_mode)]
+	/// Execution mode of executable collections
+	pub(super) type ExecutionModes<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, ExecutionMode<T::BlockNumber>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_last_execution)]
+	/// Block an executable asset was last executed
+	pub(super) type LastExecution<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_execution_filter)]
+	/// Governance controlled filter on calls executable assets can dispatch
+	pub(super) type ExecutionFilter<T: Config> = StorageValue<_, T::ExecutableCallFilter, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_time_capsule)]
+	/// Sealed time capsules by asset
+	pub(super) type TimeCapsules<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, TimeCapsule<BalanceOf<T>, T::BlockNumber, T::Hash>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_asset_approval)]
+	/// Account approved to transfer a single asset and the approval expiry
+	pub(super) type AssetApprovals<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, (T::AccountId, Option<T::BlockNumber>), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_operator)]
+	/// Operators of an owner by class, or of all classes if no class, and the operator expiry
+	pub(super) type Operators<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(T::AccountId, Option<ClassIdOf<T>>),
+		Option<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_asset_user_info)]
+	/// Account allowed to use an asset without owning it, until the expiry block
+	pub(super) type AssetUsers<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, AssetUser<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_rental_offer)]
+	/// Rental terms of assets available for rent
+	pub(super) type RentalOffers<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, RentalOffer<BalanceOf<T>, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_voucher_redeemed)]
+	/// Redeemed or cancelled mint voucher nonces by class
+	pub(super) type RedeemedVouchers<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Twox64Concat, u64, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -264,7 +598,7 @@ pub mod pallet {
 		NewNftCollectionCreated(GroupCollectionId),
 		/// New NFT Collection/Class created
 		NewNftClassCreated(<T as frame_system::Config>::AccountId, ClassIdOf<T>),
-		/// Emit event when new nft minted - show the first and last asset mint
+		/// Emit event when new nft minted - show the first and last asset mint and the class total supply
 		NewNftMinted(
 			AssetId,
 			AssetId,
@@ -272,6 +606,7 @@ pub mod pallet {
 			ClassIdOf<T>,
 			u32,
 			TokenIdOf<T>,
+			u64,
 		),
 		/// Emit event when new time capsule minted
 		NewTimeCapsuleMinted(
@@ -298,10 +633,14 @@ pub mod pallet {
 		),
 		/// Signed on NFT
 		SignedNft(TokenIdOf<T>, <T as frame_system::Config>::AccountId),
-		/// Promotion enabled
-		PromotionEnabled(bool),
-		/// Burn NFT
-		BurnedNft(AssetId),
+		/// Promotion campaign created [campaign_id, budget]
+		PromotionCampaignCreated(PromotionCampaignId, BalanceOf<T>),
+		/// Promotion reward paid to a minter [campaign_id, minter, reward]
+		PromotionRewardPaid(PromotionCampaignId, T::AccountId, BalanceOf<T>),
+		/// Promotion campaign closed and its remaining budget returned [campaign_id, remaining_budget]
+		PromotionCampaignClosed(PromotionCampaignId, BalanceOf<T>),
+		/// Burn NFT - show the class total supply after burning
+		BurnedNft(AssetId, u64),
 		/// Executed NFT
 		ExecutedNft(AssetId),
 		/// Scheduled time capsule
@@ -310,6 +649,60 @@ pub mod pallet {
 		CollectionLocked(ClassIdOf<T>),
 		/// Collection is unlocked
 		CollectionUnlocked(ClassIdOf<T>),
+		/// Metadata updater of the collection changed
+		MetadataUpdaterChanged(ClassIdOf<T>, Option<<T as frame_system::Config>::AccountId>),
+		/// Token metadata updated
+		TokenMetadataUpdated(AssetId),
+		/// Token attribute set
+		TokenAttributeSet(AssetId, Vec<u8>, Vec<u8>),
+		/// Token attribute removed
+		TokenAttributeRemoved(AssetId, Vec<u8>),
+		/// Token metadata frozen
+		TokenMetadataFrozen(AssetId),
+		/// Class metadata frozen
+		ClassMetadataFrozen(ClassIdOf<T>),
+		/// Asset equipped onto parent asset [child, parent, slot]
+		AssetEquipped(AssetId, AssetId, NftMetadata),
+		/// Asset unequipped from parent asset [child, parent, slot]
+		AssetUnequipped(AssetId, AssetId, NftMetadata),
+		/// Execution mode of collection changed
+		ExecutionModeChanged(ClassIdOf<T>, ExecutionMode<T::BlockNumber>),
+		/// Execution filter changed
+		ExecutionFilterChanged(T::ExecutableCallFilter),
+		/// Time capsule created [asset_id, unlock_block]
+		TimeCapsuleCreated(AssetId, T::BlockNumber),
+		/// Time capsule opened [asset_id, holder, revealed_metadata]
+		TimeCapsuleOpened(AssetId, T::AccountId, NftMetadata),
+		/// NFT class destroyed
+		ClassDestroyed(<T as frame_system::Config>::AccountId, ClassIdOf<T>),
+		/// Asset approved for transfer [owner, delegate, asset_id, expiry]
+		AssetApproved(T::AccountId, T::AccountId, AssetId, Option<T::BlockNumber>),
+		/// Asset approval cancelled [owner, asset_id]
+		AssetApprovalCancelled(T::AccountId, AssetId),
+		/// Operator set [owner, operator, class_id, expiry]
+		OperatorSet(T::AccountId, T::AccountId, Option<ClassIdOf<T>>, Option<T::BlockNumber>),
+		/// Operator removed [owner, operator, class_id]
+		OperatorRemoved(T::AccountId, T::AccountId, Option<ClassIdOf<T>>),
+		/// Mint voucher redeemed [buyer, class_id, nonce, asset_id]
+		VoucherRedeemed(T::AccountId, ClassIdOf<T>, u64, AssetId),
+		/// Mint voucher cancelled [class_id, nonce]
+		VoucherCancelled(ClassIdOf<T>, u64),
+		/// Usage rights of an asset granted [owner, user, asset_id, expires]
+		AssetUserSet(T::AccountId, T::AccountId, AssetId, T::BlockNumber),
+		/// Usage rights of an asset given up [user, asset_id]
+		AssetUserCleared(T::AccountId, AssetId),
+		/// Asset offered for rent [owner, asset_id, duration, currency_id, price]
+		RentalOffered(T::AccountId, AssetId, T::BlockNumber, FungibleTokenId, BalanceOf<T>),
+		/// Rental offer withdrawn [owner, asset_id]
+		RentalOfferCancelled(T::AccountId, AssetId),
+		/// Asset rented [renter, asset_id, expires, currency_id, price]
+		AssetRented(T::AccountId, AssetId, T::BlockNumber, FungibleTokenId, BalanceOf<T>),
+		/// Asset signed by a supporter [supporter, asset_id, contribution]
+		AssetSupported(T::AccountId, AssetId, BalanceOf<T>),
+		/// Vested contributions withdrawn by the class owner [class_id, owner, amount]
+		SupportedFundsWithdrawn(ClassIdOf<T>, T::AccountId, BalanceOf<T>),
+		/// Supporter royalty rewards claimed [supporter, currency_id, amount]
+		SupporterRewardsClaimed(T::AccountId, FungibleTokenId, BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -364,6 +757,102 @@ pub mod pallet {
 		CollectionIsLocked,
 		/// Collection is not locked
 		CollectionIsNotLocked,
+		/// Max supply must be greater than zero
+		InvalidMaxSupply,
+		/// Minting would exceed the class max supply
+		ExceedMaximumSupply,
+		/// Metadata is frozen
+		MetadataIsFrozen,
+		/// Metadata is already frozen
+		MetadataAlreadyFrozen,
+		/// Attribute not found
+		AttributeNotFound,
+		/// Only wearable assets can be equipped
+		AssetIsNotWearable,
+		/// Asset is equipped onto a parent asset
+		AssetIsEquipped,
+		/// Asset is not equipped
+		AssetIsNotEquipped,
+		/// Asset has equipped assets
+		AssetHasEquippedAssets,
+		/// Slot of parent asset is already occupied
+		SlotIsOccupied,
+		/// Asset cannot be equipped onto itself or its own equipped assets
+		InvalidParentAsset,
+		/// Exceed maximum nesting depth
+		ExceedMaximumNestingDepth,
+		/// Only executable collection
+		OnlyForExecutableCollectionType,
+		/// Executable call can not be decoded
+		InvalidExecutableCall,
+		/// Executable call is not allowed by execution filter
+		ExecutableCallFiltered,
+		/// Executable call weight is above the weight limit
+		ExecutableCallWeightTooHigh,
+		/// Asset was executed too recently
+		AssetExecutionOnCooldown,
+		/// Collection already has minted assets
+		CollectionAlreadyMinted,
+		/// Asset is a sealed time capsule
+		AssetIsTimeCapsule,
+		/// Time capsule does not exist
+		TimeCapsuleNotFound,
+		/// Time capsule can not be opened before the unlock block
+		TimeCapsuleIsSealed,
+		/// Metadata does not match the sealed hash of the time capsule
+		InvalidTimeCapsuleMetadata,
+		/// Unlock block must be in the future
+		InvalidUnlockBlock,
+		/// Class still has tokens in circulation
+		ClassIsNotEmpty,
+		/// Account can not approve itself
+		InvalidApprovalDelegate,
+		/// Expiry block must be in the future
+		InvalidApprovalExpiry,
+		/// Approval does not exist
+		ApprovalNotFound,
+		/// Operator does not exist
+		OperatorNotFound,
+		/// Account is not approved to transfer the asset
+		NotApproved,
+		/// Voucher is past its expiry block
+		VoucherExpired,
+		/// Voucher nonce was already redeemed or cancelled
+		VoucherAlreadyRedeemed,
+		/// Voucher is not signed by the class owner
+		InvalidVoucherSignature,
+		/// Metadata does not match the voucher metadata hash
+		InvalidVoucherMetadata,
+		/// Asset is already used by another account
+		AssetAlreadyRented,
+		/// Usage rights must end after the current block
+		InvalidRentalPeriod,
+		/// Account is not the user of the asset
+		NotAssetUser,
+		/// Asset is not offered for rent
+		RentalOfferNotFound,
+		/// Owner cannot rent its own asset
+		CannotRentOwnAsset,
+		/// Asset reached the maximum number of supporters
+		ExceedMaximumSupporters,
+		/// No vested contributions to withdraw
+		NoSupportedFundsToWithdraw,
+		/// No supporter rewards to claim
+		NoSupporterRewards,
+		/// Promotion campaign period, reward or budget is invalid
+		InvalidPromotionCampaign,
+		/// Too many promotion campaigns are open
+		ExceedMaximumPromotionCampaigns,
+		/// Promotion campaign does not exist
+		PromotionCampaignNotFound,
+		/// Promotion campaign has not ended yet
+		PromotionCampaignNotEnded,
+		/// No available promotion campaign id
+		NoAvailableCampaignId,
+		/// Contributions of supporters are still vesting or not withdrawn
+		SupportFundNotSettled,
+		/// Contribution is below the minimum support contribution
+		ContributionBelowMinimum,
 	}
 
 	#[pallet::call]
@@ -406,12 +895,14 @@ pub mod pallet {
 			collection_id: GroupCollectionId,
 			token_type: TokenType,
 			collection_type: CollectionType,
+			max_supply: Option<u64>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			ensure!(
 				metadata.len() as u32 <= T::MaxMetadata::get(),
 				Error::<T>::ExceedMaximumMetadataLength
 			);
+			ensure!(max_supply != Some(0), Error::<T>::InvalidMaxSupply);
 			let next_class_id = NftModule::<T>::next_class_id();
 			ensure!(
 				GroupCollections::<T>::contains_key(collection_id),
@@ -435,6 +926,7 @@ pub mod pallet {
 				attributes: attributes,
 				total_supply: Default::default(),
 				initial_supply: Default::default(),
+				max_supply,
 			};
 
 			NftModule::<T>::create_class(&sender, metadata, class_data)?;
@@ -445,7 +937,9 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(< T as Config >::WeightInfo::mint(* quantity))]
+		#[pallet::weight(< T as Config >::WeightInfo::mint(* quantity)
+			.saturating_add(T::WeightInfo::promotion_rewards(T::MaxPromotionCampaigns::get())))]
+		#[transactional]
 		pub fn mint(
 			origin: OriginFor<T>,
 			class_id: ClassIdOf<T>,
@@ -455,19 +949,12 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
-			ensure!(!Self::is_collection_locked(&class_id), Error::<T>::CollectionIsLocked);
-			ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
-			ensure!(
-				quantity <= T::MaxBatchMinting::get(),
-				Error::<T>::ExceedMaximumBatchMinting
-			);
 			ensure!(
 				metadata.len() as u32 <= T::MaxMetadata::get(),
 				Error::<T>::ExceedMaximumMetadataLength
 			);
+			let total_supply = Self::ensure_can_mint(&sender, class_id, quantity)?;
 
-			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
-			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
 			let token_deposit = Self::calculate_fee_deposit(&attributes)?;
 			let class_fund: T::AccountId = T::PalletId::get().into_sub_account(class_id);
 			let deposit = token_deposit.saturating_mul(Into::<BalanceOf<T>>::into(quantity));
@@ -476,7 +963,7 @@ pub mod pallet {
 			<T as Config>::Currency::reserve(&class_fund, deposit)?;
 
 			let new_nft_data = NftAssetData {
-				deposit,
+				deposit: token_deposit,
 				attributes: attributes,
 			};
 
@@ -484,40 +971,14 @@ pub mod pallet {
 			let mut last_token_id: TokenIdOf<T> = Default::default();
 
 			for _ in 0..quantity {
-				let asset_id = NextAssetId::<T>::try_mutate(|id| -> Result<AssetId, DispatchError> {
-					let current_id = *id;
-					*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAssetId)?;
-
-					Ok(current_id)
-				})?;
-
+				let (asset_id, token_id) = Self::mint_asset(&sender, class_id, metadata.clone(), new_nft_data.clone())?;
 				new_asset_ids.push(asset_id);
-
-				if AssetsByOwner::<T>::contains_key(&sender) {
-					AssetsByOwner::<T>::try_mutate(&sender, |asset_ids| -> DispatchResult {
-						// Check if the asset_id already in the owner
-						ensure!(
-							!asset_ids.iter().any(|i| asset_id == *i),
-							Error::<T>::AssetIdAlreadyExist
-						);
-						asset_ids.push(asset_id);
-						Ok(())
-					})?;
-				} else {
-					let mut assets = Vec::<AssetId>::new();
-					assets.push(asset_id);
-					AssetsByOwner::<T>::insert(&sender, assets)
-				}
-
-				let token_id = NftModule::<T>::mint(&sender, class_id, metadata.clone(), new_nft_data.clone())?;
-				Assets::<T>::insert(asset_id, (class_id, token_id));
 				last_token_id = token_id;
 			}
 
-			// If promotion enabled
-			if Self::is_promotion_enabled() {
-				T::MultiCurrency::deposit(T::MiningResourceId::get(), &sender, T::PromotionIncentive::get())?;
-			};
+			Self::update_class_total_supply(&class_id, total_supply)?;
+
+			Self::reward_promotion_campaigns(&sender, class_id, quantity)?;
 
 			Self::deposit_event(Event::<T>::NewNftMinted(
 				*new_asset_ids.first().unwrap(),
@@ -526,192 +987,1476 @@ pub mod pallet {
 				class_id,
 				quantity,
 				last_token_id,
+				total_supply,
 			));
 
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::transfer())]
-		pub fn transfer(origin: OriginFor<T>, to: T::AccountId, asset_id: AssetId) -> DispatchResultWithPostInfo {
+		/// Mint assets with their own metadata and attributes, each optionally sent to a recipient
+		/// instead of the class owner
+		#[pallet::weight(T::WeightInfo::mint_batch(items.len() as u32, Self::batch_data_len(&items))
+			.saturating_add(T::WeightInfo::promotion_rewards(T::MaxPromotionCampaigns::get())))]
+		#[transactional]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			items: BoundedVec<MintBatchItem<T::AccountId>, T::MaxBatchMinting>,
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			ensure!(
-				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
-				Error::<T>::AssetAlreadyInAuction
-			);
+			let quantity = items.len() as u32;
+			let total_supply = Self::ensure_can_mint(&sender, class_id, quantity)?;
+			let class_fund = Self::get_class_fund(&class_id);
 
-			let token_id = Self::do_transfer(&sender, &to, asset_id)?;
+			let mut new_asset_ids: Vec<AssetId> = Vec::new();
+			let mut last_token_id: TokenIdOf<T> = Default::default();
 
-			Self::deposit_event(Event::<T>::TransferedNft(sender, to, token_id, asset_id.clone()));
+			for (metadata, attributes, recipient) in items.into_iter() {
+				ensure!(
+					metadata.len() as u32 <= T::MaxMetadata::get(),
+					Error::<T>::ExceedMaximumMetadataLength
+				);
 
-			Ok(().into())
-		}
+				let token_deposit = Self::calculate_fee_deposit(&attributes)?;
+				<T as Config>::Currency::transfer(
+					&sender,
+					&class_fund,
+					token_deposit,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				<T as Config>::Currency::reserve(&class_fund, token_deposit)?;
+
+				let owner = recipient.unwrap_or_else(|| sender.clone());
+				let (asset_id, token_id) = Self::mint_asset(
+					&owner,
+					class_id,
+					metadata,
+					NftAssetData {
+						deposit: token_deposit,
+						attributes,
+					},
+				)?;
+				new_asset_ids.push(asset_id);
+				last_token_id = token_id;
+			}
 
-		#[pallet::weight(T::WeightInfo::transfer_batch(tos.len() as u32))]
-		pub fn transfer_batch(origin: OriginFor<T>, tos: Vec<(T::AccountId, AssetId)>) -> DispatchResultWithPostInfo {
-			let sender = ensure_signed(origin)?;
+			Self::update_class_total_supply(&class_id, total_supply)?;
 
-			ensure!(
-				tos.len() as u32 <= T::MaxBatchTransfer::get(),
-				Error::<T>::ExceedMaximumBatchTransfer
-			);
+			Self::reward_promotion_campaigns(&sender, class_id, quantity)?;
 
-			for (_i, x) in tos.iter().enumerate() {
-				let item = &x;
-				let owner = &sender.clone();
-
-				let asset = Assets::<T>::get(item.1).ok_or(Error::<T>::AssetIdNotFound)?;
-
-				let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
-				let data = class_info.data;
-
-				match data.token_type {
-					TokenType::Transferable => {
-						let asset_info =
-							NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
-						ensure!(owner.clone() == asset_info.owner, Error::<T>::NoPermission);
-						Self::handle_asset_ownership_transfer(&owner, &item.0, item.1)?;
-						NftModule::<T>::transfer(&owner, &item.0, (asset.0, asset.1))?;
-						Self::deposit_event(Event::<T>::TransferedNft(
-							owner.clone(),
-							item.0.clone(),
-							asset.1.clone(),
-							item.1.clone(),
-						));
-					}
-					_ => (),
-				};
-			}
+			Self::deposit_event(Event::<T>::NewNftMinted(
+				*new_asset_ids.first().ok_or(Error::<T>::InvalidQuantity)?,
+				*new_asset_ids.last().ok_or(Error::<T>::InvalidQuantity)?,
+				sender,
+				class_id,
+				quantity,
+				last_token_id,
+				total_supply,
+			));
 
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::sign_asset())]
-		pub fn sign_asset(
+		/// Redeem a voucher signed off-chain by the class owner, paying the voucher price to the
+		/// class owner and minting the asset to the buyer
+		#[pallet::weight(T::WeightInfo::mint(1).saturating_add(T::WeightInfo::transfer()))]
+		#[transactional]
+		pub fn redeem_voucher(
 			origin: OriginFor<T>,
-			asset_id: AssetId,
-			contribution: BalanceOf<T>,
+			voucher: MintVoucherOf<T>,
+			metadata: NftMetadata,
+			signature: T::OffchainSignature,
 		) -> DispatchResultWithPostInfo {
-			let sender = ensure_signed(origin)?;
+			let buyer = ensure_signed(origin)?;
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= voucher.expiry,
+				Error::<T>::VoucherExpired
+			);
+			ensure!(
+				T::Hashing::hash(&metadata) == voucher.metadata_hash,
+				Error::<T>::InvalidVoucherMetadata
+			);
+			ensure!(
+				!RedeemedVouchers::<T>::contains_key(voucher.class_id, voucher.nonce),
+				Error::<T>::VoucherAlreadyRedeemed
+			);
+
+			let class_id = voucher.class_id;
+			let class_owner = NftModule::<T>::classes(class_id)
+				.ok_or(Error::<T>::ClassIdNotFound)?
+				.owner;
+			ensure!(
+				signature.verify(&Self::voucher_signing_payload(&voucher)[..], &class_owner),
+				Error::<T>::InvalidVoucherSignature
+			);
+			let total_supply = Self::ensure_can_mint(&class_owner, class_id, 1)?;
 
-			let asset_by_owner: Vec<AssetId> = Self::get_assets_by_owner(&sender);
-			ensure!(!asset_by_owner.contains(&asset_id), Error::<T>::SignOwnAsset);
+			// Buyer pays the voucher price to the class owner and the token deposit to the class fund
+			T::MultiCurrency::transfer(voucher.currency_id, &buyer, &class_owner, voucher.price)?;
+			let token_deposit = Self::calculate_fee_deposit(&voucher.attributes)?;
+			let class_fund = Self::get_class_fund(&class_id);
+			<T as Config>::Currency::transfer(&buyer, &class_fund, token_deposit, ExistenceRequirement::KeepAlive)?;
+			<T as Config>::Currency::reserve(&class_fund, token_deposit)?;
 
-			// Add contribution into class fund
-			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let (asset_id, token_id) = Self::mint_asset(
+				&buyer,
+				class_id,
+				metadata,
+				NftAssetData {
+					deposit: token_deposit,
+					attributes: voucher.attributes,
+				},
+			)?;
+			Self::update_class_total_supply(&class_id, total_supply)?;
+			RedeemedVouchers::<T>::insert(class_id, voucher.nonce, ());
 
-			let class_fund = Self::get_class_fund(&asset.0);
+			Self::deposit_event(Event::<T>::NewNftMinted(
+				asset_id,
+				asset_id,
+				buyer.clone(),
+				class_id,
+				1,
+				token_id,
+				total_supply,
+			));
+			Self::deposit_event(Event::<T>::VoucherRedeemed(buyer, class_id, voucher.nonce, asset_id));
 
+			Ok(().into())
+		}
+
+		/// Cancel an unredeemed voucher nonce, only class owner can cancel
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn cancel_voucher(origin: OriginFor<T>, class_id: ClassIdOf<T>, nonce: u64) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
 			ensure!(
-				<T as Config>::Currency::free_balance(&sender) > contribution,
-				Error::<T>::InsufficientBalance
+				!RedeemedVouchers::<T>::contains_key(class_id, nonce),
+				Error::<T>::VoucherAlreadyRedeemed
 			);
-			// Transfer contribution to class fund pot
-			<T as Config>::Currency::transfer(&sender, &class_fund, contribution, ExistenceRequirement::KeepAlive)?;
-			// Reserve pot fund
-			<T as Config>::Currency::reserve(&class_fund, contribution)?;
 
-			if AssetSupporters::<T>::contains_key(&asset_id) {
-				AssetSupporters::<T>::try_mutate(asset_id, |supporters| -> DispatchResult {
-					let supporters = supporters.as_mut().ok_or(Error::<T>::EmptySupporters)?;
-					supporters.push(sender);
-					Ok(())
-				})?;
-			} else {
-				let mut new_supporters = Vec::new();
-				new_supporters.push(sender);
-				AssetSupporters::<T>::insert(asset_id, new_supporters);
-			}
+			RedeemedVouchers::<T>::insert(class_id, nonce, ());
+
+			Self::deposit_event(Event::<T>::VoucherCancelled(class_id, nonce));
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::sign_asset())]
-		pub fn enable_promotion(origin: OriginFor<T>, enable: bool) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(origin: OriginFor<T>, to: T::AccountId, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			let token_id = Self::do_transfer(&sender, &to, asset_id)?;
 
-			PromotionEnabled::<T>::put(enable);
+			Self::deposit_event(Event::<T>::TransferedNft(sender, to, token_id, asset_id.clone()));
 
-			Self::deposit_event(Event::<T>::PromotionEnabled(enable));
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::sign_asset())]
-		pub fn burn(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+		/// Approve an account to transfer a single asset on behalf of the owner, optionally until
+		/// the expiry block. Approval is cleared when the asset is transferred.
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn approve(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+			asset_id: AssetId,
+			expiry: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			let asset_by_owner: Vec<AssetId> = Self::get_assets_by_owner(&sender);
+			ensure!(sender != delegate, Error::<T>::InvalidApprovalDelegate);
+			Self::ensure_valid_expiry(expiry)?;
 
-			ensure!(asset_by_owner.contains(&asset_id), Error::<T>::NoPermission);
 			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(sender == asset_info.owner, Error::<T>::NoPermission);
 
-			NftModule::<T>::burn(&sender, asset)?;
-			Assets::<T>::remove(asset_id);
-			Self::deposit_event(Event::<T>::BurnedNft(asset_id));
+			AssetApprovals::<T>::insert(asset_id, (delegate.clone(), expiry));
+
+			Self::deposit_event(Event::<T>::AssetApproved(sender, delegate, asset_id, expiry));
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::sign_asset())]
-		pub fn force_lock_collection(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
-			ensure_root(origin)?;
+		/// Cancel the approval of an asset
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn cancel_approval(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
 
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(sender == asset_info.owner, Error::<T>::NoPermission);
 			ensure!(
-				!LockedCollection::<T>::contains_key(class_id),
-				Error::<T>::CollectionAlreadyLocked
+				AssetApprovals::<T>::contains_key(asset_id),
+				Error::<T>::ApprovalNotFound
 			);
 
-			LockedCollection::<T>::insert(class_id.clone(), ());
-			Self::deposit_event(Event::<T>::CollectionLocked(class_id));
+			AssetApprovals::<T>::remove(asset_id);
 
-			Ok(())
+			Self::deposit_event(Event::<T>::AssetApprovalCancelled(sender, asset_id));
+			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::sign_asset())]
-		pub fn force_unlock_collection(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
-			ensure_root(origin)?;
+		/// Allow an operator to transfer all assets of a class, or all assets of the owner if no
+		/// class is given, optionally until the expiry block
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn set_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			class_id: Option<ClassIdOf<T>>,
+			expiry: Option<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(sender != operator, Error::<T>::InvalidApprovalDelegate);
+			Self::ensure_valid_expiry(expiry)?;
+			if let Some(class_id) = class_id {
+				ensure!(NftModule::<T>::classes(class_id).is_some(), Error::<T>::ClassIdNotFound);
+			}
+
+			Operators::<T>::insert(&sender, (operator.clone(), class_id), expiry);
+
+			Self::deposit_event(Event::<T>::OperatorSet(sender, operator, class_id, expiry));
+			Ok(().into())
+		}
 
+		/// Remove a class-wide or account-wide operator
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn remove_operator(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			class_id: Option<ClassIdOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
 			ensure!(
-				LockedCollection::<T>::contains_key(class_id),
-				Error::<T>::CollectionIsNotLocked
+				Operators::<T>::contains_key(&sender, (operator.clone(), class_id)),
+				Error::<T>::OperatorNotFound
 			);
 
-			LockedCollection::<T>::remove(class_id.clone());
-			Self::deposit_event(Event::<T>::CollectionUnlocked(class_id));
+			Operators::<T>::remove(&sender, (operator.clone(), class_id));
 
-			Ok(())
+			Self::deposit_event(Event::<T>::OperatorRemoved(sender, operator, class_id));
+			Ok(().into())
 		}
 
-		/// Force NFT transfer which only triggered by governance
+		/// Transfer an asset on behalf of its owner, only approved accounts or operators can transfer
 		#[pallet::weight(T::WeightInfo::transfer())]
-		pub fn force_transfer(
+		pub fn transfer_from(
 			origin: OriginFor<T>,
 			from: T::AccountId,
 			to: T::AccountId,
 			asset_id: AssetId,
 		) -> DispatchResultWithPostInfo {
-			ensure_root(origin)?;
+			let sender = ensure_signed(origin)?;
 			ensure!(
 				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
 				Error::<T>::AssetAlreadyInAuction
 			);
 
-			let token_id = Self::do_force_transfer(&from, &to, asset_id)?;
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(from == asset_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				Self::is_approved_or_operator(&from, &sender, asset_id, asset.0),
+				Error::<T>::NotApproved
+			);
 
-			Self::deposit_event(Event::<T>::ForceTransferredNft(from, to, token_id, asset_id.clone()));
+			let token_id = Self::do_transfer(&from, &to, asset_id)?;
+
+			Self::deposit_event(Event::<T>::TransferedNft(from, to, token_id, asset_id));
 
 			Ok(().into())
 		}
-	}
-
-	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
-}
 
-impl<T: Config> Pallet<T> {
-	pub fn is_promotion_enabled() -> bool {
-		Self::get_promotion_enabled()
-	}
+		#[pallet::weight(T::WeightInfo::transfer_batch(tos.len() as u32))]
+		#[transactional]
+		pub fn transfer_batch(origin: OriginFor<T>, tos: Vec<(T::AccountId, AssetId)>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
 
-	pub fn get_class_fund(class_id: &ClassIdOf<T>) -> T::AccountId {
-		T::PalletId::get().into_sub_account(class_id)
-	}
+			ensure!(
+				tos.len() as u32 <= T::MaxBatchTransfer::get(),
+				Error::<T>::ExceedMaximumBatchTransfer
+			);
+
+			for (to, asset_id) in tos.into_iter() {
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+					Error::<T>::AssetAlreadyInAuction
+				);
+
+				let token_id = Self::do_transfer(&sender, &to, asset_id)?;
+
+				Self::deposit_event(Event::<T>::TransferedNft(sender.clone(), to, token_id, asset_id));
+			}
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::sign_asset(T::MaxSupporterRewardCurrencies::get()))]
+		#[transactional]
+		pub fn sign_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			contribution: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				!AssetsByOwner::<T>::contains_key(&sender, asset_id),
+				Error::<T>::SignOwnAsset
+			);
+
+			// Add contribution into class fund
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+
+			let class_fund = Self::get_class_fund(&asset.0);
+
+			ensure!(
+				contribution >= T::MinimumSupportContribution::get(),
+				Error::<T>::ContributionBelowMinimum
+			);
+			ensure!(
+				<T as Config>::Currency::free_balance(&sender) > contribution,
+				Error::<T>::InsufficientBalance
+			);
+			// Repeated contributions of a supporter are merged into a single record, rewards earned
+			// on the previous contribution are credited first
+			AssetSupporters::<T>::try_mutate(asset_id, |supporters| -> DispatchResult {
+				if let Some(supporter) = supporters.iter_mut().find(|(who, _)| who == &sender) {
+					Self::settle_supporter_rewards(asset_id, &sender, supporter.1);
+					supporter.1 = supporter.1.saturating_add(contribution);
+				} else {
+					supporters
+						.try_push((sender.clone(), contribution))
+						.map_err(|_| Error::<T>::ExceedMaximumSupporters)?;
+					Self::settle_supporter_rewards(asset_id, &sender, Zero::zero());
+				}
+				Ok(())
+			})?;
+			AssetSupportTotals::<T>::mutate(asset_id, |total| *total = total.saturating_add(contribution));
+
+			// Transfer contribution to class fund pot
+			<T as Config>::Currency::transfer(&sender, &class_fund, contribution, ExistenceRequirement::KeepAlive)?;
+			// Reserve pot fund
+			<T as Config>::Currency::reserve(&class_fund, contribution)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ClassSupportFunds::<T>::mutate(asset.0, |fund| Self::add_support(fund, contribution, now));
+
+			Self::deposit_event(Event::<T>::AssetSupported(sender, asset_id, contribution));
+			Ok(().into())
+		}
+
+		/// Withdraw the vested contributions of supporters from the class fund, only the class
+		/// owner can withdraw
+		#[pallet::weight(T::WeightInfo::transfer())]
+		#[transactional]
+		pub fn withdraw_supported_funds(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let amount = ClassSupportFunds::<T>::try_mutate(class_id, |fund| -> Result<BalanceOf<T>, DispatchError> {
+				let amount = Self::withdrawable_support(fund, now);
+				ensure!(!amount.is_zero(), Error::<T>::NoSupportedFundsToWithdraw);
+				Self::release_vested_support(fund, now);
+				fund.withdrawn = fund.withdrawn.saturating_add(amount);
+				Ok(amount)
+			})?;
+
+			let class_fund = Self::get_class_fund(&class_id);
+			<T as Config>::Currency::unreserve(&class_fund, amount);
+			<T as Config>::Currency::transfer(&class_fund, &sender, amount, ExistenceRequirement::AllowDeath)?;
+
+			Self::deposit_event(Event::<T>::SupportedFundsWithdrawn(class_id, sender, amount));
+			Ok(().into())
+		}
+
+		/// Claim the royalty rewards earned as a supporter in the given currency, crediting the
+		/// rewards accrued on the asset since the last claim first
+		#[pallet::weight(T::WeightInfo::claim_supporter_rewards(T::MaxSupporterRewardCurrencies::get()))]
+		#[transactional]
+		pub fn claim_supporter_rewards(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			currency_id: FungibleTokenId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			if let Some((_, contribution)) = AssetSupporters::<T>::get(asset_id)
+				.into_iter()
+				.find(|(who, _)| who == &sender)
+			{
+				Self::settle_supporter_rewards(asset_id, &sender, contribution);
+			}
+			let amount = SupporterRewards::<T>::take(&sender, currency_id);
+			ensure!(!amount.is_zero(), Error::<T>::NoSupporterRewards);
+			T::MultiCurrency::transfer(currency_id, &Self::get_supporter_reward_account(), &sender, amount)?;
+
+			Self::deposit_event(Event::<T>::SupporterRewardsClaimed(sender, currency_id, amount));
+			Ok(().into())
+		}
+
+		/// Create a promotion campaign rewarding minters with `reward_per_token` for every token
+		/// minted between the start and end blocks, up to `account_cap` per account. The budget is
+		/// moved from the promotion fund into the campaign account.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		#[transactional]
+		pub fn create_promotion_campaign(
+			origin: OriginFor<T>,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+			currency_id: FungibleTokenId,
+			reward_per_token: BalanceOf<T>,
+			budget: BalanceOf<T>,
+			account_cap: BalanceOf<T>,
+			eligible_collections: Vec<GroupCollectionId>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(
+				start < end && end > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidPromotionCampaign
+			);
+			ensure!(
+				!reward_per_token.is_zero() && !budget.is_zero() && !account_cap.is_zero(),
+				Error::<T>::InvalidPromotionCampaign
+			);
+			ensure!(
+				Self::promotion_campaign_count() < T::MaxPromotionCampaigns::get(),
+				Error::<T>::ExceedMaximumPromotionCampaigns
+			);
+			for collection_id in eligible_collections.iter() {
+				ensure!(
+					GroupCollections::<T>::contains_key(collection_id),
+					Error::<T>::CollectionIsNotExist
+				);
+			}
+
+			let campaign_id =
+				NextPromotionCampaignId::<T>::try_mutate(|id| -> Result<PromotionCampaignId, DispatchError> {
+					let current_id = *id;
+					*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableCampaignId)?;
+					Ok(current_id)
+				})?;
+
+			T::MultiCurrency::transfer(
+				currency_id,
+				&Self::get_promotion_fund_account(),
+				&Self::get_promotion_campaign_account(campaign_id),
+				budget,
+			)?;
+
+			PromotionCampaigns::<T>::insert(
+				campaign_id,
+				PromotionCampaign {
+					start,
+					end,
+					currency_id,
+					reward_per_token,
+					remaining_budget: budget,
+					account_cap,
+					eligible_collections,
+				},
+			);
+			PromotionCampaignCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::<T>::PromotionCampaignCreated(campaign_id, budget));
+			Ok(().into())
+		}
+
+		/// Close a promotion campaign and return its remaining budget to the promotion fund.
+		/// Governance can close a campaign at any time, anyone can close it once it has ended.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		#[transactional]
+		pub fn close_promotion_campaign(
+			origin: OriginFor<T>,
+			campaign_id: PromotionCampaignId,
+		) -> DispatchResultWithPostInfo {
+			let campaign = PromotionCampaigns::<T>::get(campaign_id).ok_or(Error::<T>::PromotionCampaignNotFound)?;
+			if ensure_root(origin.clone()).is_err() {
+				ensure_signed(origin)?;
+				ensure!(
+					<frame_system::Pallet<T>>::block_number() >= campaign.end,
+					Error::<T>::PromotionCampaignNotEnded
+				);
+			}
+
+			Self::do_close_promotion_campaign(campaign_id, &campaign)?;
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::burn())]
+		#[transactional]
+		pub fn burn(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				AssetsByOwner::<T>::contains_key(&sender, asset_id),
+				Error::<T>::NoPermission
+			);
+
+			let total_supply = Self::do_burn(&sender, asset_id)?;
+			Self::deposit_event(Event::<T>::BurnedNft(asset_id, total_supply));
+			Ok(().into())
+		}
+
+		/// Destroy a class without tokens in circulation and refund the class deposit, only class
+		/// owner can destroy once every supporter contribution has vested and been withdrawn
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		#[transactional]
+		pub fn destroy_class(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				class_info.data.total_supply == 0 && class_info.total_issuance == Default::default(),
+				Error::<T>::ClassIsNotEmpty
+			);
+
+			let support_fund = ClassSupportFunds::<T>::get(class_id);
+			ensure!(
+				support_fund
+					.vesting
+					.iter()
+					.fold(support_fund.released, |total, (contribution, _)| {
+						total.saturating_add(*contribution)
+					})
+					.saturating_sub(support_fund.withdrawn)
+					.is_zero(),
+				Error::<T>::SupportFundNotSettled
+			);
+
+			NftModule::<T>::destroy_class(&sender, class_id)?;
+			ClassDataCollection::<T>::remove(class_id);
+			LockedCollection::<T>::remove(class_id);
+			MetadataUpdater::<T>::remove(class_id);
+			FrozenClassMetadata::<T>::remove(class_id);
+			ExecutionModes::<T>::remove(class_id);
+			ClassSupportFunds::<T>::remove(class_id);
+
+			// Refund class deposit to the class owner
+			let class_fund = Self::get_class_fund(&class_id);
+			let deposit = class_info.data.deposit;
+			<T as Config>::Currency::unreserve(&class_fund, deposit);
+			<T as Config>::Currency::transfer(&class_fund, &sender, deposit, ExistenceRequirement::AllowDeath)?;
+
+			Self::deposit_event(Event::<T>::ClassDestroyed(sender, class_id));
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn force_lock_collection(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				!LockedCollection::<T>::contains_key(class_id),
+				Error::<T>::CollectionAlreadyLocked
+			);
+
+			LockedCollection::<T>::insert(class_id.clone(), ());
+			Self::deposit_event(Event::<T>::CollectionLocked(class_id));
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn force_unlock_collection(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				LockedCollection::<T>::contains_key(class_id),
+				Error::<T>::CollectionIsNotLocked
+			);
+
+			LockedCollection::<T>::remove(class_id.clone());
+			Self::deposit_event(Event::<T>::CollectionUnlocked(class_id));
+
+			Ok(())
+		}
+
+		/// Force NFT transfer which only triggered by governance
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn force_transfer(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			asset_id: AssetId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			let token_id = Self::do_force_transfer(&from, &to, asset_id)?;
+
+			Self::deposit_event(Event::<T>::ForceTransferredNft(from, to, token_id, asset_id.clone()));
+
+			Ok(().into())
+		}
+
+		/// Set or clear the account allowed to update token metadata of the class
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn set_metadata_updater(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			updater: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+
+			match updater.clone() {
+				Some(account) => MetadataUpdater::<T>::insert(class_id, account),
+				None => MetadataUpdater::<T>::remove(class_id),
+			}
+
+			Self::deposit_event(Event::<T>::MetadataUpdaterChanged(class_id, updater));
+			Ok(().into())
+		}
+
+		/// Replace the metadata of a token, only class owner or metadata updater can update
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn set_token_metadata(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			metadata: NftMetadata,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+
+			let asset = Self::ensure_can_update_metadata(&sender, asset_id)?;
+			let bounded_metadata = metadata
+				.try_into()
+				.map_err(|_| Error::<T>::ExceedMaximumMetadataLength)?;
+
+			orml_nft::Tokens::<T>::try_mutate(asset.0, asset.1, |token_info| -> DispatchResult {
+				let token_info = token_info.as_mut().ok_or(Error::<T>::AssetInfoNotFound)?;
+				token_info.metadata = bounded_metadata;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TokenMetadataUpdated(asset_id));
+			Ok(().into())
+		}
+
+		/// Insert or replace a token attribute, only class owner or metadata updater can update
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		#[transactional]
+		pub fn set_token_attribute(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			Self::do_update_token_attributes(&sender, asset_id, |attributes| {
+				attributes.insert(key.clone(), value.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TokenAttributeSet(asset_id, key, value));
+			Ok(().into())
+		}
+
+		/// Remove a token attribute, only class owner or metadata updater can update
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		#[transactional]
+		pub fn remove_token_attribute(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			key: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			Self::do_update_token_attributes(&sender, asset_id, |attributes| {
+				attributes.remove(&key).ok_or(Error::<T>::AttributeNotFound)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TokenAttributeRemoved(asset_id, key));
+			Ok(().into())
+		}
+
+		/// Make token metadata and attributes permanently immutable, only class owner can freeze
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn freeze_token_metadata(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				!Self::is_metadata_frozen(&asset.0, &asset_id),
+				Error::<T>::MetadataAlreadyFrozen
+			);
+
+			FrozenTokenMetadata::<T>::insert(asset_id, ());
+
+			Self::deposit_event(Event::<T>::TokenMetadataFrozen(asset_id));
+			Ok(().into())
+		}
+
+		/// Make metadata and attributes of all class tokens permanently immutable, only class owner
+		/// can freeze
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn freeze_class_metadata(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				!FrozenClassMetadata::<T>::contains_key(class_id),
+				Error::<T>::MetadataAlreadyFrozen
+			);
+
+			FrozenClassMetadata::<T>::insert(class_id, ());
+
+			Self::deposit_event(Event::<T>::ClassMetadataFrozen(class_id));
+			Ok(().into())
+		}
+
+		/// Equip a wearable asset onto a parent asset in a named slot. The wearable moves with the
+		/// parent and is locked until it is unequipped.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		#[transactional]
+		pub fn equip(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			parent_id: AssetId,
+			slot: NftMetadata,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				slot.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+				Error::<T>::AssetAlreadyInAuction
+			);
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(parent_id)),
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			ensure!(!Self::is_collection_locked(&asset.0), Error::<T>::CollectionIsLocked);
+			let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(
+				class_info.data.collection_type.is_wearable(),
+				Error::<T>::AssetIsNotWearable
+			);
+			ensure!(
+				class_info.data.token_type.is_transferable(),
+				Error::<T>::NonTransferable
+			);
+			ensure!(!AssetParent::<T>::contains_key(asset_id), Error::<T>::AssetIsEquipped);
+			ensure!(
+				EquippedAssets::<T>::iter_prefix(asset_id).next().is_none(),
+				Error::<T>::AssetHasEquippedAssets
+			);
+
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(sender == asset_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				Self::check_nft_ownership(&sender, &parent_id)?,
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				!EquippedAssets::<T>::contains_key(parent_id, &slot),
+				Error::<T>::SlotIsOccupied
+			);
+
+			// Walk up from the parent to prevent cycles and unbounded nesting
+			let mut depth: u32 = 1;
+			let mut ancestor_id = parent_id;
+			loop {
+				ensure!(ancestor_id != asset_id, Error::<T>::InvalidParentAsset);
+				match AssetParent::<T>::get(ancestor_id) {
+					Some((grand_parent_id, _)) => {
+						depth = depth.saturating_add(1);
+						ensure!(depth < MAX_NESTING_DEPTH, Error::<T>::ExceedMaximumNestingDepth);
+						ancestor_id = grand_parent_id;
+					}
+					None => break,
+				}
+			}
+
+			let nesting_account = Self::get_nesting_account(&parent_id);
+			Self::handle_asset_ownership_transfer(&sender, &nesting_account, asset_id)?;
+			NftModule::<T>::transfer(&sender, &nesting_account, asset)?;
+
+			EquippedAssets::<T>::insert(parent_id, &slot, asset_id);
+			AssetParent::<T>::insert(asset_id, (parent_id, slot.clone()));
+
+			Self::deposit_event(Event::<T>::AssetEquipped(asset_id, parent_id, slot));
+			Ok(().into())
+		}
+
+		/// Unequip an asset from its parent, only the owner of the parent asset can unequip
+		#[pallet::weight(T::WeightInfo::transfer())]
+		#[transactional]
+		pub fn unequip(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (parent_id, slot) = AssetParent::<T>::get(asset_id).ok_or(Error::<T>::AssetIsNotEquipped)?;
+			ensure!(
+				Self::check_nft_ownership(&sender, &parent_id)?,
+				Error::<T>::NoPermission
+			);
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let nesting_account = Self::get_nesting_account(&parent_id);
+			Self::handle_asset_ownership_transfer(&nesting_account, &sender, asset_id)?;
+			NftModule::<T>::transfer(&nesting_account, &sender, asset)?;
+
+			EquippedAssets::<T>::remove(parent_id, &slot);
+			AssetParent::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::<T>::AssetUnequipped(asset_id, parent_id, slot));
+			Ok(().into())
+		}
+
+		/// Set how often assets of an executable collection can be executed, only before the first
+		/// asset is minted
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn set_execution_mode(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			mode: ExecutionMode<T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				class_info.data.collection_type.is_executable(),
+				Error::<T>::OnlyForExecutableCollectionType
+			);
+			ensure!(
+				NftModule::<T>::next_token_id(class_id) == Default::default(),
+				Error::<T>::CollectionAlreadyMinted
+			);
+
+			ExecutionModes::<T>::insert(class_id, mode.clone());
+
+			Self::deposit_event(Event::<T>::ExecutionModeChanged(class_id, mode));
+			Ok(().into())
+		}
+
+		/// Set the filter on calls executable assets can dispatch, only triggered by governance
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn set_execution_filter(
+			origin: OriginFor<T>,
+			filter: T::ExecutableCallFilter,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ExecutionFilter::<T>::put(filter.clone());
+
+			Self::deposit_event(Event::<T>::ExecutionFilterChanged(filter));
+			Ok(().into())
+		}
+
+		/// Dispatch the call encoded in an executable collection on behalf of the asset owner, only
+		/// the weight actually used by the call is charged out of `weight_limit`
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(*weight_limit))]
+		#[transactional]
+		pub fn execute_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+				Error::<T>::AssetAlreadyInAuction
+			);
+			ensure!(!AssetParent::<T>::contains_key(asset_id), Error::<T>::AssetIsEquipped);
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			ensure!(!Self::is_collection_locked(&asset.0), Error::<T>::CollectionIsLocked);
+			ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
+
+			let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			let encoded_call = match class_info.data.collection_type {
+				CollectionType::Executable(encoded_call) => encoded_call,
+				_ => return Err(Error::<T>::OnlyForExecutableCollectionType.into()),
+			};
+			let call = <T as Config>::ExecutableCall::decode_with_depth_limit(
+				MAX_EXECUTABLE_CALL_DEPTH,
+				&mut &encoded_call[..],
+			)
+			.map_err(|_| Error::<T>::InvalidExecutableCall)?;
+			ensure!(
+				Self::get_execution_filter().filter(&call),
+				Error::<T>::ExecutableCallFiltered
+			);
+			let call_info = call.get_dispatch_info();
+			ensure!(
+				call_info.weight <= weight_limit,
+				Error::<T>::ExecutableCallWeightTooHigh
+			);
+
+			match Self::get_execution_mode(asset.0) {
+				ExecutionMode::Unlimited => {}
+				ExecutionMode::SingleUse => {
+					let total_supply = Self::do_burn(&sender, asset_id)?;
+					Self::deposit_event(Event::<T>::BurnedNft(asset_id, total_supply));
+				}
+				ExecutionMode::Cooldown(cooldown) => {
+					let now = <frame_system::Pallet<T>>::block_number();
+					if let Some(last_execution) = LastExecution::<T>::get(asset_id) {
+						ensure!(
+							now >= last_execution.saturating_add(cooldown),
+							Error::<T>::AssetExecutionOnCooldown
+						);
+					}
+					LastExecution::<T>::insert(asset_id, now);
+				}
+			}
+
+			let post_info = call
+				.dispatch(frame_system::RawOrigin::Signed(sender).into())
+				.map_err(|e| {
+					let actual_weight =
+						T::WeightInfo::transfer().saturating_add(e.post_info.calc_actual_weight(&call_info));
+					e.error.with_weight(actual_weight)
+				})?;
+
+			Self::deposit_event(Event::<T>::ExecutedNft(asset_id));
+			Ok(Some(T::WeightInfo::transfer().saturating_add(post_info.calc_actual_weight(&call_info))).into())
+		}
+
+		/// Attach balances and the hash of sealed metadata to an asset, which can be claimed by the
+		/// asset holder once the unlock block is reached. The metadata stays off chain until opened.
+		#[pallet::weight(T::WeightInfo::create_time_capsule(contents.len() as u32))]
+		#[transactional]
+		pub fn create_time_capsule(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			unlock_block: T::BlockNumber,
+			contents: Vec<(FungibleTokenId, BalanceOf<T>)>,
+			metadata_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				contents.len() as u32 <= T::MaxBatchTransfer::get(),
+				Error::<T>::ExceedMaximumBatchTransfer
+			);
+			ensure!(
+				unlock_block > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidUnlockBlock
+			);
+			ensure!(
+				!TimeCapsules::<T>::contains_key(asset_id),
+				Error::<T>::AssetIsTimeCapsule
+			);
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				AssetsByOwner::<T>::contains_key(&sender, asset_id),
+				Error::<T>::NoPermission
+			);
+
+			let capsule_account = Self::get_time_capsule_account(&asset_id);
+			let mut native_amount = BalanceOf::<T>::zero();
+			for (currency_id, amount) in contents.iter() {
+				if *currency_id == FungibleTokenId::NativeToken(0) {
+					<T as Config>::Currency::transfer(
+						&sender,
+						&capsule_account,
+						*amount,
+						ExistenceRequirement::KeepAlive,
+					)?;
+					native_amount = native_amount.saturating_add(*amount);
+				} else {
+					T::MultiCurrency::transfer(*currency_id, &sender, &capsule_account, *amount)?;
+				}
+			}
+			if !native_amount.is_zero() {
+				<T as Config>::Currency::set_lock(
+					TIMECAPSULE_ID,
+					&capsule_account,
+					native_amount,
+					WithdrawReasons::all(),
+				);
+			}
+
+			TimeCapsules::<T>::insert(
+				asset_id,
+				TimeCapsule {
+					unlock_block,
+					contents,
+					metadata_hash,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::TimeCapsuleCreated(asset_id, unlock_block));
+			Ok(().into())
+		}
+
+		/// Claim the balances and reveal the sealed metadata of a time capsule, only the asset
+		/// holder can open once the unlock block is reached by providing the metadata matching the
+		/// sealed hash
+		#[pallet::weight(T::WeightInfo::open_capsule(T::MaxBatchTransfer::get(), metadata.len() as u32))]
+		#[transactional]
+		pub fn open_capsule(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			metadata: NftMetadata,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			let capsule = TimeCapsules::<T>::get(asset_id).ok_or(Error::<T>::TimeCapsuleNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= capsule.unlock_block,
+				Error::<T>::TimeCapsuleIsSealed
+			);
+			ensure!(Self::check_nft_ownership(&sender, &asset_id)?, Error::<T>::NoPermission);
+			ensure!(
+				T::Hashing::hash(&metadata) == capsule.metadata_hash,
+				Error::<T>::InvalidTimeCapsuleMetadata
+			);
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let capsule_account = Self::get_time_capsule_account(&asset_id);
+			<T as Config>::Currency::remove_lock(TIMECAPSULE_ID, &capsule_account);
+			for (currency_id, amount) in capsule.contents.iter() {
+				if *currency_id == FungibleTokenId::NativeToken(0) {
+					<T as Config>::Currency::transfer(
+						&capsule_account,
+						&sender,
+						*amount,
+						ExistenceRequirement::AllowDeath,
+					)?;
+				} else {
+					T::MultiCurrency::transfer(*currency_id, &capsule_account, &sender, *amount)?;
+				}
+			}
+
+			let bounded_metadata = metadata
+				.clone()
+				.try_into()
+				.map_err(|_| Error::<T>::ExceedMaximumMetadataLength)?;
+			orml_nft::Tokens::<T>::try_mutate(asset.0, asset.1, |token_info| -> DispatchResult {
+				let token_info = token_info.as_mut().ok_or(Error::<T>::AssetInfoNotFound)?;
+				token_info.metadata = bounded_metadata;
+				Ok(())
+			})?;
+			TimeCapsules::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::<T>::TimeCapsuleOpened(asset_id, sender, metadata));
+			Ok(().into())
+		}
+
+		/// Grant usage rights of an asset to another account until the expiry block. The owner
+		/// cannot replace or cut short a user whose rights are still active, only extend them.
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn set_user(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			user: T::AccountId,
+			expires: T::BlockNumber,
+			survives_transfer: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(expires > now, Error::<T>::InvalidRentalPeriod);
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(sender == asset_info.owner, Error::<T>::NoPermission);
+			// Active usage rights can only be extended for the same user on the same terms
+			if let Some(current) = AssetUsers::<T>::get(asset_id).filter(|current| now < current.expires) {
+				ensure!(
+					current.user == user
+						&& expires >= current.expires
+						&& survives_transfer == current.survives_transfer,
+					Error::<T>::AssetAlreadyRented
+				);
+			}
+
+			AssetUsers::<T>::insert(
+				asset_id,
+				AssetUser {
+					user: user.clone(),
+					expires,
+					survives_transfer,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::AssetUserSet(sender, user, asset_id, expires));
+			Ok(().into())
+		}
+
+		/// Give up the usage rights of an asset before they expire
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn clear_user(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				Self::get_asset_user(asset_id) == Some(sender.clone()),
+				Error::<T>::NotAssetUser
+			);
+
+			AssetUsers::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::<T>::AssetUserCleared(sender, asset_id));
+			Ok(().into())
+		}
+
+		/// Offer an asset for rent, anyone paying `price` in `currency_id` becomes its user for
+		/// `duration` blocks. The offer stays open until cancelled or the asset is transferred.
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn offer_rental(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			duration: T::BlockNumber,
+			currency_id: FungibleTokenId,
+			price: BalanceOf<T>,
+			survives_transfer: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(!duration.is_zero(), Error::<T>::InvalidRentalPeriod);
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(sender == asset_info.owner, Error::<T>::NoPermission);
+
+			RentalOffers::<T>::insert(
+				asset_id,
+				RentalOffer {
+					duration,
+					currency_id,
+					price,
+					survives_transfer,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::RentalOffered(
+				sender,
+				asset_id,
+				duration,
+				currency_id,
+				price,
+			));
+			Ok(().into())
+		}
+
+		/// Withdraw the rental offer of an asset, current usage rights are kept
+		#[pallet::weight(T::WeightInfo::sign_asset(0))]
+		pub fn cancel_rental_offer(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(sender == asset_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				RentalOffers::<T>::contains_key(asset_id),
+				Error::<T>::RentalOfferNotFound
+			);
+
+			RentalOffers::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::<T>::RentalOfferCancelled(sender, asset_id));
+			Ok(().into())
+		}
+
+		/// Pay the rent of an asset offered for rent and become its user
+		#[pallet::weight(T::WeightInfo::transfer())]
+		#[transactional]
+		pub fn rent(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let offer = RentalOffers::<T>::get(asset_id).ok_or(Error::<T>::RentalOfferNotFound)?;
+			let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(sender != asset_info.owner, Error::<T>::CannotRentOwnAsset);
+			ensure!(Self::get_asset_user(asset_id).is_none(), Error::<T>::AssetAlreadyRented);
+
+			T::MultiCurrency::transfer(offer.currency_id, &sender, &asset_info.owner, offer.price)?;
+
+			let expires = <frame_system::Pallet<T>>::block_number().saturating_add(offer.duration);
+			AssetUsers::<T>::insert(
+				asset_id,
+				AssetUser {
+					user: sender.clone(),
+					expires,
+					survives_transfer: offer.survives_transfer,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::AssetRented(
+				sender,
+				asset_id,
+				expires,
+				offer.currency_id,
+				offer.price,
+			));
+			Ok(().into())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+
+			if StorageVersion::get::<Pallet<T>>() < 1 {
+				weight = weight.saturating_add(migrations::migrate_to_v1::<T>());
+				StorageVersion::new(1).put::<Pallet<T>>();
+			}
+
+			if StorageVersion::get::<Pallet<T>>() < 2 {
+				weight = weight.saturating_add(migrations::migrate_to_v2::<T>());
+				StorageVersion::new(2).put::<Pallet<T>>();
+			}
+
+			if StorageVersion::get::<Pallet<T>>() < 3 {
+				weight = weight.saturating_add(migrations::migrate_to_v3::<T>());
+				StorageVersion::new(3).put::<Pallet<T>>();
+			}
+
+			if StorageVersion::get::<Pallet<T>>() < 4 {
+				weight = weight.saturating_add(migrations::migrate_to_v4::<T>());
+				StorageVersion::new(4).put::<Pallet<T>>();
+			}
+
+			weight
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn get_promotion_fund_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account(PROMOTION_FUND_ID)
+	}
+
+	pub fn get_promotion_campaign_account(campaign_id: PromotionCampaignId) -> T::AccountId {
+		T::PalletId::get().into_sub_account((PROMOTION_FUND_ID, campaign_id))
+	}
+
+	/// Pay the rewards of every open campaign the minted class is eligible for, closing the
+	/// campaigns that run out of budget
+	fn reward_promotion_campaigns(minter: &T::AccountId, class_id: ClassIdOf<T>, quantity: u32) -> DispatchResult {
+		if Self::promotion_campaign_count() == 0 {
+			return Ok(());
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let collection_id = ClassDataCollection::<T>::get(class_id);
+		let campaigns: Vec<(PromotionCampaignId, PromotionCampaignOf<T>)> = PromotionCampaigns::<T>::iter()
+			.filter(|(_, campaign)| {
+				now >= campaign.start
+					&& now < campaign.end
+					&& (campaign.eligible_collections.is_empty()
+						|| campaign.eligible_collections.contains(&collection_id))
+			})
+			.collect();
+
+		for (campaign_id, mut campaign) in campaigns {
+			let rewarded = PromotionRewards::<T>::get(campaign_id, minter);
+			let reward = campaign
+				.reward_per_token
+				.saturating_mul(quantity.into())
+				.min(campaign.account_cap.saturating_sub(rewarded))
+				.min(campaign.remaining_budget);
+			if reward.is_zero() {
+				continue;
+			}
+
+			T::MultiCurrency::transfer(
+				campaign.currency_id,
+				&Self::get_promotion_campaign_account(campaign_id),
+				minter,
+				reward,
+			)?;
+			PromotionRewards::<T>::insert(campaign_id, minter, rewarded.saturating_add(reward));
+			campaign.remaining_budget = campaign.remaining_budget.saturating_sub(reward);
+			Self::deposit_event(Event::<T>::PromotionRewardPaid(campaign_id, minter.clone(), reward));
+
+			if campaign.remaining_budget.is_zero() {
+				Self::do_close_promotion_campaign(campaign_id, &campaign)?;
+			} else {
+				PromotionCampaigns::<T>::insert(campaign_id, campaign);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn do_close_promotion_campaign(
+		campaign_id: PromotionCampaignId,
+		campaign: &PromotionCampaignOf<T>,
+	) -> DispatchResult {
+		if !campaign.remaining_budget.is_zero() {
+			T::MultiCurrency::transfer(
+				campaign.currency_id,
+				&Self::get_promotion_campaign_account(campaign_id),
+				&Self::get_promotion_fund_account(),
+				campaign.remaining_budget,
+			)?;
+		}
+
+		PromotionCampaigns::<T>::remove(campaign_id);
+		PromotionRewards::<T>::remove_prefix(campaign_id, None);
+		PromotionCampaignCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+		Self::deposit_event(Event::<T>::PromotionCampaignClosed(
+			campaign_id,
+			campaign.remaining_budget,
+		));
+		Ok(())
+	}
+
+	/// Payload signed by the class owner for a mint voucher, bound to this chain by its genesis
+	/// hash so vouchers can't be replayed on other networks
+	pub fn voucher_signing_payload(voucher: &MintVoucherOf<T>) -> Vec<u8> {
+		let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+		(VOUCHER_SIGNING_DOMAIN, genesis_hash, voucher).encode()
+	}
+
+	pub fn get_class_fund(class_id: &ClassIdOf<T>) -> T::AccountId {
+		T::PalletId::get().into_sub_account(class_id)
+	}
+
+	pub fn get_supporter_reward_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account(SUPPORTER_REWARD_ID)
+	}
+
+	/// Contributions vested at `now`, every contribution vests linearly over
+	/// `SupportVestingPeriod` blocks from the block it was made
+	fn vested_support(fund: &SupportFundOf<T>, now: T::BlockNumber) -> BalanceOf<T> {
+		let period = T::SupportVestingPeriod::get();
+		fund.vesting
+			.iter()
+			.fold(fund.released, |vested, (contribution, vesting_start)| {
+				let elapsed = now.saturating_sub(*vesting_start);
+				if elapsed >= period {
+					vested.saturating_add(*contribution)
+				} else {
+					vested.saturating_add(Perbill::from_rational(elapsed, period).mul_floor(*contribution))
+				}
+			})
+	}
+
+	/// Contributions the class owner can withdraw at `now`
+	pub fn withdrawable_support(fund: &SupportFundOf<T>, now: T::BlockNumber) -> BalanceOf<T> {
+		Self::vested_support(fund, now).saturating_sub(fund.withdrawn)
+	}
+
+	/// Move the fully vested contributions out of the vesting schedules
+	fn release_vested_support(fund: &mut SupportFundOf<T>, now: T::BlockNumber) {
+		let period = T::SupportVestingPeriod::get();
+		let mut released = fund.released;
+		fund.vesting.retain(|(contribution, vesting_start)| {
+			let vested = now.saturating_sub(*vesting_start) >= period;
+			if vested {
+				released = released.saturating_add(*contribution);
+			}
+			!vested
+		});
+		fund.released = released;
+	}
+
+	/// Add a contribution to the fund vesting from `now`, contributions already vesting keep
+	/// their schedule. Once `MaxSupportSchedules` schedules are vesting the contribution is
+	/// merged into the most recent one.
+	fn add_support(fund: &mut SupportFundOf<T>, contribution: BalanceOf<T>, now: T::BlockNumber) {
+		Self::release_vested_support(fund, now);
+		let merge = fund.vesting.len() >= T::MaxSupportSchedules::get() as usize
+			|| fund
+				.vesting
+				.last()
+				.map_or(false, |(_, vesting_start)| *vesting_start == now);
+		match fund.vesting.last_mut() {
+			Some((vesting, _)) if merge => *vesting = vesting.saturating_add(contribution),
+			_ => fund.vesting.push((contribution, now)),
+		}
+	}
+
+	/// Pay the supporters share of a secondary sale royalty from `payer` and accrue it to the
+	/// reward index of the asset in that currency. Supporters are credited pro rata to their
+	/// contribution when they next contribute or claim. Returns the amount paid.
+	pub fn distribute_supporter_royalty(
+		payer: &T::AccountId,
+		asset_id: &AssetId,
+		currency_id: FungibleTokenId,
+		royalty: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let total_contribution = AssetSupportTotals::<T>::get(asset_id);
+		let supporters_share = T::SupporterRoyaltyShare::get().mul_floor(royalty);
+		if total_contribution.is_zero() || supporters_share.is_zero() {
+			return Ok(Zero::zero());
+		}
+
+		let accrued = FixedU128::saturating_from_rational(
+			supporters_share.saturated_into::<u128>(),
+			total_contribution.saturated_into::<u128>(),
+		);
+		let mut indexes = SupporterRewardIndexes::<T>::get(asset_id);
+		if let Some((_, index)) = indexes.iter_mut().find(|(currency, _)| *currency == currency_id) {
+			*index = index.saturating_add(accrued);
+		} else if indexes.try_push((currency_id, accrued)).is_err() {
+			return Ok(Zero::zero());
+		}
+
+		T::MultiCurrency::transfer(
+			currency_id,
+			payer,
+			&Self::get_supporter_reward_account(),
+			supporters_share,
+		)?;
+		SupporterRewardIndexes::<T>::insert(asset_id, indexes);
+
+		Ok(supporters_share)
+	}
+
+	/// Credit a supporter with the rewards accrued on their contribution to the asset since they
+	/// were last credited
+	fn settle_supporter_rewards(asset_id: AssetId, supporter: &T::AccountId, contribution: BalanceOf<T>) {
+		let indexes = SupporterRewardIndexes::<T>::get(asset_id);
+		let paid_indexes = SupporterPaidIndexes::<T>::get(asset_id, supporter);
+		for (currency_id, index) in indexes.iter() {
+			let paid_index = paid_indexes
+				.iter()
+				.find(|(currency, _)| currency == currency_id)
+				.map_or_else(FixedU128::zero, |(_, paid_index)| *paid_index);
+			let reward: BalanceOf<T> = index
+				.saturating_sub(paid_index)
+				.saturating_mul_int(contribution.saturated_into::<u128>())
+				.saturated_into();
+			if !reward.is_zero() {
+				SupporterRewards::<T>::mutate(supporter, currency_id, |balance| {
+					*balance = balance.saturating_add(reward)
+				});
+			}
+		}
+		SupporterPaidIndexes::<T>::insert(asset_id, supporter, indexes);
+	}
 
 	fn do_create_group_collection(name: Vec<u8>, properties: Vec<u8>) -> Result<GroupCollectionId, DispatchError> {
 		let next_group_collection_id =
@@ -732,37 +2477,119 @@ impl<T: Config> Pallet<T> {
 		Ok(next_group_collection_id)
 	}
 
-	fn handle_asset_ownership_transfer(sender: &T::AccountId, to: &T::AccountId, asset_id: AssetId) -> DispatchResult {
-		// Remove asset from sender
-		AssetsByOwner::<T>::try_mutate(&sender, |asset_ids| -> DispatchResult {
-			// Check if the asset_id already in the owner
-			let asset_index = asset_ids.iter().position(|x| *x == asset_id).unwrap();
-			asset_ids.remove(asset_index);
+	/// Check if the account can transfer the asset on behalf of the owner
+	pub fn is_approved_or_operator(
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+		asset_id: AssetId,
+		class_id: ClassIdOf<T>,
+	) -> bool {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let is_active = |expiry: Option<T::BlockNumber>| expiry.map_or(true, |expiry| now < expiry);
+
+		if let Some((approved, expiry)) = AssetApprovals::<T>::get(asset_id) {
+			if &approved == delegate && is_active(expiry) {
+				return true;
+			}
+		}
 
-			Ok(())
-		})?;
+		[Some(class_id), None]
+			.iter()
+			.any(|scope| Operators::<T>::get(owner, (delegate.clone(), *scope)).map_or(false, &is_active))
+	}
+
+	fn ensure_valid_expiry(expiry: Option<T::BlockNumber>) -> DispatchResult {
+		if let Some(expiry) = expiry {
+			ensure!(
+				expiry > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidApprovalExpiry
+			);
+		}
+		Ok(())
+	}
 
-		// Insert asset to recipient
+	/// Account currently allowed to use the asset, usage rights past their expiry are ignored
+	pub fn get_asset_user(asset_id: AssetId) -> Option<T::AccountId> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		AssetUsers::<T>::get(asset_id)
+			.filter(|asset_user| now < asset_user.expires)
+			.map(|asset_user| asset_user.user)
+	}
 
-		if AssetsByOwner::<T>::contains_key(to) {
-			AssetsByOwner::<T>::try_mutate(&to, |asset_ids| -> DispatchResult {
-				// Check if the asset_id already in the owner
-				ensure!(
-					!asset_ids.iter().any(|i| asset_id == *i),
-					Error::<T>::AssetIdAlreadyExist
-				);
-				asset_ids.push(asset_id);
-				Ok(())
-			})?;
-		} else {
-			let mut asset_ids = Vec::<AssetId>::new();
-			asset_ids.push(asset_id);
-			AssetsByOwner::<T>::insert(&to, asset_ids);
+	fn handle_asset_ownership_transfer(sender: &T::AccountId, to: &T::AccountId, asset_id: AssetId) -> DispatchResult {
+		AssetApprovals::<T>::remove(asset_id);
+		RentalOffers::<T>::remove(asset_id);
+		if !AssetUsers::<T>::get(asset_id).map_or(false, |asset_user| asset_user.survives_transfer) {
+			AssetUsers::<T>::remove(asset_id);
 		}
 
+		Self::remove_asset_from_owner(sender, asset_id)?;
+		Self::add_asset_to_owner(to, asset_id)
+	}
+
+	fn add_asset_to_owner(owner: &T::AccountId, asset_id: AssetId) -> DispatchResult {
+		ensure!(
+			!AssetsByOwner::<T>::contains_key(owner, asset_id),
+			Error::<T>::AssetIdAlreadyExist
+		);
+
+		AssetsByOwner::<T>::insert(owner, asset_id, ());
+		AssetsCountByOwner::<T>::mutate(owner, |count| *count = count.saturating_add(1));
+		Ok(())
+	}
+
+	fn remove_asset_from_owner(owner: &T::AccountId, asset_id: AssetId) -> DispatchResult {
+		ensure!(
+			AssetsByOwner::<T>::contains_key(owner, asset_id),
+			Error::<T>::NoPermission
+		);
+
+		AssetsByOwner::<T>::remove(owner, asset_id);
+		AssetsCountByOwner::<T>::mutate_exists(owner, |count| {
+			*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0)
+		});
 		Ok(())
 	}
 
+	/// All assets owned by the account, sorted by asset id
+	pub fn get_assets_by_owner(owner: impl EncodeLike<T::AccountId>) -> Vec<AssetId> {
+		let mut asset_ids: Vec<AssetId> = AssetsByOwner::<T>::iter_key_prefix(owner).collect();
+		asset_ids.sort_unstable();
+		asset_ids
+	}
+
+	/// Assets owned by the account in storage order, optionally filtered by class or group
+	/// collection, starting after the `start` asset and returning at most `limit` assets. The last
+	/// asset of a page is the `start` of the next page
+	pub fn get_assets_by_owner_paginated(
+		owner: &T::AccountId,
+		class_id: Option<ClassIdOf<T>>,
+		group_collection_id: Option<GroupCollectionId>,
+		start: Option<AssetId>,
+		limit: u32,
+	) -> Vec<AssetId> {
+		let owned_assets = match start {
+			Some(start) => {
+				AssetsByOwner::<T>::iter_prefix_from(owner, AssetsByOwner::<T>::hashed_key_for(owner, start))
+			}
+			None => AssetsByOwner::<T>::iter_prefix(owner),
+		};
+
+		owned_assets
+			.map(|(asset_id, _)| asset_id)
+			.filter(|asset_id| match Assets::<T>::get(asset_id) {
+				Some((asset_class_id, _)) => {
+					class_id.map_or(true, |class_id| class_id == asset_class_id)
+						&& group_collection_id.map_or(true, |group_collection_id| {
+							Self::get_class_collection(asset_class_id) == group_collection_id
+						})
+				}
+				None => false,
+			})
+			.take(limit as usize)
+			.collect()
+	}
+
 	pub fn do_transfer(
 		sender: &T::AccountId,
 		to: &T::AccountId,
@@ -776,6 +2603,7 @@ impl<T: Config> Pallet<T> {
 
 		match data.token_type {
 			TokenType::Transferable => {
+				ensure!(!AssetParent::<T>::contains_key(asset_id), Error::<T>::AssetIsEquipped);
 				let check_ownership = Self::check_nft_ownership(&sender, &asset_id)?;
 				ensure!(check_ownership, Error::<T>::NoPermission);
 
@@ -788,15 +2616,35 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Check ownership of an asset, equipped assets are owned by the owner of their root asset
 	pub fn check_nft_ownership(sender: &T::AccountId, asset_id: &AssetId) -> Result<bool, DispatchError> {
-		let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+		let mut current_asset_id = *asset_id;
 
-		let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
-		if sender == &asset_info.owner {
-			return Ok(true);
+		for _ in 0..MAX_NESTING_DEPTH {
+			let asset = Assets::<T>::get(current_asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+
+			let asset_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			if sender == &asset_info.owner {
+				return Ok(true);
+			}
+
+			match AssetParent::<T>::get(current_asset_id) {
+				Some((parent_id, _)) => current_asset_id = parent_id,
+				None => return Ok(false),
+			}
 		}
 
-		return Ok(false);
+		Ok(false)
+	}
+
+	/// Account holding the assets equipped onto a parent asset
+	pub fn get_nesting_account(parent_id: &AssetId) -> T::AccountId {
+		T::PalletId::get().into_sub_account((b"nest", parent_id))
+	}
+
+	/// Account holding the contents of a time capsule
+	pub fn get_time_capsule_account(asset_id: &AssetId) -> T::AccountId {
+		T::PalletId::get().into_sub_account((TIMECAPSULE_ID, asset_id))
 	}
 
 	/// Check if the NFT collection is locked
@@ -820,6 +2668,182 @@ impl<T: Config> Pallet<T> {
 		Ok(asset.1)
 	}
 
+	/// Burn an asset and return the number of tokens left in circulation of its class
+	fn do_burn(owner: &T::AccountId, asset_id: AssetId) -> Result<u64, DispatchError> {
+		ensure!(
+			!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+			Error::<T>::AssetAlreadyInAuction
+		);
+		ensure!(
+			!TimeCapsules::<T>::contains_key(asset_id),
+			Error::<T>::AssetIsTimeCapsule
+		);
+		ensure!(
+			EquippedAssets::<T>::iter_prefix(asset_id).next().is_none(),
+			Error::<T>::AssetHasEquippedAssets
+		);
+		let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+
+		let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		let token_info = NftModule::<T>::tokens(asset.0, asset.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+		let total_supply = class_info.data.total_supply.saturating_sub(One::one());
+
+		NftModule::<T>::burn(owner, asset)?;
+		Assets::<T>::remove(asset_id);
+		Self::remove_asset_from_owner(owner, asset_id)?;
+		AssetApprovals::<T>::remove(asset_id);
+		LastExecution::<T>::remove(asset_id);
+		FrozenTokenMetadata::<T>::remove(asset_id);
+		AssetUsers::<T>::remove(asset_id);
+		RentalOffers::<T>::remove(asset_id);
+		// Supporter records are kept so rewards accrued before the burn can still be claimed
+		Self::update_class_total_supply(&asset.0, total_supply)?;
+
+		// Refund token deposit to the burner
+		let class_fund = Self::get_class_fund(&asset.0);
+		let deposit = token_info.data.deposit;
+		<T as Config>::Currency::unreserve(&class_fund, deposit);
+		<T as Config>::Currency::transfer(&class_fund, owner, deposit, ExistenceRequirement::AllowDeath)?;
+
+		Ok(total_supply)
+	}
+
+	/// Check the account can mint the quantity of tokens and return the class total supply after
+	/// minting
+	fn ensure_can_mint(sender: &T::AccountId, class_id: ClassIdOf<T>, quantity: u32) -> Result<u64, DispatchError> {
+		ensure!(!Self::is_collection_locked(&class_id), Error::<T>::CollectionIsLocked);
+		ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
+		ensure!(
+			quantity <= T::MaxBatchMinting::get(),
+			Error::<T>::ExceedMaximumBatchMinting
+		);
+
+		let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(sender == &class_info.owner, Error::<T>::NoPermission);
+		let total_supply = class_info
+			.data
+			.total_supply
+			.checked_add(quantity.into())
+			.ok_or(Error::<T>::ExceedMaximumSupply)?;
+		if let Some(max_supply) = class_info.data.max_supply {
+			// Burned tokens still count towards the cap so scarcity can't be bypassed by re-minting
+			let minted: u64 = NftModule::<T>::next_token_id(class_id).saturated_into();
+			ensure!(
+				minted.saturating_add(quantity.into()) <= max_supply,
+				Error::<T>::ExceedMaximumSupply
+			);
+		}
+
+		Ok(total_supply)
+	}
+
+	/// Total metadata and attribute bytes of a mint batch, the data length `mint_batch` is
+	/// benchmarked with
+	fn batch_data_len(items: &[MintBatchItem<T::AccountId>]) -> u32 {
+		items.iter().fold(0u32, |total, (metadata, attributes, _)| {
+			attributes
+				.iter()
+				.fold(total.saturating_add(metadata.len() as u32), |total, (k, v)| {
+					total.saturating_add(k.len().saturating_add(v.len()) as u32)
+				})
+		})
+	}
+
+	/// Mint a token to the owner and register it as a new asset
+	fn mint_asset(
+		owner: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		metadata: NftMetadata,
+		data: NftAssetData<BalanceOf<T>>,
+	) -> Result<(AssetId, TokenIdOf<T>), DispatchError> {
+		let asset_id = NextAssetId::<T>::try_mutate(|id| -> Result<AssetId, DispatchError> {
+			let current_id = *id;
+			*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAssetId)?;
+
+			Ok(current_id)
+		})?;
+
+		Self::add_asset_to_owner(owner, asset_id)?;
+
+		let token_id = NftModule::<T>::mint(owner, class_id, metadata, data)?;
+		Assets::<T>::insert(asset_id, (class_id, token_id));
+
+		Ok((asset_id, token_id))
+	}
+
+	/// Update the number of tokens in circulation of the class
+	fn update_class_total_supply(class_id: &ClassIdOf<T>, total_supply: u64) -> DispatchResult {
+		orml_nft::Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+			let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+			class_info.data.total_supply = total_supply;
+			Ok(())
+		})
+	}
+
+	/// Check if token metadata and attributes can no longer be changed
+	pub fn is_metadata_frozen(class_id: &ClassIdOf<T>, asset_id: &AssetId) -> bool {
+		FrozenClassMetadata::<T>::contains_key(class_id) || FrozenTokenMetadata::<T>::contains_key(asset_id)
+	}
+
+	/// Ensure the account is class owner or metadata updater and the token metadata is not frozen
+	fn ensure_can_update_metadata(
+		sender: &T::AccountId,
+		asset_id: AssetId,
+	) -> Result<(ClassIdOf<T>, TokenIdOf<T>), DispatchError> {
+		let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+		ensure!(!Self::is_collection_locked(&asset.0), Error::<T>::CollectionIsLocked);
+		ensure!(
+			!Self::is_metadata_frozen(&asset.0, &asset_id),
+			Error::<T>::MetadataIsFrozen
+		);
+
+		let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(
+			*sender == class_info.owner || Self::get_metadata_updater(asset.0).as_ref() == Some(sender),
+			Error::<T>::NoPermission
+		);
+
+		Ok(asset)
+	}
+
+	/// Update token attributes and settle the difference in deposit with the class fund.
+	/// Extra deposit is paid by the updater, surplus deposit is refunded to the class owner.
+	fn do_update_token_attributes(
+		sender: &T::AccountId,
+		asset_id: AssetId,
+		update: impl FnOnce(&mut Attributes) -> DispatchResult,
+	) -> DispatchResult {
+		let asset = Self::ensure_can_update_metadata(sender, asset_id)?;
+		let class_info = NftModule::<T>::classes(asset.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		let class_fund = Self::get_class_fund(&asset.0);
+
+		orml_nft::Tokens::<T>::try_mutate(asset.0, asset.1, |token_info| -> DispatchResult {
+			let token_info = token_info.as_mut().ok_or(Error::<T>::AssetInfoNotFound)?;
+			update(&mut token_info.data.attributes)?;
+
+			let old_deposit = token_info.data.deposit;
+			let new_deposit = Self::calculate_fee_deposit(&token_info.data.attributes)?;
+
+			if new_deposit > old_deposit {
+				let extra_deposit = new_deposit.saturating_sub(old_deposit);
+				<T as Config>::Currency::transfer(sender, &class_fund, extra_deposit, ExistenceRequirement::KeepAlive)?;
+				<T as Config>::Currency::reserve(&class_fund, extra_deposit)?;
+			} else if new_deposit < old_deposit {
+				let surplus_deposit = old_deposit.saturating_sub(new_deposit);
+				<T as Config>::Currency::unreserve(&class_fund, surplus_deposit);
+				<T as Config>::Currency::transfer(
+					&class_fund,
+					&class_info.owner,
+					surplus_deposit,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+
+			token_info.data.deposit = new_deposit;
+			Ok(())
+		})
+	}
+
 	/// Calculate deposit fee
 	fn calculate_fee_deposit(attributes: &Attributes) -> Result<BalanceOf<T>, DispatchError> {
 		// Accumulate lens of attributes length
@@ -837,3 +2861,14 @@ impl<T: Config> Pallet<T> {
 		Ok(deposit_required)
 	}
 }
+
+impl<T: Config> NFTTrait<T::AccountId> for Pallet<T> {
+	fn check_ownership(who: &T::AccountId, asset_id: &AssetId) -> Result<bool, DispatchError> {
+		Self::check_nft_ownership(who, asset_id)
+	}
+
+	fn transfer_nft(from: &T::AccountId, to: &T::AccountId, asset_id: &AssetId) -> DispatchResult {
+		Self::do_transfer(from, to, *asset_id)?;
+		Ok(())
+	}
+}
@@ -0,0 +1,153 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{
+	storage::migration::{remove_storage_prefix, storage_key_iter},
+	traits::{Get, PalletInfoAccess},
+	weights::Weight,
+	Blake2_128Concat,
+};
+use orml_nft::{ClassInfo, ClassMetadataOf};
+
+use super::*;
+
+/// Class data before `max_supply` was introduced.
+#[derive(Decode)]
+struct NftClassDataV0<Balance> {
+	deposit: Balance,
+	attributes: Attributes,
+	token_type: TokenType,
+	collection_type: CollectionType,
+	/// Out of sync with the issuance, replaced by `total_issuance` on migration
+	_total_supply: u64,
+	initial_supply: u64,
+}
+
+/// Add `max_supply` to every class and sync `total_supply` with the live issuance.
+pub fn migrate_to_v1<T: Config>() -> Weight {
+	let mut translated: u64 = 0;
+
+	orml_nft::Classes::<T>::translate::<
+		ClassInfo<TokenIdOf<T>, T::AccountId, NftClassDataV0<BalanceOf<T>>, ClassMetadataOf<T>>,
+		_,
+	>(|_class_id, class_info| {
+		translated = translated.saturating_add(1);
+		let old_data = class_info.data;
+
+		Some(ClassInfo {
+			metadata: class_info.metadata,
+			total_issuance: class_info.total_issuance,
+			owner: class_info.owner,
+			data: NftClassData {
+				deposit: old_data.deposit,
+				attributes: old_data.attributes,
+				token_type: old_data.token_type,
+				collection_type: old_data.collection_type,
+				total_supply: class_info.total_issuance.saturated_into(),
+				initial_supply: old_data.initial_supply,
+				max_supply: None,
+			},
+		})
+	});
+
+	T::DbWeight::get().reads_writes(translated, translated)
+}
+
+/// Replace the `AssetsByOwner` vector map with a double map index and per-owner asset count.
+pub fn migrate_to_v2<T: Config>() -> Weight {
+	let pallet_prefix = <Pallet<T> as PalletInfoAccess>::name();
+	let old_assets_by_owner: Vec<(T::AccountId, Vec<AssetId>)> =
+		storage_key_iter::<T::AccountId, Vec<AssetId>, Blake2_128Concat>(pallet_prefix.as_bytes(), b"AssetsByOwner")
+			.drain()
+			.collect();
+
+	let mut reads: u64 = 0;
+	let mut writes: u64 = 0;
+	for (owner, asset_ids) in old_assets_by_owner {
+		reads = reads.saturating_add(1);
+		writes = writes.saturating_add(asset_ids.len() as u64).saturating_add(2);
+
+		for asset_id in asset_ids.iter() {
+			AssetsByOwner::<T>::insert(&owner, asset_id, ());
+		}
+		if !asset_ids.is_empty() {
+			AssetsCountByOwner::<T>::insert(&owner, asset_ids.len() as u32);
+		}
+	}
+
+	T::DbWeight::get().reads_writes(reads, writes)
+}
+
+/// Deduplicate and bound the supporters of every asset. Contributions were not recorded before,
+/// so migrated supporters start with a zero contribution and only earn rewards from their next
+/// contribution onwards. The contributions already reserved in every class fund, the reserved
+/// balance left after the class and token deposits, are released to the class owner.
+pub fn migrate_to_v3<T: Config>() -> Weight {
+	let mut translated: u64 = 0;
+
+	AssetSupporters::<T>::translate::<Vec<T::AccountId>, _>(|_asset_id, old_supporters| {
+		translated = translated.saturating_add(1);
+
+		let mut supporters: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+		for supporter in old_supporters {
+			if !supporters.iter().any(|(who, _)| who == &supporter) {
+				supporters.push((supporter, Zero::zero()));
+			}
+		}
+		supporters.truncate(T::MaxSupporters::get() as usize);
+
+		supporters.try_into().ok()
+	});
+
+	let mut reads: u64 = 0;
+	let mut writes: u64 = 0;
+	for (class_id, class_info) in orml_nft::Classes::<T>::iter() {
+		reads = reads.saturating_add(2);
+
+		let deposits = orml_nft::Tokens::<T>::iter_prefix_values(class_id).fold(
+			class_info.data.deposit,
+			|deposits, token_info| {
+				reads = reads.saturating_add(1);
+				deposits.saturating_add(token_info.data.deposit)
+			},
+		);
+		let contributions =
+			<T as Config>::Currency::reserved_balance(&Pallet::<T>::get_class_fund(&class_id)).saturating_sub(deposits);
+
+		if !contributions.is_zero() {
+			writes = writes.saturating_add(1);
+			ClassSupportFunds::<T>::insert(
+				class_id,
+				SupportFund {
+					vesting: Vec::new(),
+					released: contributions,
+					withdrawn: Zero::zero(),
+				},
+			);
+		}
+	}
+
+	T::DbWeight::get().reads_writes(translated.saturating_add(reads), translated.saturating_add(writes))
+}
+
+/// Remove the global promotion switch replaced by promotion campaigns.
+pub fn migrate_to_v4<T: Config>() -> Weight {
+	let pallet_prefix = <Pallet<T> as PalletInfoAccess>::name();
+	remove_storage_prefix(pallet_prefix.as_bytes(), b"PromotionEnabled", b"");
+
+	T::DbWeight::get().writes(1)
+}
@@ -1,13 +1,16 @@
 #![cfg(test)]
 
+use std::cell::RefCell;
+
 use codec::{Decode, Encode};
-use frame_support::traits::{EqualPrivilegeOnly, Nothing};
+use frame_support::traits::{EqualPrivilegeOnly, InstanceFilter, Nothing};
 use frame_support::{construct_runtime, parameter_types};
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::testing::Header;
-use sp_runtime::traits::IdentityLookup;
+use sp_runtime::traits::{IdentifyAccount, IdentityLookup, Lazy, Verify};
+use sp_runtime::Perbill;
 
 use auction_manager::{Auction, AuctionInfo, AuctionType, ListingLevel};
 use primitives::{Amount, CurrencyId, FungibleTokenId, ItemId};
@@ -118,6 +121,14 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 
 	fn remove_auction(_id: u64, _item_id: ItemId) {}
 
+	fn set_reserve_price(_id: u64, _reserve_price: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn place_bid(_who: u128, _id: u64, _value: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn auction_bid_handler(
 		_now: u64,
 		_id: u64,
@@ -127,22 +138,6 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 		Ok(())
 	}
 
-	fn local_auction_bid_handler(
-		_: BlockNumber,
-		_: u64,
-		_: (
-			AccountId,
-			<Self as auction_manager::Auction<AccountId, BlockNumber>>::Balance,
-		),
-		_: std::option::Option<(
-			AccountId,
-			<Self as auction_manager::Auction<AccountId, BlockNumber>>::Balance,
-		)>,
-		_: FungibleTokenId,
-	) -> Result<(), sp_runtime::DispatchError> {
-		Ok(())
-	}
-
 	fn collect_royalty_fee(
 		_high_bid_price: &Self::Balance,
 		_high_bidder: &u128,
@@ -153,9 +148,17 @@ impl Auction<AccountId, BlockNumber> for MockAuctionManager {
 	}
 }
 
+thread_local! {
+	static ITEMS_IN_AUCTION: RefCell<Vec<ItemId>> = RefCell::new(Vec::new());
+}
+
+pub fn set_item_in_auction(item_id: ItemId) {
+	ITEMS_IN_AUCTION.with(|items| items.borrow_mut().push(item_id));
+}
+
 impl CheckAuctionItemHandler for MockAuctionManager {
-	fn check_item_in_auction(_item_id: ItemId) -> bool {
-		return false;
+	fn check_item_in_auction(item_id: ItemId) -> bool {
+		ITEMS_IN_AUCTION.with(|items| items.borrow().contains(&item_id))
 	}
 }
 
@@ -165,7 +168,13 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 10;
 	pub MaxMetadata: u32 = 10;
-	pub PromotionIncentive: Balance = 1;
+	pub MaxPromotionCampaigns: u32 = 2;
+	pub MaxSupporters: u32 = 3;
+	pub SupportVestingPeriod: BlockNumber = 10;
+	pub MaxSupportSchedules: u32 = 2;
+	pub MinimumSupportContribution: Balance = 10;
+	pub SupporterRoyaltyShare: Perbill = Perbill::from_percent(50);
+	pub MaxSupporterRewardCurrencies: u32 = 2;
 	pub const MetaverseTreasuryPalletId: PalletId = PalletId(*b"bit/trsy");
 	pub TreasuryModuleAccount: AccountId = MetaverseTreasuryPalletId::get().into_account();
 }
@@ -211,6 +220,29 @@ impl pallet_scheduler::Config for Runtime {
 	type WeightInfo = ();
 }
 
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ExecutableCallFilter {
+	Nothing,
+	JustBalances,
+	Any,
+}
+
+impl Default for ExecutableCallFilter {
+	fn default() -> Self {
+		Self::Nothing
+	}
+}
+
+impl InstanceFilter<Call> for ExecutableCallFilter {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			ExecutableCallFilter::Nothing => false,
+			ExecutableCallFilter::JustBalances => matches!(c, Call::Balances(..)),
+			ExecutableCallFilter::Any => true,
+		}
+	}
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
@@ -221,9 +253,41 @@ impl Config for Runtime {
 	type MaxBatchMinting = MaxBatchMinting;
 	type MaxMetadata = MaxMetadata;
 	type MultiCurrency = Currencies;
-	type MiningResourceId = MiningCurrencyId;
-	type PromotionIncentive = PromotionIncentive;
+	type MaxPromotionCampaigns = MaxPromotionCampaigns;
 	type DataDepositPerByte = MetadataDataDepositPerByte;
+	type ExecutableCall = Call;
+	type ExecutableCallFilter = ExecutableCallFilter;
+	type OffchainSignature = MockSignature;
+	type OffchainPublic = MockSigner;
+	type MaxSupporters = MaxSupporters;
+	type SupportVestingPeriod = SupportVestingPeriod;
+	type MaxSupportSchedules = MaxSupportSchedules;
+	type MinimumSupportContribution = MinimumSupportContribution;
+	type SupporterRoyaltyShare = SupporterRoyaltyShare;
+	type MaxSupporterRewardCurrencies = MaxSupporterRewardCurrencies;
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MockSigner(pub AccountId);
+
+impl IdentifyAccount for MockSigner {
+	type AccountId = AccountId;
+
+	fn into_account(self) -> AccountId {
+		self.0
+	}
+}
+
+/// Signature valid for the signed message and signer account it carries
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MockSignature(pub AccountId, pub Vec<u8>);
+
+impl Verify for MockSignature {
+	type Signer = MockSigner;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+		&self.0 == signer && self.1 == msg.get()
+	}
 }
 
 parameter_types! {
@@ -274,11 +338,13 @@ impl ExtBuilder {
 			.unwrap();
 
 		pallet_balances::GenesisConfig::<Runtime> {
-			balances: vec![(ALICE, 100000)],
+			balances: vec![(ALICE, 100000), (BOB, 1000)],
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		ITEMS_IN_AUCTION.with(|items| items.borrow_mut().clear());
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
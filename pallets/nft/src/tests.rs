@@ -1,11 +1,11 @@
 use frame_support::{assert_noop, assert_ok};
 use orml_nft::Pallet as NftModule;
 use orml_traits::MultiCurrency;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash as HashT};
 use sp_std::default::Default;
 
 use mock::*;
-use primitives::{Balance, FungibleTokenId};
+use primitives::{Balance, FungibleTokenId, ItemId};
 
 #[cfg(test)]
 use super::*;
@@ -33,7 +33,7 @@ fn test_attributes(x: u8) -> Attributes {
 }
 
 fn mining_resource_id() -> FungibleTokenId {
-	<Runtime as Config>::MiningResourceId::get()
+	MiningCurrencyId::get()
 }
 
 fn init_test_nft(owner: Origin) {
@@ -45,6 +45,7 @@ fn init_test_nft(owner: Origin) {
 		COLLECTION_ID,
 		TokenType::Transferable,
 		CollectionType::Collectable,
+		None,
 	));
 	assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
 }
@@ -58,36 +59,11 @@ fn init_bound_to_address_nft(owner: Origin) {
 		COLLECTION_ID,
 		TokenType::Transferable,
 		CollectionType::Collectable,
+		None,
 	));
 	assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
 }
 
-#[test]
-fn enable_promotion_should_work() {
-	ExtBuilder::default().build().execute_with(|| {
-		let origin = Origin::root();
-		assert_ok!(Nft::enable_promotion(origin, true));
-
-		assert_eq!(Nft::get_promotion_enabled(), true);
-
-		let event = mock::Event::Nft(crate::Event::PromotionEnabled(true));
-		assert_eq!(last_event(), event);
-	});
-}
-
-#[test]
-fn disable_promotion_should_work() {
-	ExtBuilder::default().build().execute_with(|| {
-		let origin = Origin::root();
-		assert_ok!(Nft::enable_promotion(origin, false));
-
-		assert_eq!(Nft::get_promotion_enabled(), false);
-
-		let event = mock::Event::Nft(crate::Event::PromotionEnabled(false));
-		assert_eq!(last_event(), event);
-	});
-}
-
 #[test]
 fn create_group_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -129,6 +105,7 @@ fn create_class_should_work() {
 			COLLECTION_ID,
 			TokenType::Transferable,
 			CollectionType::Collectable,
+			None,
 		));
 		let class_deposit = <Runtime as Config>::DataDepositPerByte::get() * 4; // Test 4 bytes
 		assert_eq!(Nft::get_class_collection(0), 0);
@@ -141,6 +118,7 @@ fn create_class_should_work() {
 				collection_type: CollectionType::Collectable,
 				total_supply: Default::default(),
 				initial_supply: Default::default(),
+				max_supply: None,
 				attributes: test_attributes(1)
 			}
 		);
@@ -156,7 +134,6 @@ fn create_class_should_work() {
 fn mint_asset_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		let origin = Origin::signed(ALICE);
-		assert_ok!(Nft::enable_promotion(Origin::root(), true));
 		init_test_nft(origin.clone());
 
 		// deposit 8 as 4 bytes for class deposit and 4 bytes for nft deposit
@@ -165,15 +142,12 @@ fn mint_asset_should_work() {
 		assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0]);
 		assert_eq!(Nft::get_asset(0), Some((CLASS_ID, TOKEN_ID)));
 
-		let event = mock::Event::Nft(crate::Event::NewNftMinted(0, 0, ALICE, CLASS_ID, 1, 0));
+		let event = mock::Event::Nft(crate::Event::NewNftMinted(0, 0, ALICE, CLASS_ID, 1, 0, 1));
 		assert_eq!(last_event(), event);
 
 		// mint two assets
 		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 2));
 
-		// bit balance should be 2 (minted 2 NFT)
-		assert_eq!(free_bit_balance(&ALICE), 2);
-
 		assert_eq!(Nft::next_asset_id(), 3);
 		assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0, 1, 2]);
 		assert_eq!(Nft::get_asset(1), Some((CLASS_ID, 1)));
@@ -181,27 +155,155 @@ fn mint_asset_should_work() {
 	})
 }
 
+fn fund_promotion(amount: Balance) {
+	assert_ok!(<Runtime as Config>::MultiCurrency::deposit(
+		mining_resource_id(),
+		&Nft::get_promotion_fund_account(),
+		amount
+	));
+}
+
+fn create_promotion_campaign(budget: Balance, account_cap: Balance, eligible_collections: Vec<u64>) {
+	assert_ok!(Nft::create_promotion_campaign(
+		Origin::root(),
+		1,
+		10,
+		mining_resource_id(),
+		2,
+		budget,
+		account_cap,
+		eligible_collections
+	));
+}
+
+#[test]
+fn create_promotion_campaign_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		fund_promotion(100);
+
+		assert_noop!(
+			Nft::create_promotion_campaign(Origin::signed(ALICE), 1, 10, mining_resource_id(), 2, 10, 10, vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			Nft::create_promotion_campaign(Origin::root(), 10, 10, mining_resource_id(), 2, 10, 10, vec![]),
+			Error::<Runtime>::InvalidPromotionCampaign
+		);
+		assert_noop!(
+			Nft::create_promotion_campaign(Origin::root(), 1, 10, mining_resource_id(), 2, 10, 10, vec![5]),
+			Error::<Runtime>::CollectionIsNotExist
+		);
+
+		create_promotion_campaign(60, 10, vec![]);
+		assert_eq!(last_event(), Event::Nft(crate::Event::PromotionCampaignCreated(0, 60)));
+		assert_eq!(free_bit_balance(&Nft::get_promotion_fund_account()), 40);
+		assert_eq!(free_bit_balance(&Nft::get_promotion_campaign_account(0)), 60);
+
+		create_promotion_campaign(40, 10, vec![]);
+		// Promotion fund is empty
+		assert!(Nft::create_promotion_campaign(Origin::root(), 1, 10, mining_resource_id(), 2, 1, 1, vec![]).is_err());
+		fund_promotion(10);
+		assert_noop!(
+			Nft::create_promotion_campaign(Origin::root(), 1, 10, mining_resource_id(), 2, 10, 10, vec![]),
+			Error::<Runtime>::ExceedMaximumPromotionCampaigns
+		);
+	})
+}
+
 #[test]
-fn mint_asset_with_promotion_enabled_should_work() {
+fn mint_asset_with_promotion_campaign_should_pay_rewards() {
 	ExtBuilder::default().build().execute_with(|| {
 		let origin = Origin::signed(ALICE);
-		assert_ok!(Nft::enable_promotion(Origin::root(), true));
+		fund_promotion(100);
+		create_promotion_campaign(100, 7, vec![COLLECTION_ID]);
+
+		// 2 per token minted
 		init_test_nft(origin.clone());
+		assert_eq!(free_bit_balance(&ALICE), 2);
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::NewNftMinted(0, 0, ALICE, CLASS_ID, 1, 0, 1))
+		);
+
+		// Rewards are capped per account
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 3));
+		assert_eq!(free_bit_balance(&ALICE), 7);
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+		assert_eq!(free_bit_balance(&ALICE), 7);
+		assert_eq!(Nft::get_promotion_reward(0, ALICE), 7);
+		assert_eq!(Nft::get_promotion_campaign(0).unwrap().remaining_budget, 93);
+
+		// No rewards once the campaign has ended
+		System::set_block_number(10);
+		assert_ok!(Nft::create_class(
+			Origin::signed(BOB),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			None,
+		));
+		assert_ok!(Nft::mint(Origin::signed(BOB), 1, vec![1], test_attributes(1), 1));
+		assert_eq!(free_bit_balance(&BOB), 0);
 
-		// bit balance should be 1 (minted 1 NFT)
-		assert_eq!(free_bit_balance(&ALICE), 1);
+		assert_ok!(Nft::close_promotion_campaign(Origin::signed(BOB), 0));
+		assert_eq!(last_event(), Event::Nft(crate::Event::PromotionCampaignClosed(0, 93)));
+		assert_eq!(free_bit_balance(&Nft::get_promotion_fund_account()), 93);
+		assert_eq!(Nft::get_promotion_campaign(0), None);
 	})
 }
 
 #[test]
-fn mint_asset_with_promotion_disabled_should_work() {
+fn promotion_campaign_should_close_when_budget_runs_out() {
 	ExtBuilder::default().build().execute_with(|| {
 		let origin = Origin::signed(ALICE);
-		assert_ok!(Nft::enable_promotion(Origin::root(), false));
+		fund_promotion(100);
+		create_promotion_campaign(5, 100, vec![]);
+
+		assert_noop!(
+			Nft::close_promotion_campaign(Origin::signed(BOB), 0),
+			Error::<Runtime>::PromotionCampaignNotEnded
+		);
+
 		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 2));
+		assert_eq!(free_bit_balance(&ALICE), 5);
+		assert_eq!(Nft::get_promotion_campaign(0), None);
+		assert_eq!(Nft::promotion_campaign_count(), 0);
+		assert_eq!(Nft::get_promotion_reward(0, ALICE), 0);
+	})
+}
+
+#[test]
+fn mint_should_revert_when_promotion_reward_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		fund_promotion(100);
+		create_promotion_campaign(5, 100, vec![]);
+		assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1],));
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			None,
+		));
+		assert_ok!(<Runtime as Config>::MultiCurrency::withdraw(
+			mining_resource_id(),
+			&Nft::get_promotion_campaign_account(0),
+			5
+		));
 
-		// bit balance should be 1 (minted 1 NFT)
-		assert_eq!(free_bit_balance(&ALICE), 0);
+		assert!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1).is_err());
+		assert_eq!(Nft::next_asset_id(), 0);
+		assert_eq!(Nft::get_assets_count_by_owner(ALICE), 0);
+		assert_eq!(
+			reserved_balance(&class_id_account()),
+			NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.deposit
+		);
 	})
 }
 
@@ -218,6 +320,7 @@ fn mint_asset_should_fail() {
 			COLLECTION_ID,
 			TokenType::Transferable,
 			CollectionType::Collectable,
+			None,
 		));
 		assert_noop!(
 			Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 0),
@@ -246,6 +349,7 @@ fn mint_exceed_max_batch_should_fail() {
 			COLLECTION_ID,
 			TokenType::Transferable,
 			CollectionType::Collectable,
+			None,
 		));
 		assert_noop!(
 			Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 20),
@@ -254,6 +358,68 @@ fn mint_exceed_max_batch_should_fail() {
 	})
 }
 
+#[test]
+fn mint_and_burn_should_track_total_supply() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 2));
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.total_supply, 3);
+
+		let event = mock::Event::Nft(crate::Event::NewNftMinted(1, 2, ALICE, CLASS_ID, 2, 2, 3));
+		assert_eq!(last_event(), event);
+
+		assert_ok!(Nft::burn(origin, 1));
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.total_supply, 2);
+	})
+}
+
+#[test]
+fn mint_exceed_max_supply_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+		assert_noop!(
+			Nft::create_class(
+				origin.clone(),
+				vec![1],
+				test_attributes(1),
+				COLLECTION_ID,
+				TokenType::Transferable,
+				CollectionType::Collectable,
+				Some(0),
+			),
+			Error::<Runtime>::InvalidMaxSupply
+		);
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Some(2),
+		));
+		assert_noop!(
+			Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 3),
+			Error::<Runtime>::ExceedMaximumSupply
+		);
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 2));
+		assert_noop!(
+			Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1),
+			Error::<Runtime>::ExceedMaximumSupply
+		);
+
+		// burned tokens still count towards max supply
+		assert_ok!(Nft::burn(origin.clone(), 0));
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.total_supply, 1);
+		assert_noop!(
+			Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1),
+			Error::<Runtime>::ExceedMaximumSupply
+		);
+	})
+}
+
 #[test]
 fn transfer_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -271,7 +437,7 @@ fn burn_nft_should_work() {
 		let origin = Origin::signed(ALICE);
 		init_test_nft(origin.clone());
 		assert_ok!(Nft::burn(origin, 0));
-		let event = mock::Event::Nft(crate::Event::BurnedNft(0));
+		let event = mock::Event::Nft(crate::Event::BurnedNft(0, 0));
 		assert_eq!(Nft::get_asset(0), None);
 		assert_eq!(last_event(), event);
 	})
@@ -289,6 +455,7 @@ fn transfer_batch_should_work() {
 			COLLECTION_ID,
 			TokenType::Transferable,
 			CollectionType::Collectable,
+			None,
 		));
 		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 4));
 		assert_ok!(Nft::transfer_batch(origin, vec![(BOB, 0), (BOB, 1)]));
@@ -309,6 +476,7 @@ fn transfer_batch_exceed_length_should_fail() {
 			COLLECTION_ID,
 			TokenType::Transferable,
 			CollectionType::Collectable,
+			None,
 		));
 		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 4));
 		assert_noop!(
@@ -330,6 +498,7 @@ fn transfer_batch_should_fail() {
 			COLLECTION_ID,
 			TokenType::Transferable,
 			CollectionType::Collectable,
+			None,
 		));
 		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 1));
 		assert_noop!(
@@ -339,6 +508,36 @@ fn transfer_batch_should_fail() {
 	})
 }
 
+#[test]
+fn transfer_batch_should_fail_if_asset_in_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+		set_item_in_auction(ItemId::NFT(1));
+
+		// Whole batch is reverted
+		assert_noop!(
+			Nft::transfer_batch(origin, vec![(BOB, 0), (BOB, 1)]),
+			Error::<Runtime>::AssetAlreadyInAuction
+		);
+	})
+}
+
+#[test]
+fn transfer_batch_should_fail_if_collection_locked() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::force_lock_collection(Origin::root(), CLASS_ID));
+
+		assert_noop!(
+			Nft::transfer_batch(origin, vec![(BOB, 0)]),
+			Error::<Runtime>::CollectionIsLocked
+		);
+	})
+}
+
 #[test]
 fn do_create_group_collection_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -388,6 +587,7 @@ fn do_transfer_should_fail() {
 			COLLECTION_ID,
 			TokenType::BoundToAddress,
 			CollectionType::Collectable,
+			None,
 		));
 		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 1));
 
@@ -414,3 +614,1238 @@ fn do_check_nft_ownership_should_fail() {
 		);
 	})
 }
+
+#[test]
+fn set_token_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_ok!(Nft::set_token_metadata(origin.clone(), 0, vec![2, 3]));
+		assert_eq!(
+			NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID)
+				.unwrap()
+				.metadata
+				.to_vec(),
+			vec![2, 3]
+		);
+		let event = mock::Event::Nft(crate::Event::TokenMetadataUpdated(0));
+		assert_eq!(last_event(), event);
+
+		assert_noop!(
+			Nft::set_token_metadata(Origin::signed(BOB), 0, vec![4]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::set_metadata_updater(Origin::signed(BOB), CLASS_ID, Some(BOB)),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(Nft::set_metadata_updater(origin.clone(), CLASS_ID, Some(BOB)));
+		assert_ok!(Nft::set_token_metadata(Origin::signed(BOB), 0, vec![4]));
+		assert_eq!(
+			NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID)
+				.unwrap()
+				.metadata
+				.to_vec(),
+			vec![4]
+		);
+
+		assert_ok!(Nft::set_metadata_updater(origin.clone(), CLASS_ID, None));
+		assert_noop!(
+			Nft::set_token_metadata(Origin::signed(BOB), 0, vec![5]),
+			Error::<Runtime>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn set_and_remove_token_attribute_should_recalculate_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		// 4 bytes for class deposit and 4 bytes for nft deposit
+		assert_eq!(reserved_balance(&class_id_account()), 8);
+
+		assert_ok!(Nft::set_token_attribute(origin.clone(), 0, vec![9], vec![9, 9]));
+		let token_data = NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().data;
+		assert_eq!(token_data.attributes.get(&vec![9]), Some(&vec![9, 9]));
+		assert_eq!(token_data.deposit, 7);
+		assert_eq!(reserved_balance(&class_id_account()), 11);
+		let event = mock::Event::Nft(crate::Event::TokenAttributeSet(0, vec![9], vec![9, 9]));
+		assert_eq!(last_event(), event);
+
+		let balance_before_removal = free_native_balance(ALICE);
+		assert_ok!(Nft::remove_token_attribute(origin.clone(), 0, vec![9]));
+		assert_eq!(
+			NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().data.deposit,
+			4
+		);
+		assert_eq!(reserved_balance(&class_id_account()), 8);
+		assert_eq!(free_native_balance(ALICE), balance_before_removal + 3);
+
+		assert_noop!(
+			Nft::remove_token_attribute(origin.clone(), 0, vec![9]),
+			Error::<Runtime>::AttributeNotFound
+		);
+		assert_noop!(
+			Nft::set_token_attribute(origin.clone(), 0, vec![9], vec![9; 10]),
+			Error::<Runtime>::ExceedMaximumMetadataLength
+		);
+	})
+}
+
+#[test]
+fn frozen_metadata_should_not_be_updated() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+
+		assert_noop!(
+			Nft::freeze_token_metadata(Origin::signed(BOB), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(Nft::freeze_token_metadata(origin.clone(), 0));
+		let event = mock::Event::Nft(crate::Event::TokenMetadataFrozen(0));
+		assert_eq!(last_event(), event);
+		assert_noop!(
+			Nft::freeze_token_metadata(origin.clone(), 0),
+			Error::<Runtime>::MetadataAlreadyFrozen
+		);
+		assert_noop!(
+			Nft::set_token_metadata(origin.clone(), 0, vec![2]),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+		assert_noop!(
+			Nft::set_token_attribute(origin.clone(), 0, vec![9], vec![9]),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+		assert_ok!(Nft::set_token_metadata(origin.clone(), 1, vec![2]));
+
+		assert_ok!(Nft::freeze_class_metadata(origin.clone(), CLASS_ID));
+		assert_noop!(
+			Nft::set_token_metadata(origin.clone(), 1, vec![3]),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+		assert_noop!(
+			Nft::freeze_class_metadata(origin.clone(), CLASS_ID),
+			Error::<Runtime>::MetadataAlreadyFrozen
+		);
+	})
+}
+
+fn init_wearable_nft(owner: Origin) {
+	init_test_nft(owner.clone());
+	assert_ok!(Nft::create_class(
+		owner.clone(),
+		vec![1],
+		test_attributes(1),
+		COLLECTION_ID,
+		TokenType::Transferable,
+		CollectionType::Wearable,
+		None,
+	));
+	assert_ok!(Nft::mint(owner.clone(), 1, vec![1], test_attributes(1), 2));
+}
+
+#[test]
+fn equip_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_wearable_nft(origin.clone());
+
+		assert_ok!(Nft::equip(origin.clone(), 1, 0, b"hat".to_vec()));
+		let event = mock::Event::Nft(crate::Event::AssetEquipped(1, 0, b"hat".to_vec()));
+		assert_eq!(last_event(), event);
+		assert_eq!(Nft::get_equipped_asset(0, b"hat".to_vec()), Some(1));
+		assert_eq!(Nft::get_asset_parent(1), Some((0, b"hat".to_vec())));
+		assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0, 2]);
+		assert_ok!(Nft::check_nft_ownership(&ALICE, &1), true);
+
+		// equipped asset moves with the parent
+		assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+		assert_ok!(Nft::check_nft_ownership(&BOB, &1), true);
+		assert_ok!(Nft::check_nft_ownership(&ALICE, &1), false);
+
+		// equipped asset is locked
+		assert_noop!(
+			Nft::transfer(Origin::signed(BOB), ALICE, 1),
+			Error::<Runtime>::AssetIsEquipped
+		);
+		assert_noop!(
+			Nft::burn(Origin::signed(BOB), 0),
+			Error::<Runtime>::AssetHasEquippedAssets
+		);
+
+		assert_noop!(Nft::unequip(origin.clone(), 1), Error::<Runtime>::NoPermission);
+		assert_ok!(Nft::unequip(Origin::signed(BOB), 1));
+		let event = mock::Event::Nft(crate::Event::AssetUnequipped(1, 0, b"hat".to_vec()));
+		assert_eq!(last_event(), event);
+		assert_eq!(Nft::get_equipped_asset(0, b"hat".to_vec()), None);
+		assert_eq!(Nft::get_asset_parent(1), None);
+		assert_eq!(Nft::get_assets_by_owner(BOB), vec![0, 1]);
+		assert_ok!(Nft::transfer(Origin::signed(BOB), ALICE, 1));
+	})
+}
+
+#[test]
+fn equip_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_wearable_nft(origin.clone());
+
+		assert_noop!(
+			Nft::equip(origin.clone(), 0, 1, b"hat".to_vec()),
+			Error::<Runtime>::AssetIsNotWearable
+		);
+		assert_noop!(
+			Nft::equip(origin.clone(), 1, 1, b"hat".to_vec()),
+			Error::<Runtime>::InvalidParentAsset
+		);
+		assert_noop!(
+			Nft::equip(Origin::signed(BOB), 1, 0, b"hat".to_vec()),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(Nft::equip(origin.clone(), 1, 0, b"hat".to_vec()));
+		assert_noop!(
+			Nft::equip(origin.clone(), 2, 0, b"hat".to_vec()),
+			Error::<Runtime>::SlotIsOccupied
+		);
+		assert_noop!(
+			Nft::equip(origin.clone(), 1, 0, b"shoes".to_vec()),
+			Error::<Runtime>::AssetIsEquipped
+		);
+		assert_noop!(Nft::unequip(origin.clone(), 2), Error::<Runtime>::AssetIsNotEquipped);
+	})
+}
+
+fn init_executable_nft(owner: Origin, mode: ExecutionMode<u64>) {
+	assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1],));
+	assert_ok!(Nft::create_class(
+		owner.clone(),
+		vec![1],
+		test_attributes(1),
+		COLLECTION_ID,
+		TokenType::Transferable,
+		CollectionType::Executable(transfer_balance_encode(BOB, 100)),
+		None,
+	));
+	assert_ok!(Nft::set_execution_mode(owner.clone(), CLASS_ID, mode));
+	assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+}
+
+#[test]
+fn execute_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_executable_nft(origin.clone(), ExecutionMode::Unlimited);
+
+		assert_noop!(
+			Nft::set_execution_filter(origin.clone(), ExecutableCallFilter::JustBalances),
+			BadOrigin
+		);
+		assert_ok!(Nft::set_execution_filter(
+			Origin::root(),
+			ExecutableCallFilter::JustBalances
+		));
+
+		// Only the weight of the executed call is charged out of the weight limit
+		let call_weight = Call::decode(&mut &transfer_balance_encode(BOB, 100)[..])
+			.unwrap()
+			.get_dispatch_info()
+			.weight;
+		let post_info = Nft::execute_asset(origin.clone(), 0, 1_000_000_000).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::transfer() + call_weight)
+		);
+		assert_eq!(last_event(), Event::Nft(crate::Event::ExecutedNft(0)));
+		assert_eq!(Balances::free_balance(BOB), 1100);
+
+		assert_ok!(Nft::execute_asset(origin.clone(), 0, 1_000_000_000));
+		assert_eq!(Balances::free_balance(BOB), 1200);
+	})
+}
+
+#[test]
+fn execute_asset_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_executable_nft(origin.clone(), ExecutionMode::Unlimited);
+
+		assert_noop!(
+			Nft::execute_asset(origin.clone(), 0, 1_000_000_000),
+			Error::<Runtime>::ExecutableCallFiltered
+		);
+		assert_ok!(Nft::set_execution_filter(
+			Origin::root(),
+			ExecutableCallFilter::JustBalances
+		));
+		assert_noop!(
+			Nft::execute_asset(Origin::signed(BOB), 0, 1_000_000_000),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::execute_asset(origin.clone(), 0, 0),
+			Error::<Runtime>::ExecutableCallWeightTooHigh
+		);
+		assert_noop!(
+			Nft::set_execution_mode(origin.clone(), CLASS_ID, ExecutionMode::SingleUse),
+			Error::<Runtime>::CollectionAlreadyMinted
+		);
+
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			None,
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 1));
+		assert_noop!(
+			Nft::execute_asset(origin.clone(), 1, 1_000_000_000),
+			Error::<Runtime>::OnlyForExecutableCollectionType
+		);
+	})
+}
+
+#[test]
+fn execute_single_use_asset_should_burn_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_executable_nft(origin.clone(), ExecutionMode::SingleUse);
+		assert_ok!(Nft::set_execution_filter(Origin::root(), ExecutableCallFilter::Any));
+
+		assert_ok!(Nft::execute_asset(origin.clone(), 0, 1_000_000_000));
+		assert_eq!(Balances::free_balance(BOB), 1100);
+		assert_eq!(Nft::get_asset(0), None);
+		assert_noop!(
+			Nft::execute_asset(origin.clone(), 0, 1_000_000_000),
+			Error::<Runtime>::AssetIdNotFound
+		);
+	})
+}
+
+#[test]
+fn execute_asset_with_cooldown_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_executable_nft(origin.clone(), ExecutionMode::Cooldown(10));
+		assert_ok!(Nft::set_execution_filter(Origin::root(), ExecutableCallFilter::Any));
+
+		assert_ok!(Nft::execute_asset(origin.clone(), 0, 1_000_000_000));
+		assert_eq!(Nft::get_last_execution(0), Some(1));
+		assert_noop!(
+			Nft::execute_asset(origin.clone(), 0, 1_000_000_000),
+			Error::<Runtime>::AssetExecutionOnCooldown
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Nft::execute_asset(origin.clone(), 0, 1_000_000_000));
+		assert_eq!(Nft::get_last_execution(0), Some(11));
+		assert_eq!(Balances::free_balance(BOB), 1200);
+	})
+}
+
+#[test]
+fn time_capsule_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		let token_id = FungibleTokenId::FungibleToken(1);
+		init_test_nft(origin.clone());
+		assert_ok!(Tokens::deposit(token_id, &ALICE, 100));
+
+		assert_ok!(Nft::create_time_capsule(
+			origin.clone(),
+			0,
+			10,
+			vec![(FungibleTokenId::NativeToken(0), 500), (token_id, 100)],
+			BlakeTwo256::hash(&[2]),
+		));
+		assert_eq!(last_event(), Event::Nft(crate::Event::TimeCapsuleCreated(0, 10)));
+
+		let capsule_account = Nft::get_time_capsule_account(&0);
+		assert_eq!(Balances::free_balance(capsule_account), 500);
+		assert_eq!(Balances::usable_balance(capsule_account), 0);
+		assert_eq!(Tokens::free_balance(token_id, &capsule_account), 100);
+
+		// Sealed capsules can be transferred
+		assert_ok!(Nft::transfer(origin.clone(), BOB, 0));
+		assert_noop!(
+			Nft::open_capsule(Origin::signed(BOB), 0, vec![2]),
+			Error::<Runtime>::TimeCapsuleIsSealed
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Nft::open_capsule(origin.clone(), 0, vec![2]),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::open_capsule(Origin::signed(BOB), 0, vec![3]),
+			Error::<Runtime>::InvalidTimeCapsuleMetadata
+		);
+		assert_ok!(Nft::open_capsule(Origin::signed(BOB), 0, vec![2]));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::TimeCapsuleOpened(0, BOB, vec![2]))
+		);
+		assert_eq!(Balances::free_balance(BOB), 1500);
+		assert_eq!(Tokens::free_balance(token_id, &BOB), 100);
+		assert_eq!(
+			NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID)
+				.unwrap()
+				.metadata
+				.to_vec(),
+			vec![2]
+		);
+		assert_eq!(Nft::get_time_capsule(0), None);
+	})
+}
+
+#[test]
+fn time_capsule_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::create_time_capsule(origin.clone(), 0, 1, vec![], BlakeTwo256::hash(&[2])),
+			Error::<Runtime>::InvalidUnlockBlock
+		);
+		assert_noop!(
+			Nft::create_time_capsule(Origin::signed(BOB), 0, 10, vec![], BlakeTwo256::hash(&[2])),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::open_capsule(origin.clone(), 0, vec![2]),
+			Error::<Runtime>::TimeCapsuleNotFound
+		);
+
+		assert_ok!(Nft::create_time_capsule(
+			origin.clone(),
+			0,
+			10,
+			vec![(FungibleTokenId::NativeToken(0), 500)],
+			BlakeTwo256::hash(&[2]),
+		));
+		assert_noop!(
+			Nft::create_time_capsule(origin.clone(), 0, 10, vec![], BlakeTwo256::hash(&[2])),
+			Error::<Runtime>::AssetIsTimeCapsule
+		);
+		assert_noop!(Nft::burn(origin.clone(), 0), Error::<Runtime>::AssetIsTimeCapsule);
+	})
+}
+
+#[test]
+fn burn_should_refund_token_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		let class_fund = Nft::get_class_fund(&CLASS_ID);
+		let token_deposit = NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().data.deposit;
+		let class_deposit = NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.deposit;
+		let balance_before_burn = Balances::free_balance(ALICE);
+
+		assert_ok!(Nft::burn(origin.clone(), 0));
+		assert_eq!(Balances::free_balance(ALICE), balance_before_burn + token_deposit);
+		assert_eq!(Balances::reserved_balance(class_fund), class_deposit);
+		assert_eq!(Nft::get_assets_by_owner(ALICE), Vec::<AssetId>::new());
+	})
+}
+
+#[test]
+fn burn_should_fail_if_asset_in_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		set_item_in_auction(ItemId::NFT(0));
+
+		assert_noop!(Nft::burn(origin.clone(), 0), Error::<Runtime>::AssetAlreadyInAuction);
+	})
+}
+
+#[test]
+fn burn_should_clear_asset_approval() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::approve(origin.clone(), BOB, 0, None));
+
+		assert_ok!(Nft::burn(origin.clone(), 0));
+		assert_eq!(AssetApprovals::<Runtime>::get(0), None);
+	})
+}
+
+#[test]
+fn destroy_class_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::force_lock_collection(Origin::root(), CLASS_ID));
+		assert_ok!(Nft::burn(origin.clone(), 0));
+
+		let class_fund = Nft::get_class_fund(&CLASS_ID);
+		let class_deposit = NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.deposit;
+		let balance_before_destroy = Balances::free_balance(ALICE);
+
+		assert_ok!(Nft::destroy_class(origin.clone(), CLASS_ID));
+		assert_eq!(last_event(), Event::Nft(crate::Event::ClassDestroyed(ALICE, CLASS_ID)));
+		assert_eq!(Balances::free_balance(ALICE), balance_before_destroy + class_deposit);
+		assert_eq!(Balances::total_balance(&class_fund), 0);
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID), None);
+		assert!(!ClassDataCollection::<Runtime>::contains_key(CLASS_ID));
+		assert!(!Nft::is_collection_locked(&CLASS_ID));
+	})
+}
+
+#[test]
+fn destroy_class_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::destroy_class(Origin::signed(BOB), CLASS_ID),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::destroy_class(origin.clone(), CLASS_ID),
+			Error::<Runtime>::ClassIsNotEmpty
+		);
+		assert_noop!(Nft::destroy_class(origin.clone(), 1), Error::<Runtime>::ClassIdNotFound);
+	})
+}
+
+#[test]
+fn transfer_from_with_approval_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0),
+			Error::<Runtime>::NotApproved
+		);
+		assert_ok!(Nft::approve(origin.clone(), BOB, 0, Some(10)));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::AssetApproved(ALICE, BOB, 0, Some(10)))
+		);
+
+		assert_ok!(Nft::transfer_from(Origin::signed(BOB), ALICE, 3, 0));
+		assert_eq!(Nft::get_assets_by_owner(3), vec![0]);
+		// Approval is cleared on transfer
+		assert_eq!(Nft::get_asset_approval(0), None);
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), 3, BOB, 0),
+			Error::<Runtime>::NotApproved
+		);
+	})
+}
+
+#[test]
+fn approval_should_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::approve(origin.clone(), BOB, 0, Some(1)),
+			Error::<Runtime>::InvalidApprovalExpiry
+		);
+		assert_noop!(
+			Nft::approve(Origin::signed(BOB), BOB, 0, None),
+			Error::<Runtime>::InvalidApprovalDelegate
+		);
+		assert_noop!(
+			Nft::approve(Origin::signed(BOB), 3, 0, None),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(Nft::approve(origin.clone(), BOB, 0, Some(10)));
+		System::set_block_number(10);
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0),
+			Error::<Runtime>::NotApproved
+		);
+
+		assert_ok!(Nft::cancel_approval(origin.clone(), 0));
+		assert_noop!(
+			Nft::cancel_approval(origin.clone(), 0),
+			Error::<Runtime>::ApprovalNotFound
+		);
+	})
+}
+
+#[test]
+fn transfer_from_with_operator_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			None,
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 2));
+
+		// Class-wide operator
+		assert_ok!(Nft::set_operator(origin.clone(), BOB, Some(1), None));
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0),
+			Error::<Runtime>::NotApproved
+		);
+		assert_ok!(Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 1));
+
+		// Account-wide operator
+		assert_ok!(Nft::set_operator(origin.clone(), BOB, None, Some(10)));
+		assert_ok!(Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 0));
+
+		assert_ok!(Nft::remove_operator(origin.clone(), BOB, Some(1)));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::OperatorRemoved(ALICE, BOB, Some(1)))
+		);
+		System::set_block_number(10);
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, 2),
+			Error::<Runtime>::NotApproved
+		);
+		assert_noop!(
+			Nft::remove_operator(origin.clone(), BOB, Some(1)),
+			Error::<Runtime>::OperatorNotFound
+		);
+	})
+}
+
+#[test]
+fn assets_by_owner_index_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_group(Origin::root(), vec![2], vec![2]));
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			1,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			None,
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 3));
+		assert_eq!(Nft::get_assets_count_by_owner(ALICE), 4);
+
+		assert_ok!(Nft::transfer(origin.clone(), BOB, 1));
+		assert_eq!(Nft::get_assets_count_by_owner(ALICE), 3);
+		assert_eq!(Nft::get_assets_count_by_owner(BOB), 1);
+		assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0, 2, 3]);
+
+		// Pages follow storage order
+		let first_page = Nft::get_assets_by_owner_paginated(&ALICE, None, None, None, 2);
+		assert_eq!(first_page.len(), 2);
+		let second_page = Nft::get_assets_by_owner_paginated(&ALICE, None, None, first_page.last().copied(), 2);
+		assert_eq!(second_page.len(), 1);
+		let mut all_assets = [first_page, second_page].concat();
+		all_assets.sort_unstable();
+		assert_eq!(all_assets, vec![0, 2, 3]);
+
+		assert_eq!(
+			Nft::get_assets_by_owner_paginated(&ALICE, Some(0), None, None, 10),
+			vec![0]
+		);
+		let mut group_assets = Nft::get_assets_by_owner_paginated(&ALICE, None, Some(1), None, 10);
+		group_assets.sort_unstable();
+		assert_eq!(group_assets, vec![2, 3]);
+		assert_eq!(
+			Nft::get_assets_by_owner_paginated(&ALICE, Some(1), Some(0), None, 10),
+			Vec::<AssetId>::new()
+		);
+
+		assert_ok!(Nft::burn(Origin::signed(BOB), 1));
+		assert_eq!(Nft::get_assets_count_by_owner(BOB), 0);
+		assert!(!AssetsCountByOwner::<Runtime>::contains_key(BOB));
+	})
+}
+
+#[test]
+fn mint_batch_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		let items: BoundedVec<MintBatchItem<AccountId>, MaxBatchMinting> = vec![
+			(vec![2], test_attributes(2), None),
+			(vec![3], BTreeMap::new(), Some(BOB)),
+		]
+		.try_into()
+		.unwrap();
+
+		let balance_before_mint = Balances::free_balance(ALICE);
+		assert_ok!(Nft::mint_batch(origin.clone(), CLASS_ID, items));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::NewNftMinted(1, 2, ALICE, CLASS_ID, 2, 2, 3))
+		);
+
+		assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0, 1]);
+		assert_eq!(Nft::get_assets_by_owner(BOB), vec![2]);
+
+		let first_token = NftModule::<Runtime>::tokens(CLASS_ID, 1).unwrap();
+		assert_eq!(first_token.metadata.to_vec(), vec![2]);
+		assert_eq!(first_token.data.attributes, test_attributes(2));
+		assert_eq!(first_token.data.deposit, 4);
+		let second_token = NftModule::<Runtime>::tokens(CLASS_ID, 2).unwrap();
+		assert_eq!(second_token.owner, BOB);
+		assert_eq!(second_token.metadata.to_vec(), vec![3]);
+		assert_eq!(second_token.data.deposit, 0);
+
+		// Only the per item deposits are paid by the minter
+		assert_eq!(Balances::free_balance(ALICE), balance_before_mint - 4);
+	})
+}
+
+#[test]
+fn mint_batch_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		let items: BoundedVec<MintBatchItem<AccountId>, MaxBatchMinting> =
+			vec![(vec![2], test_attributes(2), None)].try_into().unwrap();
+
+		assert_noop!(
+			Nft::mint_batch(Origin::signed(BOB), CLASS_ID, items.clone()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::mint_batch(origin.clone(), CLASS_ID, vec![].try_into().unwrap()),
+			Error::<Runtime>::InvalidQuantity
+		);
+		assert_noop!(
+			Nft::mint_batch(
+				origin.clone(),
+				CLASS_ID,
+				vec![(vec![1; 11], test_attributes(2), None)].try_into().unwrap()
+			),
+			Error::<Runtime>::ExceedMaximumMetadataLength
+		);
+		let too_many_items: Result<BoundedVec<MintBatchItem<AccountId>, MaxBatchMinting>, _> =
+			vec![(vec![2], test_attributes(2), None); 11].try_into();
+		assert!(too_many_items.is_err());
+	})
+}
+
+fn mint_voucher(nonce: u64, expiry: u64) -> MintVoucherOf<Runtime> {
+	MintVoucher {
+		class_id: CLASS_ID,
+		metadata_hash: BlakeTwo256::hash(&[2]),
+		attributes: test_attributes(2),
+		price: 100,
+		currency_id: FungibleTokenId::NativeToken(0),
+		expiry,
+		nonce,
+	}
+}
+
+fn sign_voucher(signer: AccountId, voucher: &MintVoucherOf<Runtime>) -> MockSignature {
+	MockSignature(signer, Nft::voucher_signing_payload(voucher))
+}
+
+#[test]
+fn redeem_voucher_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(ALICE));
+		let voucher = mint_voucher(0, 10);
+		let signature = sign_voucher(ALICE, &voucher);
+		let alice_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(Nft::redeem_voucher(Origin::signed(BOB), voucher, vec![2], signature));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::VoucherRedeemed(BOB, CLASS_ID, 0, 1))
+		);
+		assert_eq!(Nft::get_assets_by_owner(BOB), vec![1]);
+		let token = NftModule::<Runtime>::tokens(CLASS_ID, 1).unwrap();
+		assert_eq!(token.metadata.to_vec(), vec![2]);
+		assert_eq!(token.data.attributes, test_attributes(2));
+		assert_eq!(token.data.deposit, 4);
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.total_supply, 2);
+
+		// Buyer pays the price to the class owner and the token deposit
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 100);
+		assert_eq!(Balances::free_balance(BOB), 1000 - 100 - 4);
+		assert!(Nft::is_voucher_redeemed(CLASS_ID, 0).is_some());
+	})
+}
+
+#[test]
+fn redeem_voucher_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(ALICE));
+		let voucher = mint_voucher(0, 10);
+
+		assert_noop!(
+			Nft::redeem_voucher(
+				Origin::signed(BOB),
+				voucher.clone(),
+				vec![2],
+				sign_voucher(BOB, &voucher)
+			),
+			Error::<Runtime>::InvalidVoucherSignature
+		);
+		assert_noop!(
+			Nft::redeem_voucher(
+				Origin::signed(BOB),
+				voucher.clone(),
+				vec![3],
+				sign_voucher(ALICE, &voucher)
+			),
+			Error::<Runtime>::InvalidVoucherMetadata
+		);
+		let mut tampered_voucher = voucher.clone();
+		tampered_voucher.price = 1;
+		assert_noop!(
+			Nft::redeem_voucher(
+				Origin::signed(BOB),
+				tampered_voucher,
+				vec![2],
+				sign_voucher(ALICE, &voucher)
+			),
+			Error::<Runtime>::InvalidVoucherSignature
+		);
+		// Signature without the domain tag and genesis hash
+		assert_noop!(
+			Nft::redeem_voucher(
+				Origin::signed(BOB),
+				voucher.clone(),
+				vec![2],
+				MockSignature(ALICE, voucher.encode())
+			),
+			Error::<Runtime>::InvalidVoucherSignature
+		);
+
+		assert_ok!(Nft::redeem_voucher(
+			Origin::signed(BOB),
+			voucher.clone(),
+			vec![2],
+			sign_voucher(ALICE, &voucher)
+		));
+		assert_noop!(
+			Nft::redeem_voucher(
+				Origin::signed(BOB),
+				voucher.clone(),
+				vec![2],
+				sign_voucher(ALICE, &voucher)
+			),
+			Error::<Runtime>::VoucherAlreadyRedeemed
+		);
+
+		let cancelled_voucher = mint_voucher(1, 10);
+		assert_ok!(Nft::cancel_voucher(Origin::signed(ALICE), CLASS_ID, 1));
+		assert_noop!(
+			Nft::redeem_voucher(
+				Origin::signed(BOB),
+				cancelled_voucher.clone(),
+				vec![2],
+				sign_voucher(ALICE, &cancelled_voucher)
+			),
+			Error::<Runtime>::VoucherAlreadyRedeemed
+		);
+
+		let expired_voucher = mint_voucher(2, 10);
+		System::set_block_number(11);
+		assert_noop!(
+			Nft::redeem_voucher(
+				Origin::signed(BOB),
+				expired_voucher.clone(),
+				vec![2],
+				sign_voucher(ALICE, &expired_voucher)
+			),
+			Error::<Runtime>::VoucherExpired
+		);
+	})
+}
+
+#[test]
+fn set_user_should_work_and_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::set_user(Origin::signed(BOB), 0, BOB, 10, false),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			Nft::set_user(origin.clone(), 0, BOB, 1, false),
+			Error::<Runtime>::InvalidRentalPeriod
+		);
+
+		assert_ok!(Nft::set_user(origin.clone(), 0, BOB, 10, false));
+		assert_eq!(last_event(), Event::Nft(crate::Event::AssetUserSet(ALICE, BOB, 0, 10)));
+		assert_eq!(Nft::get_asset_user(0), Some(BOB));
+		// Owner keeps ownership while the user holds usage rights
+		assert_eq!(Nft::get_assets_by_owner(ALICE), vec![0]);
+		assert_noop!(
+			Nft::set_user(origin.clone(), 0, 3, 20, false),
+			Error::<Runtime>::AssetAlreadyRented
+		);
+
+		System::set_block_number(10);
+		assert_eq!(Nft::get_asset_user(0), None);
+		assert_noop!(Nft::clear_user(Origin::signed(BOB), 0), Error::<Runtime>::NotAssetUser);
+		assert_ok!(Nft::set_user(origin.clone(), 0, 3, 20, false));
+		assert_eq!(Nft::get_asset_user(0), Some(3));
+
+		assert_ok!(Nft::clear_user(Origin::signed(3), 0));
+		assert_eq!(Nft::get_asset_user(0), None);
+	})
+}
+
+#[test]
+fn set_user_should_only_extend_active_rights() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_ok!(Nft::set_user(origin.clone(), 0, BOB, 10, true));
+		assert_noop!(
+			Nft::set_user(origin.clone(), 0, BOB, 5, true),
+			Error::<Runtime>::AssetAlreadyRented
+		);
+		assert_noop!(
+			Nft::set_user(origin.clone(), 0, BOB, 10, false),
+			Error::<Runtime>::AssetAlreadyRented
+		);
+
+		assert_ok!(Nft::set_user(origin.clone(), 0, BOB, 20, true));
+		assert_eq!(
+			Nft::get_asset_user_info(0),
+			Some(AssetUser {
+				user: BOB,
+				expires: 20,
+				survives_transfer: true,
+			})
+		);
+
+		// Terms can change once the rights expired
+		System::set_block_number(20);
+		assert_ok!(Nft::set_user(origin.clone(), 0, BOB, 25, false));
+		assert_eq!(Nft::get_asset_user(0), Some(BOB));
+	})
+}
+
+#[test]
+fn rent_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(Nft::rent(Origin::signed(BOB), 0), Error::<Runtime>::RentalOfferNotFound);
+		assert_noop!(
+			Nft::offer_rental(origin.clone(), 0, 0, FungibleTokenId::NativeToken(0), 100, false),
+			Error::<Runtime>::InvalidRentalPeriod
+		);
+		assert_ok!(Nft::offer_rental(
+			origin.clone(),
+			0,
+			5,
+			FungibleTokenId::NativeToken(0),
+			100,
+			false
+		));
+		assert_noop!(Nft::rent(origin.clone(), 0), Error::<Runtime>::CannotRentOwnAsset);
+
+		let owner_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::rent(Origin::signed(BOB), 0));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::AssetRented(
+				BOB,
+				0,
+				6,
+				FungibleTokenId::NativeToken(0),
+				100
+			))
+		);
+		assert_eq!(free_native_balance(ALICE), owner_balance + 100);
+		assert_eq!(free_native_balance(BOB), 900);
+		assert_eq!(Nft::get_asset_user(0), Some(BOB));
+		assert_noop!(Nft::rent(Origin::signed(3), 0), Error::<Runtime>::AssetAlreadyRented);
+
+		assert_ok!(Nft::cancel_rental_offer(origin.clone(), 0));
+		assert_eq!(Nft::get_rental_offer(0), None);
+		// Cancelling the offer does not revoke the current rental
+		assert_eq!(Nft::get_asset_user(0), Some(BOB));
+	})
+}
+
+#[test]
+fn asset_user_should_survive_transfer_only_if_configured() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+
+		assert_ok!(Nft::set_user(origin.clone(), 0, BOB, 10, false));
+		assert_ok!(Nft::set_user(origin.clone(), 1, BOB, 10, true));
+		assert_ok!(Nft::offer_rental(
+			origin.clone(),
+			1,
+			5,
+			FungibleTokenId::NativeToken(0),
+			100,
+			true
+		));
+
+		assert_ok!(Nft::transfer(origin.clone(), 3, 0));
+		assert_ok!(Nft::transfer(origin.clone(), 3, 1));
+
+		assert_eq!(Nft::get_asset_user(0), None);
+		assert_eq!(Nft::get_asset_user(1), Some(BOB));
+		// Rental offers are made by the previous owner and do not follow the asset
+		assert_eq!(Nft::get_rental_offer(1), None);
+	})
+}
+
+#[test]
+fn sign_asset_should_merge_and_bound_supporters() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		for supporter in [3, 4, 5].iter() {
+			<Runtime as Config>::Currency::make_free_balance_be(supporter, 1000);
+		}
+
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 100));
+		assert_eq!(last_event(), Event::Nft(crate::Event::AssetSupported(BOB, 0, 100)));
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 50));
+		assert_eq!(Nft::get_asset_supporters(0).into_inner(), vec![(BOB, 150)]);
+
+		assert_ok!(Nft::sign_asset(Origin::signed(3), 0, 10));
+		assert_ok!(Nft::sign_asset(Origin::signed(4), 0, 10));
+		assert_noop!(
+			Nft::sign_asset(Origin::signed(5), 0, 10),
+			Error::<Runtime>::ExceedMaximumSupporters
+		);
+		// Existing supporters can still add to their contribution
+		assert_ok!(Nft::sign_asset(Origin::signed(3), 0, 10));
+		assert_eq!(
+			Nft::get_asset_supporters(0).into_inner(),
+			vec![(BOB, 150), (3, 20), (4, 10)]
+		);
+	})
+}
+
+#[test]
+fn sign_asset_should_require_minimum_contribution() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::sign_asset(Origin::signed(BOB), 0, 0),
+			Error::<Runtime>::ContributionBelowMinimum
+		);
+		assert_noop!(
+			Nft::sign_asset(Origin::signed(BOB), 0, 9),
+			Error::<Runtime>::ContributionBelowMinimum
+		);
+		assert!(Nft::get_asset_supporters(0).is_empty());
+	})
+}
+
+#[test]
+fn support_schedules_should_be_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 100));
+		System::set_block_number(2);
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 10));
+		// Contributions beyond the schedule bound are merged into the most recent schedule
+		System::set_block_number(3);
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 10));
+		assert_eq!(Nft::get_class_support_fund(CLASS_ID).vesting, vec![(100, 1), (20, 2)]);
+
+		// Fully vested contributions are released and free a schedule
+		System::set_block_number(11);
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 10));
+		let fund = Nft::get_class_support_fund(CLASS_ID);
+		assert_eq!(fund.released, 100);
+		assert_eq!(fund.vesting, vec![(20, 2), (10, 11)]);
+	})
+}
+
+#[test]
+fn withdraw_supported_funds_should_vest() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 100));
+		assert_noop!(
+			Nft::withdraw_supported_funds(origin.clone(), CLASS_ID),
+			Error::<Runtime>::NoSupportedFundsToWithdraw
+		);
+		assert_noop!(
+			Nft::withdraw_supported_funds(Origin::signed(BOB), CLASS_ID),
+			Error::<Runtime>::NoPermission
+		);
+
+		let owner_balance = free_native_balance(ALICE);
+		let class_fund_reserved = reserved_balance(&class_id_account());
+		System::set_block_number(6);
+		assert_ok!(Nft::withdraw_supported_funds(origin.clone(), CLASS_ID));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::SupportedFundsWithdrawn(CLASS_ID, ALICE, 50))
+		);
+		assert_eq!(free_native_balance(ALICE), owner_balance + 50);
+		assert_eq!(reserved_balance(&class_id_account()), class_fund_reserved - 50);
+
+		// A new contribution vests on its own schedule without delaying earlier ones
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 50));
+		System::set_block_number(11);
+		assert_ok!(Nft::withdraw_supported_funds(origin.clone(), CLASS_ID));
+		assert_eq!(free_native_balance(ALICE), owner_balance + 125);
+
+		System::set_block_number(16);
+		assert_ok!(Nft::withdraw_supported_funds(origin.clone(), CLASS_ID));
+		assert_eq!(free_native_balance(ALICE), owner_balance + 150);
+		assert_noop!(
+			Nft::withdraw_supported_funds(origin.clone(), CLASS_ID),
+			Error::<Runtime>::NoSupportedFundsToWithdraw
+		);
+	})
+}
+
+#[test]
+fn destroy_class_should_wait_for_supported_funds() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 100));
+		assert_ok!(Nft::force_lock_collection(Origin::root(), CLASS_ID));
+		assert_ok!(Nft::burn(origin.clone(), 0));
+
+		assert_noop!(
+			Nft::destroy_class(origin.clone(), CLASS_ID),
+			Error::<Runtime>::SupportFundNotSettled
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Nft::withdraw_supported_funds(origin.clone(), CLASS_ID));
+		let class_deposit = NftModule::<Runtime>::classes(CLASS_ID).unwrap().data.deposit;
+		let owner_balance = free_native_balance(ALICE);
+
+		assert_ok!(Nft::destroy_class(origin.clone(), CLASS_ID));
+		assert_eq!(free_native_balance(ALICE), owner_balance + class_deposit);
+		assert_eq!(Balances::total_balance(&class_id_account()), 0);
+		assert!(!ClassSupportFunds::<Runtime>::contains_key(CLASS_ID));
+	})
+}
+
+#[test]
+fn supporters_should_share_royalties() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		<Runtime as Config>::Currency::make_free_balance_be(&3, 1000);
+
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 100));
+		assert_ok!(Nft::sign_asset(Origin::signed(3), 0, 300));
+
+		let paid = Nft::distribute_supporter_royalty(&ALICE, &0, FungibleTokenId::NativeToken(0), 100);
+		assert_eq!(paid, Ok(50));
+		// Rewards are credited lazily when supporters contribute or claim
+		assert_eq!(Nft::get_supporter_reward(BOB, FungibleTokenId::NativeToken(0)), 0);
+
+		let bob_balance = free_native_balance(BOB);
+		assert_ok!(Nft::claim_supporter_rewards(
+			Origin::signed(BOB),
+			0,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_eq!(free_native_balance(BOB), bob_balance + 12);
+		assert_noop!(
+			Nft::claim_supporter_rewards(Origin::signed(BOB), 0, FungibleTokenId::NativeToken(0)),
+			Error::<Runtime>::NoSupporterRewards
+		);
+
+		// A later contribution only earns from later royalties
+		assert_ok!(Nft::sign_asset(Origin::signed(3), 0, 100));
+		assert_eq!(Nft::get_supporter_reward(3, FungibleTokenId::NativeToken(0)), 37);
+		assert_eq!(
+			Nft::distribute_supporter_royalty(&ALICE, &0, FungibleTokenId::NativeToken(0), 100),
+			Ok(50)
+		);
+		let balance_before_claim = free_native_balance(3);
+		assert_ok!(Nft::claim_supporter_rewards(
+			Origin::signed(3),
+			0,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_eq!(free_native_balance(3), balance_before_claim + 37 + 40);
+	})
+}
+
+#[test]
+fn supporter_rewards_should_be_bounded_by_currencies() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 100));
+		for i in 1..4 {
+			assert_ok!(Tokens::deposit(FungibleTokenId::FungibleToken(i), &ALICE, 1000));
+		}
+
+		assert_eq!(
+			Nft::distribute_supporter_royalty(&ALICE, &0, FungibleTokenId::FungibleToken(1), 100),
+			Ok(50)
+		);
+		assert_eq!(
+			Nft::distribute_supporter_royalty(&ALICE, &0, FungibleTokenId::FungibleToken(2), 100),
+			Ok(50)
+		);
+		// Royalties in further currencies are not shared with supporters
+		assert_eq!(
+			Nft::distribute_supporter_royalty(&ALICE, &0, FungibleTokenId::FungibleToken(3), 100),
+			Ok(0)
+		);
+		assert_eq!(Nft::get_supporter_reward_indexes(0).len(), 2);
+	})
+}
+
+#[test]
+fn supporter_rewards_should_survive_burn() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::sign_asset(Origin::signed(BOB), 0, 100));
+		assert_ok!(Nft::distribute_supporter_royalty(
+			&ALICE,
+			&0,
+			FungibleTokenId::NativeToken(0),
+			100
+		));
+
+		assert_ok!(Nft::burn(origin.clone(), 0));
+		let bob_balance = free_native_balance(BOB);
+		assert_ok!(Nft::claim_supporter_rewards(
+			Origin::signed(BOB),
+			0,
+			FungibleTokenId::NativeToken(0)
+		));
+		assert_eq!(free_native_balance(BOB), bob_balance + 50);
+	})
+}
@@ -48,9 +48,15 @@ pub trait WeightInfo {
     fn create_group() -> Weight;
     fn create_class() -> Weight;
     fn mint(i: u32) -> Weight;
+    fn mint_batch(i: u32, b: u32) -> Weight;
     fn transfer() -> Weight;
     fn transfer_batch(i: u32) -> Weight;
-    fn sign_asset() -> Weight;
+    fn promotion_rewards(c: u32) -> Weight;
+    fn burn() -> Weight;
+    fn sign_asset(c: u32) -> Weight;
+    fn claim_supporter_rewards(c: u32) -> Weight;
+    fn create_time_capsule(c: u32) -> Weight;
+    fn open_capsule(c: u32, m: u32) -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -75,6 +81,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
     }
 
+    fn mint_batch(i: u32, b: u32) -> Weight {
+        (1_621_000 as Weight)
+            .saturating_add((45_372_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
+    }
     fn transfer() -> Weight {
         (176_000_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(6 as Weight))
@@ -86,10 +100,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(9 as Weight))
             .saturating_add(T::DbWeight::get().writes((9 as Weight).saturating_mul(i as Weight)))
     }
-    fn sign_asset() -> Weight {
-        (47_000_000 as Weight)
+    fn promotion_rewards(c: u32) -> Weight {
+        (12_000_000 as Weight)
+            .saturating_add((48_000_000 as Weight).saturating_mul(c as Weight))
             .saturating_add(T::DbWeight::get().reads(2 as Weight))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+    }
+    fn burn() -> Weight {
+        (61_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(9 as Weight))
+            .saturating_add(T::DbWeight::get().writes(14 as Weight))
+    }
+    fn sign_asset(c: u32) -> Weight {
+        (52_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+    }
+    fn claim_supporter_rewards(c: u32) -> Weight {
+        (41_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+    }
+    fn create_time_capsule(c: u32) -> Weight {
+        (62_000_000 as Weight)
+            .saturating_add((41_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+    }
+    fn open_capsule(c: u32, m: u32) -> Weight {
+        (58_000_000 as Weight)
+            .saturating_add((41_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
     }
 }
 
@@ -113,6 +163,14 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(3 as Weight))
             .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
     }
+    fn mint_batch(i: u32, b: u32) -> Weight {
+        (1_621_000 as Weight)
+            .saturating_add((45_372_000 as Weight).saturating_mul(i as Weight))
+            .saturating_add((2_000 as Weight).saturating_mul(b as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
+    }
     fn transfer() -> Weight {
         (176_000_000 as Weight)
             .saturating_add(RocksDbWeight::get().reads(6 as Weight))
@@ -124,9 +182,45 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(9 as Weight))
             .saturating_add(RocksDbWeight::get().writes((9 as Weight).saturating_mul(i as Weight)))
     }
-    fn sign_asset() -> Weight {
-        (47_000_000 as Weight)
+    fn promotion_rewards(c: u32) -> Weight {
+        (12_000_000 as Weight)
+            .saturating_add((48_000_000 as Weight).saturating_mul(c as Weight))
             .saturating_add(RocksDbWeight::get().reads(2 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+    }
+    fn burn() -> Weight {
+        (61_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(9 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(14 as Weight))
+    }
+    fn sign_asset(c: u32) -> Weight {
+        (52_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+    }
+    fn claim_supporter_rewards(c: u32) -> Weight {
+        (41_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+    }
+    fn create_time_capsule(c: u32) -> Weight {
+        (62_000_000 as Weight)
+            .saturating_add((41_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+    }
+    fn open_capsule(c: u32, m: u32) -> Weight {
+        (58_000_000 as Weight)
+            .saturating_add((41_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
     }
 }
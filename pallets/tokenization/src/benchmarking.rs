@@ -0,0 +1,121 @@
+// This file is part of Bit.Country
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks for the tokenization module.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::prelude::*;
+use sp_std::vec;
+
+use crate::Call;
+#[allow(unused)]
+pub use crate::Pallet as TokenizationModule;
+pub use crate::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use pallet_nft::{Attributes, CollectionType, TokenType};
+
+const SEED: u32 = 0;
+const ASSET_ID: AssetId = 0;
+const TOTAL_SHARES: Balance = 1_000;
+const RESERVE_PRICE: Balance = 500;
+
+fn dollar(d: u32) -> Balance {
+	let d: Balance = d.into();
+	d.saturating_mul(1_000_000_000_000_000_000)
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::MetaverseMultiCurrency::deposit(FungibleTokenId::NativeToken(0), &caller, dollar(1000)).unwrap();
+	caller
+}
+
+fn test_attributes(x: u8) -> Attributes {
+	let mut attr: Attributes = BTreeMap::new();
+	attr.insert(vec![x, x + 5], vec![x, x + 10]);
+	attr
+}
+
+fn mint_nft<T: Config + pallet_nft::Config>(owner: &T::AccountId) {
+	pallet_nft::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]).unwrap();
+	pallet_nft::Pallet::<T>::create_class(
+		RawOrigin::Signed(owner.clone()).into(),
+		vec![1],
+		test_attributes(1),
+		0u32.into(),
+		TokenType::Transferable,
+		CollectionType::Collectable,
+		None,
+	)
+	.unwrap();
+	pallet_nft::Pallet::<T>::mint(
+		RawOrigin::Signed(owner.clone()).into(),
+		0u32.into(),
+		vec![1],
+		test_attributes(1),
+		1,
+	)
+	.unwrap();
+}
+
+fn fractionalized_nft<T: Config + pallet_nft::Config>(owner: &T::AccountId) {
+	mint_nft::<T>(owner);
+	TokenizationModule::<T>::fractionalize(
+		RawOrigin::Signed(owner.clone()).into(),
+		ASSET_ID,
+		b"FRAC".to_vec(),
+		TOTAL_SHARES,
+		RESERVE_PRICE,
+	)
+	.unwrap();
+}
+
+benchmarks! {
+	where_clause { where T: pallet_nft::Config }
+
+	fractionalize{
+		let caller = funded_account::<T>("caller", 0);
+		mint_nft::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), ASSET_ID, b"FRAC".to_vec(), TOTAL_SHARES, RESERVE_PRICE)
+
+	trigger_buyout{
+		let caller = funded_account::<T>("caller", 0);
+		fractionalized_nft::<T>(&caller);
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + T::BuyoutDelay::get());
+	}: _(RawOrigin::Signed(caller), ASSET_ID)
+
+	redeem_shares{
+		let caller = funded_account::<T>("caller", 0);
+		let buyer = funded_account::<T>("buyer", 0);
+		fractionalized_nft::<T>(&caller);
+
+		// Settle the buyout as the auction pallet does when it ends
+		let vault_account = TokenizationModule::<T>::get_fractional_vault_account(ASSET_ID);
+		T::NFTHandler::transfer_nft(&vault_account, &buyer, &ASSET_ID).unwrap();
+		T::MetaverseMultiCurrency::transfer(FungibleTokenId::NativeToken(0), &buyer, &vault_account, dollar(10)).unwrap();
+	}: _(RawOrigin::Signed(caller), ASSET_ID)
+
+	unfractionalize{
+		let caller = funded_account::<T>("caller", 0);
+		fractionalized_nft::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), ASSET_ID)
+}
@@ -18,7 +18,7 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use auction_manager::SwapManager;
+use auction_manager::{Auction, AuctionType, CheckAuctionItemHandler, ListingLevel, NFTTrait, SwapManager};
 use bc_primitives::*;
 use codec::{Decode, Encode};
 use frame_support::traits::Get;
@@ -33,15 +33,20 @@ use frame_system::ensure_signed;
 use frame_system::pallet_prelude::*;
 use orml_traits::{LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
 pub use pallet::*;
-use primitives::{Balance, FungibleTokenId, MetaverseId, VestingSchedule};
+use primitives::{AssetId, AuctionId, Balance, FungibleTokenId, ItemId, MetaverseId, VestingSchedule};
 use scale_info::TypeInfo;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
 	traits::{AccountIdConversion, AtLeast32Bit, One, StaticLookup, Zero},
-	DispatchError,
+	DispatchError, RuntimeDebug,
 };
 use sp_runtime::{FixedPointNumber, SaturatedConversion};
 use sp_std::convert::TryInto;
 use sp_std::vec::Vec;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
 
 #[cfg(test)]
 mod mock;
@@ -49,6 +54,8 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+pub mod weights;
+
 /// A wrapper for a token name.
 pub type TokenName = Vec<u8>;
 
@@ -61,6 +68,21 @@ pub struct Token<Balance> {
 	pub total_supply: Balance,
 }
 
+/// Vault holding a fractionalized NFT.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct FractionalVault<AccountId, Balance, BlockNumber> {
+	/// Account that fractionalized the asset
+	pub owner: AccountId,
+	/// Token representing the shares of the asset
+	pub share_token: FungibleTokenId,
+	/// Number of shares issued
+	pub total_shares: Balance,
+	/// Minimum price of a buyout auction
+	pub reserve_price: Balance,
+	/// Block the asset was fractionalized at
+	pub fractionalized_at: BlockNumber,
+}
+
 pub use pallet::*;
 use primitives::dex::Price;
 
@@ -69,6 +91,9 @@ pub const MAX_VESTINGS: usize = 20;
 
 pub const VESTING_LOCK_ID: LockIdentifier = *b"bcstvest";
 
+/// Seed of the vault accounts holding fractionalized NFTs.
+pub const FRACTIONAL_VAULT_ID: [u8; 8] = *b"bcfrvalt";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -101,6 +126,15 @@ pub mod pallet {
 		type MinVestedTransfer: Get<Balance>;
 		/// Required origin for vested transfer.
 		type VestedTransferOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+		/// NFT handler used to lock fractionalized assets in their vault
+		type NFTHandler: NFTTrait<Self::AccountId>;
+		/// Auction handler used to run buyout auctions of fractionalized assets
+		type AuctionHandler: Auction<Self::AccountId, Self::BlockNumber, Balance = Balance> + CheckAuctionItemHandler;
+		#[pallet::constant]
+		/// Blocks after fractionalization before a buyout auction can be started
+		type BuyoutDelay: Get<Self::BlockNumber>;
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::storage]
@@ -128,6 +162,12 @@ pub mod pallet {
 	pub type VestingSchedules<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<VestingScheduleOf<T>>, ValueQuery>;
 
+	/// Vaults of fractionalized NFTs.
+	#[pallet::storage]
+	#[pallet::getter(fn fractional_vaults)]
+	pub type FractionalVaults<T: Config> =
+		StorageMap<_, Twox64Concat, AssetId, FractionalVault<T::AccountId, Balance, T::BlockNumber>, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Transfer amount should be non-zero
@@ -164,6 +204,24 @@ pub mod pallet {
 		InvalidVestingSchedule,
 		/// Invalid request
 		InvalidRequest,
+		/// Only the asset owner can fractionalize it
+		NoPermissionToFractionalize,
+		/// Asset is already fractionalized
+		AssetAlreadyFractionalized,
+		/// Asset is not fractionalized
+		AssetNotFractionalized,
+		/// Asset is currently in auction
+		AssetInAuction,
+		/// Asset was already sold in a buyout
+		BuyoutAlreadyCompleted,
+		/// Asset has not been sold in a buyout yet
+		BuyoutNotCompleted,
+		/// Account has no shares of the asset
+		NoSharesToRedeem,
+		/// All shares are required to recover the asset
+		InsufficientShares,
+		/// Buyout delay after fractionalization has not passed yet
+		BuyoutTooEarly,
 	}
 
 	#[pallet::call]
@@ -183,7 +241,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 			ensure!(
-				T::MetaverseInfoSource::check_ownership(&metaverse_owner, &metaverse_id),
+				T::MetaverseInfoSource::has_role(&metaverse_owner, &metaverse_id, MetaverseRole::Treasurer),
 				Error::<T>::NoPermissionTokenIssuance
 			);
 			ensure!(
@@ -256,6 +314,163 @@ pub mod pallet {
 			Self::deposit_event(Event::VestingSchedulesUpdated(currency_id, account));
 			Ok(().into())
 		}
+
+		/// Lock an NFT in its vault and issue `total_shares` of a new share token to the owner.
+		/// After `BuyoutDelay`, anyone can start a buyout auction with `reserve_price` as its minimum
+		/// bid.
+		#[pallet::weight(T::WeightInfo::fractionalize())]
+		#[transactional]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			ticker: Ticker,
+			total_shares: Balance,
+			reserve_price: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!total_shares.is_zero(), Error::<T>::AmountZero);
+			ensure!(
+				!FractionalVaults::<T>::contains_key(asset_id),
+				Error::<T>::AssetAlreadyFractionalized
+			);
+			ensure!(
+				T::NFTHandler::check_ownership(&who, &asset_id)?,
+				Error::<T>::NoPermissionToFractionalize
+			);
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+				Error::<T>::AssetInAuction
+			);
+
+			let vault_account = Self::get_fractional_vault_account(asset_id);
+			T::NFTHandler::transfer_nft(&who, &vault_account, &asset_id)?;
+
+			let share_token = Self::next_fungible_token_id()?;
+			FungibleTokens::<T>::insert(
+				share_token,
+				Token {
+					ticker,
+					total_supply: total_shares,
+				},
+			);
+			T::MetaverseMultiCurrency::deposit(share_token, &who, total_shares)?;
+
+			FractionalVaults::<T>::insert(
+				asset_id,
+				FractionalVault {
+					owner: who.clone(),
+					share_token,
+					total_shares,
+					reserve_price,
+					fractionalized_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::AssetFractionalized(asset_id, who, share_token, total_shares));
+			Ok(().into())
+		}
+
+		/// Start a buyout auction of a fractionalized asset at its reserve price. Anyone can start it
+		/// once `BuyoutDelay` has passed.
+		#[pallet::weight(T::WeightInfo::trigger_buyout())]
+		#[transactional]
+		pub fn trigger_buyout(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let vault = FractionalVaults::<T>::get(asset_id).ok_or(Error::<T>::AssetNotFractionalized)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				now >= vault.fractionalized_at.saturating_add(T::BuyoutDelay::get()),
+				Error::<T>::BuyoutTooEarly
+			);
+
+			let vault_account = Self::get_fractional_vault_account(asset_id);
+			ensure!(
+				Self::is_held_by_vault(&vault_account, asset_id),
+				Error::<T>::BuyoutAlreadyCompleted
+			);
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+				Error::<T>::AssetInAuction
+			);
+
+			let auction_id = T::AuctionHandler::create_auction(
+				AuctionType::Auction,
+				ItemId::NFT(asset_id),
+				None,
+				vault_account,
+				vault.reserve_price,
+				now,
+				ListingLevel::Global,
+			)?;
+			T::AuctionHandler::set_reserve_price(auction_id, vault.reserve_price)?;
+
+			Self::deposit_event(Event::BuyoutTriggered(asset_id, auction_id, who));
+			Ok(().into())
+		}
+
+		/// Burn the caller's shares of a bought out asset in exchange for a pro rata part of the
+		/// buyout proceeds.
+		#[pallet::weight(T::WeightInfo::redeem_shares())]
+		#[transactional]
+		pub fn redeem_shares(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let vault = FractionalVaults::<T>::get(asset_id).ok_or(Error::<T>::AssetNotFractionalized)?;
+			let vault_account = Self::get_fractional_vault_account(asset_id);
+			ensure!(
+				!Self::is_held_by_vault(&vault_account, asset_id),
+				Error::<T>::BuyoutNotCompleted
+			);
+
+			let shares = T::MetaverseMultiCurrency::free_balance(vault.share_token, &who);
+			ensure!(!shares.is_zero(), Error::<T>::NoSharesToRedeem);
+
+			let outstanding_shares = T::MetaverseMultiCurrency::total_issuance(vault.share_token);
+			let proceeds = T::MetaverseMultiCurrency::free_balance(FungibleTokenId::NativeToken(0), &vault_account);
+			let payout =
+				multiply_by_rational(proceeds, shares, outstanding_shares).map_err(|_| Error::<T>::NumOverflow)?;
+
+			T::MetaverseMultiCurrency::withdraw(vault.share_token, &who, shares)?;
+			if !payout.is_zero() {
+				T::MetaverseMultiCurrency::transfer(FungibleTokenId::NativeToken(0), &vault_account, &who, payout)?;
+			}
+
+			if shares == outstanding_shares {
+				FractionalVaults::<T>::remove(asset_id);
+			}
+
+			Self::deposit_event(Event::SharesRedeemed(asset_id, who, shares, payout));
+			Ok(().into())
+		}
+
+		/// Burn every share of a fractionalized asset and take the asset back out of its vault.
+		#[pallet::weight(T::WeightInfo::unfractionalize())]
+		#[transactional]
+		pub fn unfractionalize(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let vault = FractionalVaults::<T>::get(asset_id).ok_or(Error::<T>::AssetNotFractionalized)?;
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::NFT(asset_id)),
+				Error::<T>::AssetInAuction
+			);
+
+			let shares = T::MetaverseMultiCurrency::free_balance(vault.share_token, &who);
+			ensure!(
+				!shares.is_zero() && shares == T::MetaverseMultiCurrency::total_issuance(vault.share_token),
+				Error::<T>::InsufficientShares
+			);
+
+			let vault_account = Self::get_fractional_vault_account(asset_id);
+			T::MetaverseMultiCurrency::withdraw(vault.share_token, &who, shares)?;
+			T::NFTHandler::transfer_nft(&vault_account, &who, &asset_id)?;
+			FractionalVaults::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::AssetUnfractionalized(asset_id, who));
+			Ok(().into())
+		}
 	}
 
 	#[pallet::event]
@@ -273,6 +488,14 @@ pub mod pallet {
 		Claimed(FungibleTokenId, T::AccountId, Balance),
 		/// Updated vesting schedules. [token, who]
 		VestingSchedulesUpdated(FungibleTokenId, T::AccountId),
+		/// Asset locked in its vault and shares issued. [asset_id, owner, share_token, total_shares]
+		AssetFractionalized(AssetId, T::AccountId, FungibleTokenId, Balance),
+		/// Buyout auction started for a fractionalized asset. [asset_id, auction_id, who]
+		BuyoutTriggered(AssetId, AuctionId, T::AccountId),
+		/// Shares redeemed for buyout proceeds. [asset_id, who, shares, payout]
+		SharesRedeemed(AssetId, T::AccountId, Balance, Balance),
+		/// Asset taken back out of its vault. [asset_id, who]
+		AssetUnfractionalized(AssetId, T::AccountId),
 	}
 
 	#[pallet::hooks]
@@ -298,16 +521,7 @@ impl<T: Config> Pallet<T> {
 		// Remaining balance for metaverse owner
 		let owner_supply = total_supply.saturating_sub(initial_pool_supply);
 		// Generate new TokenId
-		let currency_id = NextTokenId::<T>::mutate(|id| -> Result<FungibleTokenId, DispatchError> {
-			let current_id = *id;
-			if current_id == 0 {
-				*id = 2;
-				Ok(FungibleTokenId::FungibleToken(One::one()))
-			} else {
-				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableTokenId)?;
-				Ok(FungibleTokenId::FungibleToken(current_id))
-			}
-		})?;
+		let currency_id = Self::next_fungible_token_id()?;
 		let fund_id: T::AccountId = T::FungibleTokenTreasury::get().into_sub_account(metaverse_id);
 
 		// Metaverse Network treasury
@@ -374,6 +588,28 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn next_fungible_token_id() -> Result<FungibleTokenId, DispatchError> {
+		NextTokenId::<T>::mutate(|id| -> Result<FungibleTokenId, DispatchError> {
+			let current_id = *id;
+			if current_id == 0 {
+				*id = 2;
+				Ok(FungibleTokenId::FungibleToken(One::one()))
+			} else {
+				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableTokenId)?;
+				Ok(FungibleTokenId::FungibleToken(current_id))
+			}
+		})
+	}
+
+	pub fn get_fractional_vault_account(asset_id: AssetId) -> T::AccountId {
+		T::FungibleTokenTreasury::get().into_sub_account((FRACTIONAL_VAULT_ID, asset_id))
+	}
+
+	/// The vault keeps the asset until a buyout auction sells it
+	fn is_held_by_vault(vault_account: &T::AccountId, asset_id: AssetId) -> bool {
+		T::NFTHandler::check_ownership(vault_account, &asset_id).unwrap_or(false)
+	}
+
 	fn transfer_from(
 		currency_id: FungibleTokenId,
 		from: &T::AccountId,
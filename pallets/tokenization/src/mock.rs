@@ -4,12 +4,18 @@ use crate::{Config, Module};
 use frame_support::pallet_prelude::{GenesisBuild, Hooks, MaybeSerializeDeserialize};
 use frame_support::sp_runtime::traits::AtLeast32Bit;
 use frame_support::traits::Nothing;
-use frame_support::{construct_runtime, ord_parameter_types, parameter_types, traits::EnsureOrigin, weights::Weight};
+use frame_support::{
+	assert_ok, construct_runtime, ord_parameter_types, parameter_types, traits::EnsureOrigin, weights::Weight,
+};
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use orml_traits::parameter_type_with_key;
 use primitives::FungibleTokenId::FungibleToken;
 use primitives::{Amount, CurrencyId, FungibleTokenId};
 use sp_core::H256;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use auction_manager::AuctionInfo;
 use sp_runtime::{
 	testing::Header,
 	traits::{AccountIdConversion, IdentityLookup},
@@ -24,10 +30,13 @@ pub type BlockNumber = u64;
 
 pub const ALICE: AccountId = 4;
 pub const BOB: AccountId = 5;
+pub const CHARLIE: AccountId = 6;
 pub const METAVERSE_ID: MetaverseId = 1;
 pub const METAVERSE_ID_NOT_EXIST: MetaverseId = 1;
 pub const NUUM: CurrencyId = 0;
 pub const METAVERSE_FUND: FungibleTokenId = FungibleTokenId::FungibleToken(1);
+pub const ASSET_ID: AssetId = 0;
+pub const BUYOUT_AUCTION_ID: AuctionId = 1;
 
 ord_parameter_types! {
 	pub const One: AccountId = ALICE;
@@ -119,6 +128,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 		}
 	}
 
+	fn has_role(who: &AccountId, metaverse_id: &MetaverseId, _role: MetaverseRole) -> bool {
+		Self::check_ownership(who, metaverse_id)
+	}
+
 	fn get_metaverse(metaverse_id: u64) -> Option<MetaverseInfo<u128>> {
 		None
 	}
@@ -146,6 +159,120 @@ impl SwapManager<AccountId, FungibleTokenId, Balance> for DEXManager {
 	}
 }
 
+thread_local! {
+	static NFT_OWNERS: RefCell<BTreeMap<AssetId, AccountId>> = RefCell::new(BTreeMap::new());
+	static ITEMS_IN_AUCTION: RefCell<Vec<ItemId>> = RefCell::new(Vec::new());
+}
+
+pub fn set_nft_owner(asset_id: AssetId, owner: AccountId) {
+	NFT_OWNERS.with(|owners| owners.borrow_mut().insert(asset_id, owner));
+}
+
+pub fn nft_owner(asset_id: AssetId) -> Option<AccountId> {
+	NFT_OWNERS.with(|owners| owners.borrow().get(&asset_id).cloned())
+}
+
+/// Settle the buyout auction of `asset_id`, as the auction pallet does when it ends
+pub fn finalize_buyout(asset_id: AssetId, winner: AccountId, price: Balance) {
+	ITEMS_IN_AUCTION.with(|items| items.borrow_mut().retain(|item| *item != ItemId::NFT(asset_id)));
+	let vault_account = TokenizationModule::get_fractional_vault_account(asset_id);
+	assert_ok!(<Currencies as MultiCurrency<AccountId>>::transfer(
+		FungibleTokenId::NativeToken(0),
+		&winner,
+		&vault_account,
+		price
+	));
+	set_nft_owner(asset_id, winner);
+}
+
+pub struct MockNFTHandler;
+
+impl NFTTrait<AccountId> for MockNFTHandler {
+	fn check_ownership(who: &AccountId, asset_id: &AssetId) -> Result<bool, DispatchError> {
+		let owner = nft_owner(*asset_id).ok_or(DispatchError::Other("AssetIdNotFound"))?;
+		Ok(owner == *who)
+	}
+
+	fn transfer_nft(from: &AccountId, to: &AccountId, asset_id: &AssetId) -> DispatchResult {
+		ensure!(
+			Self::check_ownership(from, asset_id)?,
+			DispatchError::Other("NoPermission")
+		);
+		set_nft_owner(*asset_id, *to);
+		Ok(())
+	}
+}
+
+pub struct MockAuctionManager;
+
+impl Auction<AccountId, BlockNumber> for MockAuctionManager {
+	type Balance = Balance;
+
+	fn auction_info(_id: u64) -> Option<AuctionInfo<u128, Self::Balance, u64>> {
+		None
+	}
+
+	fn update_auction(_id: u64, _info: AuctionInfo<u128, Self::Balance, u64>) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_auction(
+		_recipient: u128,
+		_initial_amount: Self::Balance,
+		_start: u64,
+		_end: Option<u64>,
+	) -> Result<u64, DispatchError> {
+		Ok(BUYOUT_AUCTION_ID)
+	}
+
+	fn create_auction(
+		_auction_type: AuctionType,
+		item_id: ItemId,
+		_end: Option<u64>,
+		_recipient: u128,
+		_initial_amount: Self::Balance,
+		_start: u64,
+		_listing_level: ListingLevel<AccountId>,
+	) -> Result<u64, DispatchError> {
+		ITEMS_IN_AUCTION.with(|items| items.borrow_mut().push(item_id));
+		Ok(BUYOUT_AUCTION_ID)
+	}
+
+	fn remove_auction(_id: u64, _item_id: ItemId) {}
+
+	fn set_reserve_price(_id: u64, _reserve_price: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn place_bid(_who: u128, _id: u64, _value: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn auction_bid_handler(
+		_now: u64,
+		_id: u64,
+		_new_bid: (u128, Self::Balance),
+		_last_bid: Option<(u128, Self::Balance)>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn collect_royalty_fee(
+		_high_bid_price: &Self::Balance,
+		_high_bidder: &u128,
+		_asset_id: &u64,
+		_social_currency_id: FungibleTokenId,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl CheckAuctionItemHandler for MockAuctionManager {
+	fn check_item_in_auction(item_id: ItemId) -> bool {
+		ITEMS_IN_AUCTION.with(|items| items.borrow().contains(&item_id))
+	}
+}
+
 parameter_types! {
 	pub const SwapPalletId: PalletId = PalletId(*b"bit/swap");
 	pub const SwapFee: (u32, u32) = (1, 20); //0.005%
@@ -172,6 +299,7 @@ impl currencies::Config for Runtime {
 
 parameter_types! {
 	pub const MinVestedTransfer: Balance = 100;
+	pub const BuyoutDelay: BlockNumber = 10;
 }
 
 impl Config for Runtime {
@@ -183,6 +311,10 @@ impl Config for Runtime {
 	type LiquidityPoolManager = SwapModule;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureSignedBy<One, AccountId>;
+	type NFTHandler = MockNFTHandler;
+	type AuctionHandler = MockAuctionManager;
+	type BuyoutDelay = BuyoutDelay;
+	type WeightInfo = ();
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -218,11 +350,14 @@ impl ExtBuilder {
 			.unwrap();
 
 		pallet_balances::GenesisConfig::<Runtime> {
-			balances: vec![(ALICE, 100000)],
+			balances: vec![(ALICE, 100000), (CHARLIE, 100000)],
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		NFT_OWNERS.with(|owners| owners.borrow_mut().clear());
+		ITEMS_IN_AUCTION.with(|items| items.borrow_mut().clear());
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
@@ -166,3 +166,129 @@ fn metaverse_treasury_pool_transfer_should_work() {
 		assert_eq!(Currencies::free_balance(METAVERSE_FUND, &ALICE), 380); // 120 has been vested
 	});
 }
+
+fn fractionalize_asset_by_alice() -> FungibleTokenId {
+	set_nft_owner(ASSET_ID, ALICE);
+	assert_ok!(TokenizationModule::fractionalize(
+		Origin::signed(ALICE),
+		ASSET_ID,
+		b"FRAC".to_vec(),
+		1000,
+		500
+	));
+	TokenizationModule::fractional_vaults(ASSET_ID).unwrap().share_token
+}
+
+#[test]
+fn fractionalize_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let share_token = fractionalize_asset_by_alice();
+
+		assert_eq!(share_token, FungibleTokenId::FungibleToken(1));
+		assert_eq!(
+			nft_owner(ASSET_ID),
+			Some(TokenizationModule::get_fractional_vault_account(ASSET_ID))
+		);
+		assert_eq!(Currencies::free_balance(share_token, &ALICE), 1000);
+		assert_eq!(TokenizationModule::token_details(share_token).total_supply, 1000);
+		assert_eq!(
+			last_event(),
+			Event::TokenizationModule(crate::Event::AssetFractionalized(ASSET_ID, ALICE, share_token, 1000))
+		);
+	});
+}
+
+#[test]
+fn fractionalize_should_fail_if_not_owner_or_already_fractionalized() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_nft_owner(ASSET_ID, ALICE);
+		assert_noop!(
+			TokenizationModule::fractionalize(Origin::signed(BOB), ASSET_ID, b"FRAC".to_vec(), 1000, 500),
+			Error::<Runtime>::NoPermissionToFractionalize
+		);
+		assert_noop!(
+			TokenizationModule::fractionalize(Origin::signed(ALICE), ASSET_ID, b"FRAC".to_vec(), 0, 500),
+			Error::<Runtime>::AmountZero
+		);
+
+		fractionalize_asset_by_alice();
+		assert_noop!(
+			TokenizationModule::fractionalize(Origin::signed(ALICE), ASSET_ID, b"FRAC".to_vec(), 1000, 500),
+			Error::<Runtime>::AssetAlreadyFractionalized
+		);
+	});
+}
+
+#[test]
+fn buyout_and_redeem_shares_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let share_token = fractionalize_asset_by_alice();
+		assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, share_token, 250));
+
+		assert_noop!(
+			TokenizationModule::redeem_shares(Origin::signed(BOB), ASSET_ID),
+			Error::<Runtime>::BuyoutNotCompleted
+		);
+
+		assert_noop!(
+			TokenizationModule::trigger_buyout(Origin::signed(BOB), ASSET_ID),
+			Error::<Runtime>::BuyoutTooEarly
+		);
+
+		System::set_block_number(11);
+		// Accounts without shares can start the buyout too
+		assert_ok!(TokenizationModule::trigger_buyout(Origin::signed(CHARLIE), ASSET_ID));
+		assert_eq!(
+			last_event(),
+			Event::TokenizationModule(crate::Event::BuyoutTriggered(ASSET_ID, BUYOUT_AUCTION_ID, CHARLIE))
+		);
+		assert_noop!(
+			TokenizationModule::trigger_buyout(Origin::signed(BOB), ASSET_ID),
+			Error::<Runtime>::AssetInAuction
+		);
+
+		finalize_buyout(ASSET_ID, CHARLIE, 2000);
+		assert_noop!(
+			TokenizationModule::trigger_buyout(Origin::signed(BOB), ASSET_ID),
+			Error::<Runtime>::BuyoutAlreadyCompleted
+		);
+
+		assert_ok!(TokenizationModule::redeem_shares(Origin::signed(BOB), ASSET_ID));
+		assert_eq!(Currencies::free_balance(FungibleTokenId::NativeToken(0), &BOB), 500);
+		assert_eq!(Currencies::free_balance(share_token, &BOB), 0);
+		assert_eq!(
+			last_event(),
+			Event::TokenizationModule(crate::Event::SharesRedeemed(ASSET_ID, BOB, 250, 500))
+		);
+		assert_noop!(
+			TokenizationModule::redeem_shares(Origin::signed(BOB), ASSET_ID),
+			Error::<Runtime>::NoSharesToRedeem
+		);
+
+		assert_ok!(TokenizationModule::redeem_shares(Origin::signed(ALICE), ASSET_ID));
+		assert_eq!(
+			Currencies::free_balance(FungibleTokenId::NativeToken(0), &ALICE),
+			101500
+		);
+		assert_eq!(TokenizationModule::fractional_vaults(ASSET_ID), None);
+	});
+}
+
+#[test]
+fn unfractionalize_should_require_all_shares() {
+	ExtBuilder::default().build().execute_with(|| {
+		let share_token = fractionalize_asset_by_alice();
+		assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, share_token, 1));
+
+		assert_noop!(
+			TokenizationModule::unfractionalize(Origin::signed(ALICE), ASSET_ID),
+			Error::<Runtime>::InsufficientShares
+		);
+
+		assert_ok!(Currencies::transfer(Origin::signed(BOB), ALICE, share_token, 1));
+		assert_ok!(TokenizationModule::unfractionalize(Origin::signed(ALICE), ASSET_ID));
+		assert_eq!(nft_owner(ASSET_ID), Some(ALICE));
+		assert_eq!(Currencies::total_issuance(share_token), 0);
+		assert_eq!(TokenizationModule::fractional_vaults(ASSET_ID), None);
+	});
+}
@@ -1,7 +1,10 @@
 use crate::{MetaverseId, SpotId};
 use sp_runtime::DispatchError;
 
-pub trait Continuum<AccountId> {
+pub trait Continuum<AccountId, Balance> {
 	fn transfer_spot(spot_id: SpotId, from: &AccountId, to: &(AccountId, MetaverseId))
 		-> Result<SpotId, DispatchError>;
+
+	/// Record the price of a spot sold through auction
+	fn record_spot_sale(spot_id: SpotId, price: Balance);
 }
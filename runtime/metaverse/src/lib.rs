@@ -62,7 +62,7 @@ use currencies::BasicCurrencyAdapter;
 pub use estate::{MintingRateInfo, Range as MintingRange};
 //use pallet_evm::{EnsureAddressTruncated, HashedAddressMapping};
 use estate::weights::WeightInfo;
-use primitives::{Amount, Balance, BlockNumber, FungibleTokenId};
+use primitives::{Amount, AssetId, Balance, BlockNumber, FungibleTokenId, GroupCollectionId, MetaverseId};
 
 // primitives imports
 use crate::opaque::SessionKeys;
@@ -99,6 +99,9 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// Identifier of an nft class.
+pub type ClassId = u32;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -431,7 +434,50 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
 	pub MaxNftMetadata: u32 = 1024;
-	pub PromotionIncentive: Balance = 1 * DOLLARS;
+	pub MaxPromotionCampaigns: u32 = 10;
+	pub MaxSupporters: u32 = 1000;
+	pub SupportVestingPeriod: BlockNumber = 30 * DAYS;
+	pub MaxSupportSchedules: u32 = 30;
+	pub MinimumSupportContribution: Balance = 1 * DOLLARS;
+	pub SupporterRoyaltyShare: Perbill = Perbill::from_percent(20);
+	pub MaxSupporterRewardCurrencies: u32 = 8;
+	pub MaxNftExecutableTransfer: Balance = 100 * DOLLARS;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum NftExecutableCallFilter {
+	Nothing,
+	JustTransfer,
+	Any,
+}
+
+impl Default for NftExecutableCallFilter {
+	fn default() -> Self {
+		Self::Nothing
+	}
+}
+
+impl InstanceFilter<Call> for NftExecutableCallFilter {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			NftExecutableCallFilter::Nothing => false,
+			NftExecutableCallFilter::JustTransfer => match c {
+				Call::Balances(pallet_balances::Call::transfer { value, .. })
+				| Call::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) => {
+					*value <= MaxNftExecutableTransfer::get()
+				}
+				Call::Currencies(currencies::Call::transfer { amount, .. })
+				| Call::Currencies(currencies::Call::transfer_native_currency { amount, .. }) => {
+					*amount <= MaxNftExecutableTransfer::get()
+				}
+				_ => false,
+			},
+			NftExecutableCallFilter::Any => true,
+		}
+	}
+	fn is_superset(&self, o: &Self) -> bool {
+		self == &NftExecutableCallFilter::Any || self == o || o == &NftExecutableCallFilter::Nothing
+	}
 }
 
 impl nft::Config for Runtime {
@@ -444,9 +490,18 @@ impl nft::Config for Runtime {
 	type MaxBatchTransfer = MaxBatchTransfer;
 	type MaxBatchMinting = MaxBatchMinting;
 	type MaxMetadata = MaxNftMetadata;
-	type MiningResourceId = MiningResourceCurrencyId;
-	type PromotionIncentive = PromotionIncentive;
+	type MaxPromotionCampaigns = MaxPromotionCampaigns;
 	type DataDepositPerByte = MetadataDepositPerByte;
+	type ExecutableCall = Call;
+	type ExecutableCallFilter = NftExecutableCallFilter;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type MaxSupporters = MaxSupporters;
+	type SupportVestingPeriod = SupportVestingPeriod;
+	type MaxSupportSchedules = MaxSupportSchedules;
+	type MinimumSupportContribution = MinimumSupportContribution;
+	type SupporterRoyaltyShare = SupporterRoyaltyShare;
+	type MaxSupporterRewardCurrencies = MaxSupporterRewardCurrencies;
 }
 
 parameter_types! {
@@ -455,7 +510,7 @@ parameter_types! {
 }
 
 impl orml_nft::Config for Runtime {
-	type ClassId = u32;
+	type ClassId = ClassId;
 	type TokenId = u64;
 	type ClassData = nft::NftClassData<Balance>;
 	type TokenData = nft::NftAssetData<Balance>;
@@ -465,8 +520,14 @@ impl orml_nft::Config for Runtime {
 
 parameter_types! {
 	pub MaxMetaverseMetadata: u32 = 1024;
+	pub const MaxMetaverseMetadataHistory: u32 = 20;
+	pub const MetaverseTransferExpiry: BlockNumber = 7 * DAYS;
 	pub MinContribution: Balance = 1 * DOLLARS;
 	pub MaxNumberOfStakerPerMetaverse: u32 = 512;
+	pub const MaxMetaverseRewardsPerBlock: u32 = 50;
+	pub const MetaverseStakingUnbondingRounds: u32 = 7;
+	pub const MaxMetaverseUnbondingChunks: u32 = 32;
+	pub const MaxMetaverseRoles: u32 = 32;
 }
 
 impl metaverse::Config for Runtime {
@@ -474,6 +535,8 @@ impl metaverse::Config for Runtime {
 	type MetaverseTreasury = MetaverseNetworkTreasuryPalletId;
 	type Currency = Balances;
 	type MaxMetaverseMetadata = MaxMetaverseMetadata;
+	type MaxMetadataHistory = MaxMetaverseMetadataHistory;
+	type MetaverseTransferExpiry = MetaverseTransferExpiry;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureRootOrHalfMetaverseCouncil;
 	type WeightInfo = weights::module_metaverse::WeightInfo<Runtime>;
@@ -481,6 +544,11 @@ impl metaverse::Config for Runtime {
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakerPerMetaverse;
 	type MultiCurrency = Currencies;
+	type StakingRewardSource = Mining;
+	type MaxMetaverseRewardsPerBlock = MaxMetaverseRewardsPerBlock;
+	type UnbondingRounds = MetaverseStakingUnbondingRounds;
+	type MaxUnbondingChunks = MaxMetaverseUnbondingChunks;
+	type MaxRolesPerMetaverse = MaxMetaverseRoles;
 }
 
 parameter_types! {
@@ -508,6 +576,13 @@ parameter_types! {
 	pub const AuctionTimeToClose: u32 = 100; // Default 100800 Blocks
 	pub const ContinuumSessionDuration: BlockNumber = 100; // Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 100; // Default 43200 Blocks
+	pub const GNPApprovalThreshold: Perbill = Perbill::from_percent(50);
+	pub const MaxContinuumPremiumZones: u32 = 50;
+	pub const MaxRecentContinuumSpotSales: u32 = 20;
+	pub const MaxContinuumSpotPriceAdjustment: u32 = 4;
+	pub const MinimumEOIDeposit: Balance = 1 * DOLLARS;
+	pub const MaxEOISpots: u32 = 20;
+	pub const MaxEOIParticipants: u32 = 10;
 	pub const MinimumAuctionDuration: BlockNumber = 30; // Minimum duration is 300 blocks
 	pub const RoyaltyFee: u16 = 10; // Loyalty fee 0.1%
 }
@@ -535,6 +610,17 @@ impl continuum::Config for Runtime {
 	type ContinuumTreasury = MetaverseNetworkTreasuryPalletId;
 	type Currency = Balances;
 	type MetaverseInfoSource = Metaverse;
+	type GNPApprovalThreshold = GNPApprovalThreshold;
+	type MaxPremiumZones = MaxContinuumPremiumZones;
+	type MaxRecentSpotSales = MaxRecentContinuumSpotSales;
+	type MaxSpotPriceAdjustment = MaxContinuumSpotPriceAdjustment;
+	type MinimumEOIDeposit = MinimumEOIDeposit;
+	type MaxEOISpots = MaxEOISpots;
+	type MaxEOIParticipants = MaxEOIParticipants;
+}
+
+parameter_types! {
+	pub const FractionalBuyoutDelay: BlockNumber = 7 * DAYS;
 }
 
 impl tokenization::Config for Runtime {
@@ -546,6 +632,10 @@ impl tokenization::Config for Runtime {
 	type LiquidityPoolManager = Swap;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrMetaverseTreasury;
+	type NFTHandler = Nft;
+	type AuctionHandler = Auction;
+	type BuyoutDelay = FractionalBuyoutDelay;
+	type WeightInfo = tokenization::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -1131,6 +1221,65 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl nft_runtime_api::NftApi<Block, AccountId, nft::ClassIdOf<Runtime>> for Runtime {
+		fn assets_by_owner(
+			owner: AccountId,
+			class_id: Option<nft::ClassIdOf<Runtime>>,
+			group_collection_id: Option<GroupCollectionId>,
+			start: Option<AssetId>,
+			limit: u32,
+		) -> Vec<AssetId> {
+			Nft::get_assets_by_owner_paginated(&owner, class_id, group_collection_id, start, limit)
+		}
+
+		fn assets_count_by_owner(owner: AccountId) -> u32 {
+			Nft::get_assets_count_by_owner(owner)
+		}
+
+		fn asset_user(asset_id: AssetId) -> Option<AccountId> {
+			Nft::get_asset_user(asset_id)
+		}
+	}
+
+	impl metaverse_runtime_api::MetaverseApi<Block, AccountId, Balance, Hash, BlockNumber> for Runtime {
+		fn metaverses(
+			start: MetaverseId,
+			limit: u32,
+		) -> Vec<metaverse_runtime_api::MetaverseSummary<AccountId, Balance>> {
+			Metaverse::get_metaverses_paginated(start, limit)
+				.into_iter()
+				.map(|(metaverse_id, metaverse_info)| {
+					let (staking_registration, total_stake) = Metaverse::get_metaverse_staking_info(metaverse_id);
+
+					metaverse_runtime_api::MetaverseSummary {
+						metaverse_id,
+						owner: metaverse_info.owner,
+						metadata: metaverse_info.metadata,
+						currency_id: metaverse_info.currency_id,
+						is_frozen: metaverse_info.is_frozen,
+						staking_registration,
+						total_stake,
+						land_units_count: Estate::get_metaverse_land_units_count(metaverse_id),
+						estates_count: Estate::metaverse_estates_count(metaverse_id),
+						social_token_supply: Tokenization::get_total_issuance(metaverse_id).ok(),
+					}
+				})
+				.collect()
+		}
+
+		fn metaverse_metadata(
+			metaverse_id: MetaverseId,
+		) -> Option<(metaverse_runtime_api::MetaverseMetadata, metaverse_runtime_api::MetaverseProfile)> {
+			Metaverse::get_metaverse_metadata(metaverse_id)
+		}
+
+		fn metaverse_metadata_versions(
+			metaverse_id: MetaverseId,
+		) -> Vec<metaverse_runtime_api::MetaverseMetadataVersion<Hash, BlockNumber>> {
+			Metaverse::get_metaverse_metadata_versions(metaverse_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -1143,6 +1292,7 @@ impl_runtime_apis! {
 			use nft::benchmarking::Pallet as NftBench;
 			use estate::benchmarking::EstateModule as EstateBench;
 			use auction::benchmarking::AuctionModule as AuctionBench;
+			use tokenization::benchmarking::TokenizationModule as TokenizationBench;
 			use metaverse::benchmarking::MetaverseModule as MetaverseBench;
 			use crowdloan::benchmarking::CrowdloanModule as CrowdloanBench;
 
@@ -1154,6 +1304,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, nft, NftBench::<Runtime>);
 			list_benchmark!(list, extra, estate, EstateBench::<Runtime>);
 			list_benchmark!(list, extra, auction, AuctionBench::<Runtime>);
+			list_benchmark!(list, extra, tokenization, TokenizationBench::<Runtime>);
 			list_benchmark!(list, extra, metaverse, MetaverseBench::<Runtime>);
 			list_benchmark!(list, extra, crowdloan, CrowdloanBench::<Runtime>);
 			list_benchmark!(list, extra, pallet_utility, Utility);
@@ -1174,6 +1325,7 @@ impl_runtime_apis! {
 			use nft::benchmarking::Pallet as NftBench;
 			use estate::benchmarking::EstateModule as EstateBench;
 			use auction::benchmarking::AuctionModule as AuctionBench;
+			use tokenization::benchmarking::TokenizationModule as TokenizationBench;
 			use metaverse::benchmarking::MetaverseModule as MetaverseBench;
 			use crowdloan::benchmarking::CrowdloanModule as CrowdloanBench;
 
@@ -1199,6 +1351,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, nft, NftBench::<Runtime>);
 			add_benchmark!(params, batches, estate, EstateBench::<Runtime>);
 			add_benchmark!(params, batches, auction, AuctionBench::<Runtime>);
+			add_benchmark!(params, batches, tokenization, TokenizationBench::<Runtime>);
 			add_benchmark!(params, batches, metaverse, MetaverseBench::<Runtime>);
 			add_benchmark!(params, batches, crowdloan, CrowdloanBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_utility, Utility);
@@ -994,8 +994,14 @@ parameter_types! {
 
 parameter_types! {
 	pub MaxMetaverseMetadata: u32 = 1024;
+	pub const MaxMetaverseMetadataHistory: u32 = 20;
+	pub const MetaverseTransferExpiry: BlockNumber = 7 * DAYS;
 	pub MinContribution: Balance = 1 * DOLLARS;
 	pub MaxNumberOfStakersPerMetaverse: u32 = 512;
+	pub const MaxMetaverseRewardsPerBlock: u32 = 50;
+	pub const MetaverseStakingUnbondingRounds: u32 = 7;
+	pub const MaxMetaverseUnbondingChunks: u32 = 32;
+	pub const MaxMetaverseRoles: u32 = 32;
 }
 
 impl metaverse::Config for Runtime {
@@ -1003,6 +1009,8 @@ impl metaverse::Config for Runtime {
 	type MetaverseTreasury = MetaverseNetworkTreasuryPalletId;
 	type Currency = Balances;
 	type MaxMetaverseMetadata = MaxMetaverseMetadata;
+	type MaxMetadataHistory = MaxMetaverseMetadataHistory;
+	type MetaverseTransferExpiry = MetaverseTransferExpiry;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureRootOrMetaverseTreasury;
 	type WeightInfo = weights::module_metaverse::WeightInfo<Runtime>;
@@ -1010,6 +1018,11 @@ impl metaverse::Config for Runtime {
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
 	type MultiCurrency = Currencies;
+	type StakingRewardSource = Mining;
+	type MaxMetaverseRewardsPerBlock = MaxMetaverseRewardsPerBlock;
+	type UnbondingRounds = MetaverseStakingUnbondingRounds;
+	type MaxUnbondingChunks = MaxMetaverseUnbondingChunks;
+	type MaxRolesPerMetaverse = MaxMetaverseRoles;
 }
 
 //parameter_types! {
@@ -1063,6 +1076,10 @@ impl metaverse::Config for Runtime {
 //    type MetaverseInfoSource = Metaverse;
 //}
 
+parameter_types! {
+	pub const FractionalBuyoutDelay: BlockNumber = 7 * DAYS;
+}
+
 impl tokenization::Config for Runtime {
 	type Event = Event;
 	type TokenId = u64;
@@ -1072,6 +1089,10 @@ impl tokenization::Config for Runtime {
 	type LiquidityPoolManager = Swap;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrMetaverseTreasury;
+	type NFTHandler = ();
+	type AuctionHandler = ();
+	type BuyoutDelay = FractionalBuyoutDelay;
+	type WeightInfo = tokenization::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -1038,7 +1038,50 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
 	pub MaxNftMetadata: u32 = 1024;
-	pub PromotionIncentive: Balance = 1 * DOLLARS;
+	pub MaxPromotionCampaigns: u32 = 10;
+	pub MaxSupporters: u32 = 1000;
+	pub SupportVestingPeriod: BlockNumber = 30 * DAYS;
+	pub MaxSupportSchedules: u32 = 30;
+	pub MinimumSupportContribution: Balance = 1 * DOLLARS;
+	pub SupporterRoyaltyShare: Perbill = Perbill::from_percent(20);
+	pub MaxSupporterRewardCurrencies: u32 = 8;
+	pub MaxNftExecutableTransfer: Balance = 100 * DOLLARS;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum NftExecutableCallFilter {
+	Nothing,
+	JustTransfer,
+	Any,
+}
+
+impl Default for NftExecutableCallFilter {
+	fn default() -> Self {
+		Self::Nothing
+	}
+}
+
+impl InstanceFilter<Call> for NftExecutableCallFilter {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			NftExecutableCallFilter::Nothing => false,
+			NftExecutableCallFilter::JustTransfer => match c {
+				Call::Balances(pallet_balances::Call::transfer { value, .. })
+				| Call::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) => {
+					*value <= MaxNftExecutableTransfer::get()
+				}
+				Call::Currencies(currencies::Call::transfer { amount, .. })
+				| Call::Currencies(currencies::Call::transfer_native_currency { amount, .. }) => {
+					*amount <= MaxNftExecutableTransfer::get()
+				}
+				_ => false,
+			},
+			NftExecutableCallFilter::Any => true,
+		}
+	}
+	fn is_superset(&self, o: &Self) -> bool {
+		self == &NftExecutableCallFilter::Any || self == o || o == &NftExecutableCallFilter::Nothing
+	}
 }
 
 impl nft::Config for Runtime {
@@ -1051,9 +1094,18 @@ impl nft::Config for Runtime {
 	type MaxBatchTransfer = MaxBatchTransfer;
 	type MaxBatchMinting = MaxBatchMinting;
 	type MaxMetadata = MaxNftMetadata;
-	type MiningResourceId = MiningResourceCurrencyId;
-	type PromotionIncentive = PromotionIncentive;
+	type MaxPromotionCampaigns = MaxPromotionCampaigns;
 	type DataDepositPerByte = MetadataDepositPerByte;
+	type ExecutableCall = Call;
+	type ExecutableCallFilter = NftExecutableCallFilter;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type MaxSupporters = MaxSupporters;
+	type SupportVestingPeriod = SupportVestingPeriod;
+	type MaxSupportSchedules = MaxSupportSchedules;
+	type MinimumSupportContribution = MinimumSupportContribution;
+	type SupporterRoyaltyShare = SupporterRoyaltyShare;
+	type MaxSupporterRewardCurrencies = MaxSupporterRewardCurrencies;
 }
 
 parameter_types! {
@@ -1072,8 +1124,14 @@ impl orml_nft::Config for Runtime {
 
 parameter_types! {
 	pub MaxMetaverseMetadata: u32 = 1024;
+	pub const MaxMetaverseMetadataHistory: u32 = 20;
+	pub const MetaverseTransferExpiry: BlockNumber = 7 * DAYS;
 	pub MinContribution: Balance = 1 * DOLLARS;
 	pub MaxNumberOfStakersPerMetaverse: u32 = 512;
+	pub const MaxMetaverseRewardsPerBlock: u32 = 50;
+	pub const MetaverseStakingUnbondingRounds: u32 = 7;
+	pub const MaxMetaverseUnbondingChunks: u32 = 32;
+	pub const MaxMetaverseRoles: u32 = 32;
 }
 
 impl metaverse::Config for Runtime {
@@ -1081,6 +1139,8 @@ impl metaverse::Config for Runtime {
 	type MetaverseTreasury = MetaverseNetworkTreasuryPalletId;
 	type Currency = Balances;
 	type MaxMetaverseMetadata = MaxMetaverseMetadata;
+	type MaxMetadataHistory = MaxMetaverseMetadataHistory;
+	type MetaverseTransferExpiry = MetaverseTransferExpiry;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureRootOrHalfMetaverseCouncil;
 	type WeightInfo = weights::module_metaverse::WeightInfo<Runtime>;
@@ -1088,6 +1148,11 @@ impl metaverse::Config for Runtime {
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
 	type MultiCurrency = Currencies;
+	type StakingRewardSource = Mining;
+	type MaxMetaverseRewardsPerBlock = MaxMetaverseRewardsPerBlock;
+	type UnbondingRounds = MetaverseStakingUnbondingRounds;
+	type MaxUnbondingChunks = MaxMetaverseUnbondingChunks;
+	type MaxRolesPerMetaverse = MaxMetaverseRoles;
 }
 
 parameter_types! {
@@ -1117,6 +1182,13 @@ parameter_types! {
 	pub const AuctionTimeToClose: u32 = 100800; // Default 100800 Blocks
 	pub const ContinuumSessionDuration: BlockNumber = 43200; // Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 43200; // Default 43200 Blocks
+	pub const GNPApprovalThreshold: Perbill = Perbill::from_percent(50);
+	pub const MaxContinuumPremiumZones: u32 = 50;
+	pub const MaxRecentContinuumSpotSales: u32 = 20;
+	pub const MaxContinuumSpotPriceAdjustment: u32 = 4;
+	pub const MinimumEOIDeposit: Balance = 1 * DOLLARS;
+	pub const MaxEOISpots: u32 = 20;
+	pub const MaxEOIParticipants: u32 = 10;
 	pub const MinimumAuctionDuration: BlockNumber = 300; // Minimum duration is 300 blocks
 	pub const RoyaltyFee: u16 = 10; // Loyalty fee 0.1%
 }
@@ -1144,6 +1216,17 @@ impl continuum::Config for Runtime {
 	type ContinuumTreasury = MetaverseNetworkTreasuryPalletId;
 	type Currency = Balances;
 	type MetaverseInfoSource = Metaverse;
+	type GNPApprovalThreshold = GNPApprovalThreshold;
+	type MaxPremiumZones = MaxContinuumPremiumZones;
+	type MaxRecentSpotSales = MaxRecentContinuumSpotSales;
+	type MaxSpotPriceAdjustment = MaxContinuumSpotPriceAdjustment;
+	type MinimumEOIDeposit = MinimumEOIDeposit;
+	type MaxEOISpots = MaxEOISpots;
+	type MaxEOIParticipants = MaxEOIParticipants;
+}
+
+parameter_types! {
+	pub const FractionalBuyoutDelay: BlockNumber = 7 * DAYS;
 }
 
 impl tokenization::Config for Runtime {
@@ -1155,6 +1238,10 @@ impl tokenization::Config for Runtime {
 	type LiquidityPoolManager = Swap;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrMetaverseTreasury;
+	type NFTHandler = Nft;
+	type AuctionHandler = Auction;
+	type BuyoutDelay = FractionalBuyoutDelay;
+	type WeightInfo = tokenization::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
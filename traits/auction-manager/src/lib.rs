@@ -16,7 +16,7 @@ use sp_std::{
 	vec::Vec,
 };
 
-use primitives::{AssetId, AuctionId, FungibleTokenId, ItemId, MetaverseId};
+use primitives::{AssetId, AuctionId, Balance, FungibleTokenId, ItemId, MetaverseId};
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub enum Change<Value> {
@@ -101,6 +101,12 @@ pub trait Auction<AccountId, BlockNumber> {
 	/// Remove auction by `id`
 	fn remove_auction(id: AuctionId, item_id: ItemId);
 
+	/// Require the first bid on auction `id` to be at least `reserve_price`
+	fn set_reserve_price(id: AuctionId, reserve_price: Self::Balance) -> DispatchResult;
+
+	/// Bid `value` from `who` on auction `id` through the same checks as a bid extrinsic
+	fn place_bid(who: AccountId, id: AuctionId, value: Self::Balance) -> DispatchResult;
+
 	fn auction_bid_handler(
 		_now: BlockNumber,
 		id: AuctionId,
@@ -108,14 +114,6 @@ pub trait Auction<AccountId, BlockNumber> {
 		last_bid: Option<(AccountId, Self::Balance)>,
 	) -> DispatchResult;
 
-	fn local_auction_bid_handler(
-		_now: BlockNumber,
-		id: AuctionId,
-		new_bid: (AccountId, Self::Balance),
-		last_bid: Option<(AccountId, Self::Balance)>,
-		social_currency_id: FungibleTokenId,
-	) -> DispatchResult;
-
 	fn collect_royalty_fee(
 		high_bid_price: &Self::Balance,
 		high_bidder: &AccountId,
@@ -128,6 +126,92 @@ pub trait CheckAuctionItemHandler {
 	fn check_item_in_auction(item_id: ItemId) -> bool;
 }
 
+impl CheckAuctionItemHandler for () {
+	fn check_item_in_auction(_item_id: ItemId) -> bool {
+		false
+	}
+}
+
+/// Auction implementation for runtimes without an auction pallet, every auction is rejected.
+impl<AccountId, BlockNumber> Auction<AccountId, BlockNumber> for () {
+	type Balance = Balance;
+
+	fn auction_info(_id: AuctionId) -> Option<AuctionInfo<AccountId, Self::Balance, BlockNumber>> {
+		None
+	}
+
+	fn update_auction(_id: AuctionId, _info: AuctionInfo<AccountId, Self::Balance, BlockNumber>) -> DispatchResult {
+		Err(DispatchError::Other("Auction is not supported"))
+	}
+
+	fn new_auction(
+		_recipient: AccountId,
+		_initial_amount: Self::Balance,
+		_start: BlockNumber,
+		_end: Option<BlockNumber>,
+	) -> Result<AuctionId, DispatchError> {
+		Err(DispatchError::Other("Auction is not supported"))
+	}
+
+	fn create_auction(
+		_auction_type: AuctionType,
+		_item_id: ItemId,
+		_end: Option<BlockNumber>,
+		_recipient: AccountId,
+		_initial_amount: Self::Balance,
+		_start: BlockNumber,
+		_listing_level: ListingLevel<AccountId>,
+	) -> Result<AuctionId, DispatchError> {
+		Err(DispatchError::Other("Auction is not supported"))
+	}
+
+	fn remove_auction(_id: AuctionId, _item_id: ItemId) {}
+
+	fn set_reserve_price(_id: AuctionId, _reserve_price: Self::Balance) -> DispatchResult {
+		Err(DispatchError::Other("Auction is not supported"))
+	}
+
+	fn place_bid(_who: AccountId, _id: AuctionId, _value: Self::Balance) -> DispatchResult {
+		Err(DispatchError::Other("Auction is not supported"))
+	}
+
+	fn auction_bid_handler(
+		_now: BlockNumber,
+		_id: AuctionId,
+		_new_bid: (AccountId, Self::Balance),
+		_last_bid: Option<(AccountId, Self::Balance)>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Auction is not supported"))
+	}
+
+	fn collect_royalty_fee(
+		_high_bid_price: &Self::Balance,
+		_high_bidder: &AccountId,
+		_asset_id: &AssetId,
+		_social_currency_id: FungibleTokenId,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Abstraction over NFT custody for pallets that hold assets on behalf of users.
+pub trait NFTTrait<AccountId> {
+	/// Check if `who` owns `asset_id`
+	fn check_ownership(who: &AccountId, asset_id: &AssetId) -> Result<bool, DispatchError>;
+	/// Move `asset_id` from `from` to `to`
+	fn transfer_nft(from: &AccountId, to: &AccountId, asset_id: &AssetId) -> DispatchResult;
+}
+
+impl<AccountId> NFTTrait<AccountId> for () {
+	fn check_ownership(_who: &AccountId, _asset_id: &AssetId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn transfer_nft(_from: &AccountId, _to: &AccountId, _asset_id: &AssetId) -> DispatchResult {
+		Err(DispatchError::Other("NFT is not supported"))
+	}
+}
+
 /// The result of bid handling.
 pub struct OnNewBidResult<BlockNumber> {
 	/// Indicates if the bid was accepted
@@ -3,7 +3,7 @@
 use codec::{Decode, Encode};
 use primitives::{FungibleTokenId, MetaverseId, UndeployedLandBlockId, UndeployedLandBlockType};
 use scale_info::TypeInfo;
-use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
 use sp_std::vec::Vec;
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -25,6 +25,28 @@ pub struct MetaverseInfo<AccountId> {
 	pub is_frozen: bool,
 }
 
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct MetaverseProfile {
+	/// Display name of this metaverse
+	pub name: Vec<u8>,
+	/// URI of the description of this metaverse
+	pub description_uri: Vec<u8>,
+	/// Content id of the logo of this metaverse
+	pub logo_cid: Vec<u8>,
+	/// Website of this metaverse
+	pub website: Vec<u8>,
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MetaverseMetadataVersion<Hash, BlockNumber> {
+	/// Version number, starting from 0 at creation
+	pub version: u32,
+	/// Hash of the metadata and profile of this version
+	pub hash: Hash,
+	/// Block at which this version was recorded
+	pub updated_at: BlockNumber,
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct MetaverseFund<AccountId, Balance> {
 	/// The fund account of this metaverse
@@ -37,9 +59,25 @@ pub struct MetaverseFund<AccountId, Balance> {
 	pub currency_id: FungibleTokenId,
 }
 
+/// Delegated permissions a metaverse owner can grant to other accounts
+#[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum MetaverseRole {
+	/// Full administration of the metaverse, including granting other roles
+	Admin,
+	/// Buy, release and bid on continuum spots for the metaverse
+	LandManager,
+	/// List items on the local marketplace of the metaverse
+	Marketplace,
+	/// Receive the social token issued for the metaverse
+	Treasurer,
+}
+
 pub trait MetaverseTrait<AccountId> {
 	/// Check the ownership of this metaverse
 	fn check_ownership(who: &AccountId, metaverse_id: &MetaverseId) -> bool;
+	/// Check if the account can act with the role on this metaverse, owner and admins hold every
+	/// role
+	fn has_role(who: &AccountId, metaverse_id: &MetaverseId, role: MetaverseRole) -> bool;
 	/// Get the detail of this metaverse
 	fn get_metaverse(metaverse_id: MetaverseId) -> Option<MetaverseInfo<AccountId>>;
 	/// Get metaverse token detail
@@ -48,6 +86,17 @@ pub trait MetaverseTrait<AccountId> {
 	fn update_metaverse_token(metaverse_id: MetaverseId, currency_id: FungibleTokenId) -> Result<(), DispatchError>;
 }
 
+pub trait MiningResourceAllocation<AccountId, Balance> {
+	/// Move mining resource from the mining treasury to fund staking rewards
+	fn fund_staking_rewards(recipient: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+impl<AccountId, Balance> MiningResourceAllocation<AccountId, Balance> for () {
+	fn fund_staking_rewards(_recipient: &AccountId, _amount: Balance) -> DispatchResult {
+		Err(DispatchError::Other("Mining resource allocation is not supported"))
+	}
+}
+
 pub trait MetaverseLandTrait<AccountId> {
 	/// Get Land units owned by account
 	fn get_user_land_units(who: &AccountId, metaverse_id: &MetaverseId) -> Vec<(i32, i32)>;